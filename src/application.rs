@@ -1 +1,2 @@
-pub mod simulator;
\ No newline at end of file
+pub mod simulator;
+pub mod closed_loop;
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod closed_loop_runner;
\ No newline at end of file
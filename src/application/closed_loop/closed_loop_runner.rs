@@ -0,0 +1,250 @@
+use crate::application::simulator::simulator::Simulator;
+use crate::domain::controller::controller_trait::Controller;
+use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
+use crate::domain::dynamics::propagator::Propagator;
+use crate::domain::force::force_trait::Force;
+use crate::domain::maneuver::impulsive_maneuver::ImpulsiveState;
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::logger::loggable_trait::Loggable;
+use crate::infrastructure::logger::logger::Logger;
+
+/// **制御器の内部の値 (最適化した軌道, アクチュエータの指令など) をログの行にする関数**
+pub type ControllerLogEntries<C> = Box<dyn Fn(&C, f64) -> Vec<Box<dyn Loggable>>>;
+
+/// **状態量と時刻から実行を一時停止するか決める関数**
+pub type StopCondition<T> = Box<dyn Fn(&T, f64) -> bool>;
+
+/// **実行結果のまとめ**
+#[derive(Debug, Clone)]
+pub struct RunSummary {
+    pub steps: usize,            // 実行したステップ数 (これまでの合計)
+    pub t_start: f64,
+    pub t_end: f64,
+    pub input_integral: f64,     // 入力のノルムの時間積分 (加速度なら Δv 相当)
+    pub max_input_norm: f64,
+    pub paused: bool,            // 停止条件で止まったか
+    pub finished: bool,          // 設定したステップ数を実行し終えたか
+}
+
+/// **閉ループのシミュレーションを実行する**
+/// 制御器は真値の状態量からシミュレータの入力を返すもの (状態量・入力の変換は
+/// `ControllerPipeline` のアダプタで組み込む). 各ステップで入力を計算してシミュレータを進め,
+/// ロガーがあれば状態量・入力と制御器の内部の値を 1 行ずつ記録する.
+/// 停止条件が成り立つと一時停止し, `resume` で再開できる (停止条件は一度成り立つと外す)
+pub struct ClosedLoopRunner<T, U, P, D, C>
+where
+    T: StateVector + ImpulsiveState + Clone,
+    U: Force + Clone,
+    P: Propagator<T, U>,
+    D: ContinuousDynamics<T, U>,
+    C: Controller<T, U>,
+{
+    simulator: Simulator<T, U, P, D>,
+    controller: C,
+    state_logger: Option<Logger>,
+    controller_logger: Option<(Logger, ControllerLogEntries<C>)>,
+    stop_condition: Option<StopCondition<T>>,
+    paused: bool,
+    steps: usize,
+    t_start: f64,
+    input_integral: f64,
+    max_input_norm: f64,
+}
+
+impl<T, U, P, D, C> ClosedLoopRunner<T, U, P, D, C>
+where
+    T: StateVector + ImpulsiveState + Clone + Loggable + 'static,
+    U: Force + Clone + Loggable + 'static,
+    P: Propagator<T, U>,
+    D: ContinuousDynamics<T, U>,
+    C: Controller<T, U>,
+{
+    pub fn new(simulator: Simulator<T, U, P, D>, controller: C) -> Self {
+        let t_start = simulator.t;
+        Self {
+            simulator,
+            controller,
+            state_logger: None,
+            controller_logger: None,
+            stop_condition: None,
+            paused: false,
+            steps: 0,
+            t_start,
+            input_integral: 0.0,
+            max_input_norm: 0.0,
+        }
+    }
+
+    /// **状態量と入力を記録するロガーを付ける**
+    pub fn with_state_logger(mut self, filename: &str) -> std::io::Result<Self> {
+        self.state_logger = Some(Logger::new(filename)?);
+        Ok(self)
+    }
+
+    /// **制御器の内部の値を記録するロガーを付ける**
+    pub fn with_controller_logger(mut self, filename: &str, entries: ControllerLogEntries<C>) -> std::io::Result<Self> {
+        self.controller_logger = Some((Logger::new(filename)?, entries));
+        Ok(self)
+    }
+
+    pub fn with_stop_condition(mut self, stop_condition: StopCondition<T>) -> Self {
+        self.stop_condition = Some(stop_condition);
+        self
+    }
+
+    pub fn simulator(&self) -> &Simulator<T, U, P, D> {
+        &self.simulator
+    }
+
+    /// **マヌーバの予約などのためにシミュレータを直接操作する**
+    pub fn simulator_mut(&mut self) -> &mut Simulator<T, U, P, D> {
+        &mut self.simulator
+    }
+
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// **設定したステップ数 (`Simulator::step`) を実行し終えたか**
+    pub fn is_finished(&self) -> bool {
+        self.steps as i64 >= self.simulator.step
+    }
+
+    /// **1 ステップ進める (一時停止中・終了後は何もせず false)**
+    pub fn step(&mut self) -> bool {
+        if self.paused || self.is_finished() {
+            return false;
+        }
+        let t = self.simulator.t;
+        let input = self.controller.compute_control_input(self.simulator.get_state(), t);
+        self.simulator.update(&input);
+        let dt_step = self.simulator.t - t;
+        self.steps += 1;
+
+        let input_norm = input.get_vector().dot(input.get_vector()).sqrt();
+        self.input_integral += input_norm * dt_step;
+        self.max_input_norm = self.max_input_norm.max(input_norm);
+
+        // 同じタイムステップのデータを一行にまとめる
+        let t = self.simulator.t;
+        if let Some(logger) = self.state_logger.as_mut() {
+            logger.add_entry(self.simulator.get_state().clone());
+            logger.add_entry(input);
+            logger.log(t);
+        }
+        if let Some((logger, entries)) = self.controller_logger.as_mut() {
+            for entry in entries(&self.controller, t) {
+                logger.add_boxed_entry(entry);
+            }
+            logger.log(t);
+        }
+
+        if self.stop_condition.as_ref().is_some_and(|stop| stop(self.simulator.get_state(), t)) {
+            self.stop_condition = None;
+            self.paused = true;
+        }
+        true
+    }
+
+    /// **時刻 t_end まで進める (一時停止・終了したらそこで止まる)**
+    pub fn run_until(&mut self, t_end: f64) -> RunSummary {
+        while self.simulator.t < t_end - 1e-9 && self.step() {}
+        self.flush();
+        self.summary()
+    }
+
+    /// **設定したステップ数を最後まで進める (一時停止したらそこで止まる)**
+    pub fn run(&mut self) -> RunSummary {
+        while self.step() {}
+        self.flush();
+        self.summary()
+    }
+
+    pub fn summary(&self) -> RunSummary {
+        RunSummary {
+            steps: self.steps,
+            t_start: self.t_start,
+            t_end: self.simulator.t,
+            input_integral: self.input_integral,
+            max_input_norm: self.max_input_norm,
+            paused: self.paused,
+            finished: self.is_finished(),
+        }
+    }
+
+    pub fn flush(&mut self) {
+        if let Some(logger) = self.state_logger.as_mut() {
+            logger.flush();
+        }
+        if let Some((logger, _)) = self.controller_logger.as_mut() {
+            logger.flush();
+        }
+    }
+
+    /// **記録したログを Python スクリプトで描画する**
+    pub fn plot_logs(&self) {
+        if let Some(logger) = self.state_logger.as_ref() {
+            logger.execute_python_script();
+        }
+        if let Some((logger, _)) = self.controller_logger.as_ref() {
+            logger.execute_python_script();
+        }
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::controller::pd_controller::PdController;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_closed_loop_runner_step_pause_and_summary() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let target = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let controller = PdController::new(&dynamics, arr1(&[1e-4, 1e-4, 1e-4]), arr1(&[2e-2, 2e-2, 2e-2]), target, Some(1e-3)).unwrap();
+    let x0 = PositionVelocityStateLvlh::form_from_list([10.0, -20.0, 5.0], [0.0, 0.0, 0.0]);
+    let simulator = Simulator::new(RungeKutta4Propagator, dynamics.clone(), x0, 1.0, 1500, 0.0);
+
+    // 相対距離が 1 m を切ったら一時停止する
+    let mut runner = ClosedLoopRunner::new(simulator, controller)
+        .with_stop_condition(Box::new(|state: &PositionVelocityStateLvlh, _| state.position_norm() < 1.0));
+
+    assert!(runner.step());
+    let summary = runner.run_until(100.0);
+    assert_eq!(summary.steps, 100);
+    assert!((summary.t_end - 100.0).abs() < 1e-9 && !summary.paused && !summary.finished);
+
+    let summary = runner.run();
+    assert!(summary.paused && !summary.finished);
+    assert!(runner.simulator().get_state().position_norm() < 1.0);
+    assert!(!runner.step());
+
+    // 再開すると最後まで進み, 入力の積分は飽和値 × 時間以下
+    runner.resume();
+    let summary = runner.run();
+    assert!(summary.finished && summary.steps == 1500);
+    assert!((summary.t_end - 1500.0).abs() < 1e-9);
+    assert!(summary.max_input_norm <= 1e-3 * 3.0_f64.sqrt());
+    assert!(summary.input_integral > 0.0 && summary.input_integral <= summary.max_input_norm * 1500.0);
+    assert!(runner.simulator().get_state().position_norm() < 1e-2);
+}
\ No newline at end of file
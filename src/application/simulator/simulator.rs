@@ -1,14 +1,19 @@
 use std::marker::PhantomData;
 
+use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
 use crate::domain::dynamics::propagator::Propagator;
 use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::force::force_trait::Force;
 use crate::domain::disturbance::disturbance_trait::DisturbanceCalculator;
+use crate::domain::maneuver::impulsive_maneuver::{ImpulsiveManeuver, ImpulsiveState};
 
 pub struct Simulator<T, U, P, D>
 where
-    T: StateVector + Clone,
+    T: StateVector + ImpulsiveState + Clone,
     U: Force + Clone,
     P: Propagator<T, U>,
     D: ContinuousDynamics<T, U>,
@@ -17,6 +22,11 @@ where
     dynamics: D,
     state: T,
     disturbances: Vec<Box<dyn DisturbanceCalculator<T, U>>>,
+    rng: StdRng,
+    maneuvers: Vec<ImpulsiveManeuver>,
+    propulsion: Option<T::Propulsion>,  // 噴射で推進剤を減らすための推進系の諸元
+    executed_maneuvers: Vec<ImpulsiveManeuver>,
+    skipped_maneuvers: Vec<(ImpulsiveManeuver, &'static str)>,
     dt: f64,
     pub step: i64,
     pub t: f64,
@@ -25,7 +35,7 @@ where
 
 impl<T, U, P, D> Simulator<T, U, P, D>
 where
-    T: StateVector + Clone,
+    T: StateVector + ImpulsiveState + Clone,
     U: Force,
     P: Propagator<T, U>,
     D: ContinuousDynamics<T, U>,
@@ -38,34 +48,133 @@ where
             dt,
             step,
             disturbances: Vec::new(),
+            rng: StdRng::seed_from_u64(0),
+            maneuvers: Vec::new(),
+            propulsion: None,
+            executed_maneuvers: Vec::new(),
+            skipped_maneuvers: Vec::new(),
             t: t0,
             _marker: PhantomData,
         }
     }
 
+    /// **1 ステップ進める**
+    /// ステップ内に予約したマヌーバがあれば, その時刻で伝搬を区切って Δv を加える
     pub fn update(&mut self, input: &U) {
-        let sum = input.add_vec(&self.calc_disturbance());
-        self.state = self.propagator.propagate_continuous(&self.state, &sum, &self.dynamics, self.dt);
-        self.t += self.dt;
+        for disturbance in self.disturbances.iter_mut() {
+            disturbance.update_stochastic(self.t, self.dt, &mut self.rng);
+        }
+        let t_end = self.t + self.dt;
+        let eps = 1e-9 * self.dt.abs();
+
+        self.execute_maneuvers(self.t + eps);
+        while let Some(t_burn) = self.maneuvers.first().map(|m| m.t).filter(|&t| t < t_end - eps) {
+            self.propagate(input, t_burn - self.t);
+            self.t = t_burn;
+            self.execute_maneuvers(t_burn + eps);
+        }
+        self.propagate(input, t_end - self.t);
+        self.t = t_end;
+        self.execute_maneuvers(t_end + eps);
     }
 
-    pub fn add_disturbance(&mut self, disturbance: Box<dyn DisturbanceCalculator<T, U>>) {
-        self.disturbances.push(disturbance);
+    fn propagate(&mut self, input: &U, dt: f64) {
+        let dynamics = DisturbedDynamics {
+            dynamics: &self.dynamics,
+            disturbances: &self.disturbances,
+        };
+        self.state = self.propagator.propagate_continuous(&self.state, input, &dynamics, self.t, dt);
     }
 
-    fn calc_disturbance(&self) -> U {
-        self.disturbances.iter()
-            .fold(
-                U::zeros(), 
-                |acc, disturbance| 
-                acc.add_vec(&disturbance.calc_force(&self.state))
-            )
+    /// **時刻 t_limit までに予約されたマヌーバを実行する**
+    /// 実行できないマヌーバ (推進剤不足など) は状態を変えずに飛ばし, 理由とともに `skipped_maneuvers` に残す
+    fn execute_maneuvers(&mut self, t_limit: f64) {
+        while self.maneuvers.first().is_some_and(|m| m.t <= t_limit) {
+            let maneuver = self.maneuvers.remove(0);
+            match self.state.apply_maneuver(&maneuver, self.propulsion.as_ref()) {
+                Ok(state) => {
+                    self.state = state;
+                    self.executed_maneuvers.push(maneuver);
+                }
+                Err(reason) => self.skipped_maneuvers.push((maneuver, reason)),
+            }
+        }
+    }
+
+    /// **マヌーバを予約する (現在時刻以降, 状態量が扱える座標系のみ)**
+    pub fn schedule_maneuver(&mut self, maneuver: ImpulsiveManeuver) -> Result<(), &'static str> {
+        if maneuver.t < self.t - 1e-9 * self.dt.abs() {
+            return Err("現在時刻より前のマヌーバは予約できません。");
+        }
+        if !T::supports_frame(maneuver.frame) {
+            return Err("状態量が扱えない座標系のマヌーバです。");
+        }
+        let index = self.maneuvers.partition_point(|m| m.t <= maneuver.t);
+        self.maneuvers.insert(index, maneuver);
+        Ok(())
+    }
+
+    /// **噴射で推進剤を減らすための推進系の諸元を設定**
+    pub fn set_propulsion(&mut self, propulsion: T::Propulsion) {
+        self.propulsion = Some(propulsion);
+    }
+
+    /// **実行済みのマヌーバ (実行順)**
+    pub fn executed_maneuvers(&self) -> &[ImpulsiveManeuver] {
+        &self.executed_maneuvers
+    }
+
+    /// **実行できずに飛ばしたマヌーバとその理由**
+    pub fn skipped_maneuvers(&self) -> &[(ImpulsiveManeuver, &'static str)] {
+        &self.skipped_maneuvers
+    }
+
+    /// **確率的な外乱の乱数シードを設定**
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn add_disturbance(&mut self, disturbance: Box<dyn DisturbanceCalculator<T, U>>) {
+        self.disturbances.push(disturbance);
     }
-    
 
     pub fn get_state(&self) -> &T {
         &self.state
     }
+
+    /// **現在時刻に速度を Δv だけ瞬時に変える**
+    pub fn apply_impulse(&mut self, delta_v: &Array1<f64>) {
+        self.state = self.state.apply_delta_v(delta_v);
+    }
+}
+
+/// **外乱を入力に加えたダイナミクス**
+/// 伝搬の各段で、その段の状態・時刻に対して外乱を評価する
+struct DisturbedDynamics<'a, T, U, D>
+where
+    T: StateVector,
+    U: Force,
+    D: ContinuousDynamics<T, U>,
+{
+    dynamics: &'a D,
+    disturbances: &'a Vec<Box<dyn DisturbanceCalculator<T, U>>>,
+}
+
+impl<T, U, D> ContinuousDynamics<T, U> for DisturbedDynamics<'_, T, U, D>
+where
+    T: StateVector,
+    U: Force,
+    D: ContinuousDynamics<T, U>,
+{
+    fn compute_derivative(&self, state: &T, input: &U, t: f64) -> T {
+        let sum = self.disturbances.iter()
+            .fold(
+                input.clone(), 
+                |acc, disturbance| 
+                acc.add_vec(&disturbance.calc_force(state, t))
+            );
+        self.dynamics.compute_derivative(state, &sum, t)
+    }
 }
 
 // #[cfg(test)]
@@ -98,4 +207,133 @@ where
 
 //     // 期待値を手計算または理論値と比較する
 //     assert!(final_state[0] > 0.0);
-// }
\ No newline at end of file
+// }
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+
+#[cfg(test)]
+struct RampDisturbance;
+
+#[cfg(test)]
+impl DisturbanceCalculator<PositionVelocityStateLvlh, Force3dLvlh> for RampDisturbance {
+    fn calc_force(&self, _: &PositionVelocityStateLvlh, t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_list([t, 0.0, 0.0])
+    }
+}
+
+#[test]
+fn test_disturbance_evaluated_in_each_stage() {
+    // 平均運動がほぼ 0 の HCW に時間比例の外乱を加えると v_x = t^2 / 2 (RK4 は厳密)
+    let initial_state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let propagator = RungeKutta4Propagator;
+    let mut simulator = Simulator::new(propagator, HcwDynamics::new(1.0e12), initial_state, 1.0, 10, 0.0);
+    simulator.add_disturbance(Box::new(RampDisturbance));
+
+    for _ in 0..10 {
+        simulator.update(&Force3dLvlh::zeros());
+    }
+
+    let final_state = simulator.get_state().get_vector();
+    assert!((final_state[3] - 50.0).abs() < 1e-6);
+    assert!((final_state[0] - 1000.0 / 6.0).abs() < 1e-6);
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_2sat_2body::PairTwoBodyDynamics;
+#[cfg(test)]
+use crate::domain::force::force_6d_eci::Force6dEci;
+#[cfg(test)]
+use crate::domain::maneuver::impulsive_maneuver::ManeuverFrame;
+#[cfg(test)]
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_scheduled_maneuver_splits_step() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let initial_state = PositionVelocityStateLvlh::form_from_list([10.0, -100.0, 0.0], [0.0, 0.0, 0.0]);
+    let delta_v = arr1(&[0.01, -0.02, 0.005]);
+
+    // ステップ途中 (t = 15) の噴射は, 15 秒伝搬 → Δv → 5 秒伝搬と一致する
+    let mut simulator = Simulator::new(RungeKutta4Propagator, dynamics.clone(), initial_state.clone(), 10.0, 2, 0.0);
+    simulator.schedule_maneuver(ImpulsiveManeuver::new(15.0, delta_v.clone(), ManeuverFrame::Lvlh)).unwrap();
+    simulator.update(&Force3dLvlh::zeros());
+    assert!(simulator.executed_maneuvers().is_empty());
+    simulator.update(&Force3dLvlh::zeros());
+    assert_eq!(simulator.executed_maneuvers().len(), 1);
+    assert!((simulator.t - 20.0).abs() < 1e-12);
+
+    let propagator = RungeKutta4Propagator;
+    let zero = Force3dLvlh::zeros();
+    let expected = propagator.propagate_continuous(&initial_state, &zero, &dynamics, 0.0, 15.0).apply_delta_v(&delta_v);
+    let expected = propagator.propagate_continuous(&expected, &zero, &dynamics, 15.0, 5.0);
+    let error = simulator.get_state().get_vector() - expected.get_vector();
+    assert!(error.iter().all(|v| v.abs() < 1e-9));
+
+    // LVLH の状態量に ECI の Δv, 過去の時刻のマヌーバは予約できない
+    assert!(simulator.schedule_maneuver(ImpulsiveManeuver::new(30.0, delta_v.clone(), ManeuverFrame::Eci)).is_err());
+    assert!(simulator.schedule_maneuver(ImpulsiveManeuver::new(10.0, delta_v.clone(), ManeuverFrame::Lvlh)).is_err());
+}
+
+#[test]
+fn test_scheduled_maneuver_on_pair_state() {
+    let a = CONSTANTS.radius + 500.0e3;
+    let v = (CONSTANTS.mu / a).sqrt();
+    let pair = PositionVelocityPairStateEci::form_from_list([a, 0.0, 0.0, 0.0, v, 0.0], [a, -100.0, 0.0, 0.0, v, 0.0]);
+    let mut simulator = Simulator::new(RungeKutta4Propagator, PairTwoBodyDynamics::new(), pair, 10.0, 1, 0.0);
+
+    // t = 0 の LVLH 進行方向の噴射は deputy の ECI y 方向の速度を変え, chief は変えない
+    simulator.schedule_maneuver(ImpulsiveManeuver::new(0.0, arr1(&[0.0, 1.0, 0.0]), ManeuverFrame::Lvlh)).unwrap();
+    simulator.schedule_maneuver(ImpulsiveManeuver::new(0.0, arr1(&[0.0, 0.0, 2.0]), ManeuverFrame::Eci)).unwrap();
+    let mut reference = Simulator::new(RungeKutta4Propagator, PairTwoBodyDynamics::new(), simulator.get_state().clone(), 10.0, 1, 0.0);
+    reference.apply_impulse(&arr1(&[0.0, 1.0, 2.0]));
+    simulator.update(&Force6dEci::zeros());
+    reference.update(&Force6dEci::zeros());
+
+    assert_eq!(simulator.executed_maneuvers().len(), 2);
+    let error = simulator.get_state().get_vector() - reference.get_state().get_vector();
+    assert!(error.iter().all(|v| v.abs() < 1e-9));
+}
+
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_2sat_2body_variable_mass::VariableMassPairTwoBodyDynamics;
+#[cfg(test)]
+use crate::domain::force::thrust_acceleration_pair_eci::ThrustAccelerationPairEci;
+#[cfg(test)]
+use crate::domain::maneuver::impulsive_maneuver::ImpulsivePropulsion;
+#[cfg(test)]
+use crate::domain::state::position_velocity_mass_pair_state_eci::PositionVelocityMassPairStateEci;
+
+#[test]
+fn test_unexecutable_maneuver_is_skipped() {
+    let a = CONSTANTS.radius + 500.0e3;
+    let v = (CONSTANTS.mu / a).sqrt();
+    let state = PositionVelocityMassPairStateEci::form_from_list(
+        [a, 0.0, 0.0, 0.0, v, 0.0, 5.0, 0.0],
+        [a, -100.0, 0.0, 0.0, v, 0.0, 0.5, 0.0],
+    );
+    let dynamics = VariableMassPairTwoBodyDynamics::new(45.0, 45.0, 60.0, 60.0);
+    let mut simulator = Simulator::new(RungeKutta4Propagator, dynamics, state, 10.0, 2, 0.0);
+    simulator.set_propulsion(ImpulsivePropulsion { dry_mass: 45.0, isp: 60.0 });
+
+    // 推進剤 0.5 kg では 50 m/s の噴射はできないので飛ばし, 0.1 m/s の噴射は実行する
+    simulator.schedule_maneuver(ImpulsiveManeuver::new(5.0, arr1(&[50.0, 0.0, 0.0]), ManeuverFrame::Lvlh)).unwrap();
+    simulator.schedule_maneuver(ImpulsiveManeuver::new(15.0, arr1(&[0.0, 0.1, 0.0]), ManeuverFrame::Lvlh)).unwrap();
+    simulator.update(&ThrustAccelerationPairEci::zeros());
+    simulator.update(&ThrustAccelerationPairEci::zeros());
+
+    assert_eq!(simulator.skipped_maneuvers().len(), 1);
+    assert!((simulator.skipped_maneuvers()[0].0.t - 5.0).abs() < 1e-12);
+    assert_eq!(simulator.executed_maneuvers().len(), 1);
+    assert!((simulator.get_state().delta_v_deputy() - 0.1).abs() < 1e-12);
+    assert!(simulator.get_state().propellant_deputy() < 0.5);
+}
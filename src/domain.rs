@@ -3,6 +3,9 @@ pub mod force;
 pub mod dynamics;
 pub mod math;
 pub mod disturbance;
+pub mod attitude;
+pub mod actuator;
+pub mod maneuver;
 pub mod controller;
 pub mod cost;
 pub mod differentiable;
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod actuator_trait;
+pub mod thruster_actuator;
@@ -0,0 +1,37 @@
+use crate::domain::force::force_trait::Force;
+use crate::infrastructure::logger::loggable_trait::Loggable;
+
+/// **アクチュエータのトレイト**
+/// 制御器の指令値から実際に機体に加わる値を返す
+pub trait Actuator<U: Force> {
+    /// 時刻 t から dt の間に指令 command を実行したときの出力
+    fn actuate(&mut self, command: &U, t: f64, dt: f64) -> U;
+
+    /// 推進剤を消費する機体で, 現在の推進剤質量 (kg) を受け取る (質量を使わないアクチュエータは何もしない)
+    fn update_propellant(&mut self, _propellant: f64) {}
+}
+
+/// **指令値と実際の出力を並べたログ**
+#[derive(Debug, Clone)]
+pub struct ActuatorLog<U: Force> {
+    commanded: U,
+    delivered: U,
+}
+
+impl<U: Force> ActuatorLog<U> {
+    pub fn new(commanded: U, delivered: U) -> Self {
+        Self { commanded, delivered }
+    }
+}
+
+impl<U: Force + Loggable> Loggable for ActuatorLog<U> {
+    fn header(&self) -> String {
+        let commanded = self.commanded.header().split(',').map(|h| format!("cmd_{}", h)).collect::<Vec<String>>();
+        let delivered = self.delivered.header().split(',').map(|h| format!("act_{}", h)).collect::<Vec<String>>();
+        [commanded, delivered].concat().join(",")
+    }
+
+    fn output_log(&self) -> String {
+        format!("{},{}", self.commanded.output_log(), self.delivered.output_log())
+    }
+}
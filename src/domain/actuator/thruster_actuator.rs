@@ -0,0 +1,257 @@
+use std::marker::PhantomData;
+
+use ndarray::{Array1, Array2};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, StandardNormal};
+
+use super::actuator_trait::Actuator;
+use crate::domain::force::force_trait::Force;
+use crate::domain::math::formulations::Math;
+
+/// **単体のスラスタ**
+/// 推力方向は機体座標系 (制御器の座標系と一致しているとする) の単位ベクトル
+#[derive(Debug, Clone)]
+pub struct Thruster {
+    nominal_direction: Array1<f64>,  // 配分に使う設計上の推力方向
+    actual_direction: Array1<f64>,   // 実際の推力方向
+    max_thrust: f64,                 // 最大推力 (N)
+}
+
+impl Thruster {
+    pub fn new(direction: Array1<f64>, max_thrust: f64) -> Self {
+        let direction = Math::normalize(&direction);
+        Self {
+            nominal_direction: direction.clone(),
+            actual_direction: direction,
+            max_thrust,
+        }
+    }
+
+    /// **取り付け誤差のあるスラスタ**
+    /// 設計上の推力方向を 3-2-1 オイラー角だけ回転した方向に噴射する
+    pub fn misaligned(direction: Array1<f64>, max_thrust: f64, roll_rad: f64, pitch_rad: f64, yaw_rad: f64) -> Self {
+        let direction = Math::normalize(&direction);
+        let actual_direction = Math::dcm_from_euler_321(roll_rad, pitch_rad, yaw_rad).dot(&direction);
+        Self {
+            nominal_direction: direction,
+            actual_direction,
+            max_thrust,
+        }
+    }
+
+    pub fn max_thrust(&self) -> f64 {
+        self.max_thrust
+    }
+}
+
+/// **オンオフスラスタ群のアクチュエータ**
+/// 指令 (加速度) × 質量を各スラスタの推力に配分し, 制御周期 dt の中のオン時間に変換する.
+/// オン時間の量子化, 最小インパルスビット, 一次遅れ, 推力の大きさの誤差, 取り付け誤差を考慮して
+/// 実際に加わる加速度を返す. 乾燥質量を与えると, 受け取った推進剤質量で機体質量を更新する
+#[derive(Debug, Clone)]
+pub struct ThrusterActuator<U: Force> {
+    thrusters: Vec<Thruster>,
+    mass: f64,                  // 機体質量 (kg)
+    dry_mass: Option<f64>,      // 乾燥質量 (kg, None なら質量一定)
+    minimum_impulse_bit: f64,   // 最小インパルスビット (N s)
+    on_time_resolution: f64,    // オン時間の分解能 (s, 0 なら量子化しない)
+    time_constant: f64,         // 推力立ち上がりの時定数 (s, 0 なら遅れなし)
+    magnitude_noise: f64,       // 推力の大きさの誤差 (1σ, 比率)
+    thrust: Array1<f64>,        // 各スラスタの現在の推力 (N)
+    rng: StdRng,
+    _marker: PhantomData<U>,
+}
+
+impl<U: Force> ThrusterActuator<U> {
+    pub fn new(
+        thrusters: Vec<Thruster>,
+        mass: f64,
+        minimum_impulse_bit: f64,
+        on_time_resolution: f64,
+        time_constant: f64,
+        magnitude_noise: f64,
+        seed: u64,
+    ) -> Result<Self, &'static str> {
+        // 指令は推力方向と同じ 3 次元の力 (6 次元の力などは配分できない)
+        if U::zeros().get_vector().len() != 3 {
+            return Err("指令は 3 次元の力である必要があります。");
+        }
+        if thrusters.iter().any(|thruster| thruster.nominal_direction.len() != 3) {
+            return Err("スラスタの推力方向は 3 次元である必要があります。");
+        }
+        let thrust = Array1::zeros(thrusters.len());
+        Ok(Self {
+            thrusters,
+            mass,
+            dry_mass: None,
+            minimum_impulse_bit,
+            on_time_resolution,
+            time_constant,
+            magnitude_noise,
+            thrust,
+            rng: StdRng::seed_from_u64(seed),
+            _marker: PhantomData,
+        })
+    }
+
+    /// **乾燥質量を設定し, 推進剤質量から機体質量を更新するようにする**
+    pub fn with_dry_mass(mut self, dry_mass: f64) -> Self {
+        self.dry_mass = Some(dry_mass);
+        self
+    }
+
+    pub fn mass(&self) -> f64 {
+        self.mass
+    }
+
+    /// **設計上の推力方向を並べた行列 (3 × スラスタ数)**
+    fn nominal_matrix(&self) -> Array2<f64> {
+        let mut matrix = Array2::<f64>::zeros((3, self.thrusters.len()));
+        for (i, thruster) in self.thrusters.iter().enumerate() {
+            matrix.column_mut(i).assign(&thruster.nominal_direction);
+        }
+        matrix
+    }
+
+    /// **推力配分**
+    /// 0 ≤ f_i ≤ f_max_i の範囲で |D f - F|^2 を最小にする推力を射影勾配法で求める
+    pub fn allocate(&self, desired_force: &Array1<f64>) -> Array1<f64> {
+        let d = self.nominal_matrix();
+        let lipschitz = d.iter().map(|v| v * v).sum::<f64>().max(1e-12);
+        let mut thrust = Array1::<f64>::zeros(self.thrusters.len());
+
+        for _ in 0..200 {
+            let gradient = d.t().dot(&(d.dot(&thrust) - desired_force));
+            thrust = thrust - gradient / lipschitz;
+            for (f, thruster) in thrust.iter_mut().zip(self.thrusters.iter()) {
+                *f = f.clamp(0.0, thruster.max_thrust);
+            }
+        }
+        thrust
+    }
+
+    /// **配分した推力を制御周期内のオン時間に変換し, 周期平均の推力を返す**
+    fn quantize(&self, thrust: &Array1<f64>, dt: f64) -> Array1<f64> {
+        let mut average = Array1::<f64>::zeros(thrust.len());
+        for (i, thruster) in self.thrusters.iter().enumerate() {
+            let mut on_time = thrust[i] / thruster.max_thrust * dt;
+            if self.on_time_resolution > 0.0 {
+                on_time = ((on_time / self.on_time_resolution).round() * self.on_time_resolution).min(dt);
+            }
+            if on_time * thruster.max_thrust < self.minimum_impulse_bit {
+                on_time = 0.0;
+            }
+            average[i] = thruster.max_thrust * on_time / dt;
+        }
+        average
+    }
+}
+
+impl<U: Force> Actuator<U> for ThrusterActuator<U> {
+    fn actuate(&mut self, command: &U, _t: f64, dt: f64) -> U {
+        let desired_force = command.get_vector() * self.mass;
+        let target = self.quantize(&self.allocate(&desired_force), dt);
+
+        // 一次遅れ
+        if self.time_constant > 0.0 {
+            let gain = 1.0 - (-dt / self.time_constant).exp();
+            self.thrust = &self.thrust + &((&target - &self.thrust) * gain);
+        } else {
+            self.thrust = target;
+        }
+
+        let mut delivered = Array1::<f64>::zeros(command.get_vector().len());
+        for (i, thruster) in self.thrusters.iter().enumerate() {
+            let noise: f64 = StandardNormal.sample(&mut self.rng);
+            let thrust = (self.thrust[i] * (1.0 + self.magnitude_noise * noise)).max(0.0);
+            delivered = delivered + thrust * &thruster.actual_direction;
+        }
+        U::form_from_array(delivered / self.mass)
+    }
+
+    fn update_propellant(&mut self, propellant: f64) {
+        if let Some(dry_mass) = self.dry_mass {
+            self.mass = dry_mass + propellant.max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::force::force_6d_eci::Force6dEci;
+
+#[cfg(test)]
+fn axis_thrusters(max_thrust: f64) -> Vec<Thruster> {
+    [[1.0, 0.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, -1.0]]
+        .iter()
+        .map(|d| Thruster::new(arr1(d), max_thrust))
+        .collect()
+}
+
+#[test]
+fn test_thruster_allocation_and_minimum_impulse_bit() {
+    let mut actuator = ThrusterActuator::<Force3dLvlh>::new(axis_thrusters(1.0), 10.0, 1e-3, 0.0, 0.0, 0.0, 0).unwrap();
+
+    // 飽和しない指令はそのまま出力される (反対向きのスラスタは噴かない)
+    let allocation = actuator.allocate(&arr1(&[0.5, -0.2, 0.0]));
+    assert!((allocation[0] - 0.5).abs() < 1e-9 && allocation[1].abs() < 1e-9);
+    assert!((allocation[3] - 0.2).abs() < 1e-9 && allocation[2].abs() < 1e-9);
+
+    let delivered = actuator.actuate(&Force3dLvlh::form_from_list([0.05, -0.02, 0.0]), 0.0, 1.0);
+    assert!((delivered.get_vector() - &arr1(&[0.05, -0.02, 0.0])).iter().all(|v| v.abs() < 1e-9));
+
+    // 最小インパルスビット未満の指令は出力されない
+    let delivered = actuator.actuate(&Force3dLvlh::form_from_list([1e-5, 0.0, 0.0]), 1.0, 1.0);
+    assert!(delivered.get_vector().iter().all(|v| v.abs() < 1e-12));
+}
+
+#[test]
+fn test_thruster_lag_and_misalignment() {
+    let tau = 2.0;
+    let mut actuator = ThrusterActuator::<Force3dLvlh>::new(axis_thrusters(1.0), 1.0, 0.0, 0.0, tau, 0.0, 0).unwrap();
+    let command = Force3dLvlh::form_from_list([0.5, 0.0, 0.0]);
+
+    // 一次遅れで 1 周期後は 1 - exp(-dt/tau) 倍
+    let delivered = actuator.actuate(&command, 0.0, 1.0);
+    assert!((delivered.get_vector()[0] - 0.5 * (1.0 - (-0.5_f64).exp())).abs() < 1e-9);
+    for k in 1..50 {
+        actuator.actuate(&command, k as f64, 1.0);
+    }
+    assert!((actuator.actuate(&command, 50.0, 1.0).get_vector()[0] - 0.5).abs() < 1e-6);
+
+    // 取り付け誤差があると指令と異なる方向に推力が出る
+    let mut thrusters = axis_thrusters(1.0);
+    thrusters[0] = Thruster::misaligned(arr1(&[1.0, 0.0, 0.0]), 1.0, 0.0, 0.0, 0.1);
+    let mut actuator = ThrusterActuator::<Force3dLvlh>::new(thrusters, 1.0, 0.0, 0.0, 0.0, 0.0, 0).unwrap();
+    let delivered = actuator.actuate(&command, 0.0, 1.0);
+    assert!((delivered.get_vector()[1] - 0.5 * 0.1_f64.sin()).abs() < 1e-9);
+}
+
+#[test]
+fn test_thruster_actuator_uses_current_mass() {
+    let command = Force3dLvlh::form_from_list([0.01, 0.0, 0.0]);
+
+    // 乾燥質量 45 kg, 推進剤 5 kg → 1 kg: 同じ加速度指令に必要な推力は質量に比例して減る
+    let mut actuator = ThrusterActuator::<Force3dLvlh>::new(axis_thrusters(1.0), 50.0, 0.0, 0.0, 0.0, 0.0, 0).unwrap().with_dry_mass(45.0);
+    actuator.update_propellant(1.0);
+    assert!((actuator.mass() - 46.0).abs() < 1e-12);
+    let delivered = actuator.actuate(&command, 0.0, 1.0);
+    assert!((delivered.get_vector()[0] - 0.01).abs() < 1e-9);
+    assert!((actuator.thrust[0] - 0.46).abs() < 1e-9);
+
+    // 乾燥質量を与えなければ質量一定
+    let mut actuator = ThrusterActuator::<Force3dLvlh>::new(axis_thrusters(1.0), 50.0, 0.0, 0.0, 0.0, 0.0, 0).unwrap();
+    actuator.update_propellant(1.0);
+    assert_eq!(actuator.mass(), 50.0);
+}
+
+#[test]
+fn test_thruster_actuator_rejects_non_3d_command() {
+    assert!(ThrusterActuator::<Force6dEci>::new(axis_thrusters(1.0), 50.0, 0.0, 0.0, 0.0, 0.0, 0).is_err());
+    let planar = vec![Thruster::new(arr1(&[1.0, 0.0]), 1.0)];
+    assert!(ThrusterActuator::<Force3dLvlh>::new(planar, 50.0, 0.0, 0.0, 0.0, 0.0, 0).is_err());
+}
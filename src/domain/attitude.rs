@@ -0,0 +1 @@
+pub mod attitude_provider;
\ No newline at end of file
@@ -0,0 +1,218 @@
+use std::cell::{Cell, RefCell};
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use ndarray::{Array1, Array2};
+
+use crate::domain::math::formulations::Math;
+
+/// **姿勢を与えるトレイト**
+/// 機体座標系で定義した面 (`Surface`) などを慣性系に変換するのに使う
+pub trait AttitudeProvider: Debug {
+    /// 位置・速度 (ECI) と時刻 (エポックからの経過秒) から機体→ECI の方向余弦行列を返す
+    fn body_to_eci(&self, position_eci: &Array1<f64>, velocity_eci: &Array1<f64>, t: f64) -> Array2<f64>;
+}
+
+/// **慣性系固定の姿勢 (機体軸 = ECI 軸)**
+/// 機体座標系を導入する前の挙動と同じ
+#[derive(Debug, Clone, Default)]
+pub struct InertialAttitude;
+
+impl InertialAttitude {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AttitudeProvider for InertialAttitude {
+    fn body_to_eci(&self, _position_eci: &Array1<f64>, _velocity_eci: &Array1<f64>, _t: f64) -> Array2<f64> {
+        Array2::eye(3)
+    }
+}
+
+/// **LVLH 指向姿勢 (LVLH に対して一定のオイラー角)**
+/// 角度がすべて 0 なら機体 x 軸が動径方向, y 軸が進行方向, z 軸が軌道面法線を向く
+#[derive(Debug, Clone)]
+pub struct LvlhPointingAttitude {
+    body_to_lvlh: Array2<f64>,
+}
+
+impl LvlhPointingAttitude {
+    pub fn new(roll_rad: f64, pitch_rad: f64, yaw_rad: f64) -> Self {
+        Self {
+            body_to_lvlh: Math::dcm_from_euler_321(roll_rad, pitch_rad, yaw_rad),
+        }
+    }
+}
+
+impl AttitudeProvider for LvlhPointingAttitude {
+    fn body_to_eci(&self, position_eci: &Array1<f64>, velocity_eci: &Array1<f64>, _t: f64) -> Array2<f64> {
+        Math::mat_lvlh2eci(position_eci, velocity_eci).dot(&self.body_to_lvlh)
+    }
+}
+
+/// **姿勢コマンド (時刻 t 以降, LVLH に対するオイラー角)**
+#[derive(Debug, Clone)]
+pub struct AttitudeCommand {
+    pub t: f64,
+    pub roll_rad: f64,
+    pub pitch_rad: f64,
+    pub yaw_rad: f64,
+}
+
+/// **姿勢コマンドのプロファイル**
+/// 各コマンドを次のコマンド時刻まで保持する (最初のコマンドより前は最初のコマンド)
+#[derive(Debug, Clone)]
+pub struct CommandedAttitudeProfile {
+    commands: Vec<AttitudeCommand>,
+}
+
+impl CommandedAttitudeProfile {
+    pub fn new(mut commands: Vec<AttitudeCommand>) -> Result<Self, &'static str> {
+        if commands.is_empty() {
+            return Err("姿勢コマンドが空です。");
+        }
+        commands.sort_by(|a, b| a.t.total_cmp(&b.t));
+        Ok(Self { commands })
+    }
+
+    pub fn command_at(&self, t: f64) -> &AttitudeCommand {
+        let index = self.commands.partition_point(|c| c.t <= t);
+        &self.commands[index.saturating_sub(1)]
+    }
+}
+
+impl AttitudeProvider for CommandedAttitudeProfile {
+    fn body_to_eci(&self, position_eci: &Array1<f64>, velocity_eci: &Array1<f64>, t: f64) -> Array2<f64> {
+        let command = self.command_at(t);
+        let body_to_lvlh = Math::dcm_from_euler_321(command.roll_rad, command.pitch_rad, command.yaw_rad);
+        Math::mat_lvlh2eci(position_eci, velocity_eci).dot(&body_to_lvlh)
+    }
+}
+
+/// **姿勢状態 (機体→ECI のクォータニオン, 角速度)**
+#[derive(Debug, Clone)]
+pub struct AttitudeState {
+    pub quaternion: [f64; 4],        // スカラー先頭
+    pub angular_velocity: [f64; 3],  // 機体座標系 (rad/s)
+}
+
+impl AttitudeState {
+    pub fn new(quaternion: [f64; 4], angular_velocity: [f64; 3]) -> Self {
+        Self { quaternion, angular_velocity }
+    }
+}
+
+pub type AttitudeStateHandle = Rc<RefCell<AttitudeState>>;
+
+/// **外部から更新される姿勢状態を参照する姿勢**
+/// 姿勢ダイナミクスや姿勢制御側がハンドル経由で状態を書き換える
+#[derive(Debug, Clone)]
+pub struct SharedAttitudeState {
+    state: AttitudeStateHandle,
+}
+
+impl SharedAttitudeState {
+    pub fn new(state: AttitudeStateHandle) -> Self {
+        Self { state }
+    }
+
+    pub fn handle(&self) -> AttitudeStateHandle {
+        Rc::clone(&self.state)
+    }
+}
+
+impl AttitudeProvider for SharedAttitudeState {
+    fn body_to_eci(&self, _position_eci: &Array1<f64>, _velocity_eci: &Array1<f64>, _t: f64) -> Array2<f64> {
+        Math::dcm_from_quaternion(&self.state.borrow().quaternion)
+    }
+}
+
+/// **空力抵抗を大きく・小さくする姿勢の構成**
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragConfiguration {
+    HighDrag,
+    LowDrag,
+}
+
+pub type DragConfigurationHandle = Rc<Cell<DragConfiguration>>;
+
+/// **高抵抗・低抵抗の 2 つの LVLH 指向姿勢を切り替える姿勢**
+/// 差動抗力制御がハンドル経由で構成を選び, 流れを受ける面の実効面積を変える
+#[derive(Debug, Clone)]
+pub struct SwitchableDragAttitude {
+    high_drag: LvlhPointingAttitude,
+    low_drag: LvlhPointingAttitude,
+    configuration: DragConfigurationHandle,
+}
+
+impl SwitchableDragAttitude {
+    pub fn new(high_drag: LvlhPointingAttitude, low_drag: LvlhPointingAttitude, configuration: DragConfigurationHandle) -> Self {
+        Self { high_drag, low_drag, configuration }
+    }
+
+    pub fn handle(&self) -> DragConfigurationHandle {
+        Rc::clone(&self.configuration)
+    }
+}
+
+impl AttitudeProvider for SwitchableDragAttitude {
+    fn body_to_eci(&self, position_eci: &Array1<f64>, velocity_eci: &Array1<f64>, t: f64) -> Array2<f64> {
+        match self.configuration.get() {
+            DragConfiguration::HighDrag => self.high_drag.body_to_eci(position_eci, velocity_eci, t),
+            DragConfiguration::LowDrag => self.low_drag.body_to_eci(position_eci, velocity_eci, t),
+        }
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+
+#[test]
+fn test_attitude_providers() {
+    let position = arr1(&[7.0e6, 0.0, 0.0]);
+    let velocity = arr1(&[0.0, 7.5e3, 0.0]);
+    let x_body = arr1(&[1.0, 0.0, 0.0]);
+
+    // LVLH 指向で機体 x 軸は動径方向
+    let lvlh = LvlhPointingAttitude::new(0.0, 0.0, 0.0);
+    let x_eci = lvlh.body_to_eci(&position, &velocity, 0.0).dot(&x_body);
+    assert!((x_eci[0] - 1.0).abs() < 1e-12);
+
+    // ヨー 90 度で機体 x 軸は進行方向
+    let profile = CommandedAttitudeProfile::new(vec![
+        AttitudeCommand { t: 100.0, roll_rad: 0.0, pitch_rad: 0.0, yaw_rad: std::f64::consts::FRAC_PI_2 },
+        AttitudeCommand { t: 0.0, roll_rad: 0.0, pitch_rad: 0.0, yaw_rad: 0.0 },
+    ])
+    .unwrap();
+    let x_eci = profile.body_to_eci(&position, &velocity, 50.0).dot(&x_body);
+    assert!((x_eci[0] - 1.0).abs() < 1e-12);
+    let x_eci = profile.body_to_eci(&position, &velocity, 150.0).dot(&x_body);
+    assert!((x_eci[1] - 1.0).abs() < 1e-12);
+
+    // クォータニオンとオイラー角の z 軸回転が一致する
+    let angle: f64 = 0.3;
+    let shared = SharedAttitudeState::new(Rc::new(RefCell::new(AttitudeState::new(
+        [(angle / 2.0).cos(), 0.0, 0.0, (angle / 2.0).sin()],
+        [0.0, 0.0, 0.0],
+    ))));
+    let diff = shared.body_to_eci(&position, &velocity, 0.0) - Math::dcm_from_euler_321(0.0, 0.0, angle);
+    assert!(diff.iter().all(|v| v.abs() < 1e-12));
+
+    // ハンドル経由の更新が反映される
+    shared.handle().borrow_mut().quaternion = [1.0, 0.0, 0.0, 0.0];
+    let diff = shared.body_to_eci(&position, &velocity, 0.0) - Array2::<f64>::eye(3);
+    assert!(diff.iter().all(|v| v.abs() < 1e-12));
+
+    // 抵抗の構成をハンドル経由で切り替える
+    let switchable = SwitchableDragAttitude::new(
+        LvlhPointingAttitude::new(0.0, 0.0, 0.0),
+        LvlhPointingAttitude::new(0.0, 0.0, std::f64::consts::FRAC_PI_2),
+        Rc::new(Cell::new(DragConfiguration::HighDrag)),
+    );
+    let x_eci = switchable.body_to_eci(&position, &velocity, 0.0).dot(&x_body);
+    assert!((x_eci[0] - 1.0).abs() < 1e-12);
+    switchable.handle().set(DragConfiguration::LowDrag);
+    let x_eci = switchable.body_to_eci(&position, &velocity, 0.0).dot(&x_body);
+    assert!((x_eci[1] - 1.0).abs() < 1e-12);
+}
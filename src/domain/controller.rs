@@ -1,2 +1,13 @@
 pub mod controller_trait;
-pub mod mode_controller;
\ No newline at end of file
+pub mod mode_controller;
+pub mod pulse_modulator;
+pub mod lqr_controller;
+pub mod finite_horizon_lqr_controller;
+pub mod mpc_controller;
+pub mod ilqr_controller;
+pub mod roe_lyapunov_controller;
+pub mod differential_drag_controller;
+pub mod pd_controller;
+pub mod sliding_mode_controller;
+pub mod sample_and_hold_controller;
+pub mod controller_pipeline;
\ No newline at end of file
@@ -0,0 +1,418 @@
+use std::cell::{Ref, RefCell};
+use std::marker::PhantomData;
+
+use ndarray::Array1;
+
+use super::controller_trait::Controller;
+use crate::domain::actuator::actuator_trait::{Actuator, ActuatorLog};
+use crate::domain::force::force_converter::ForceConverter;
+use crate::domain::force::force_trait::Force;
+use crate::domain::force::force_6d_eci::Force6dEci;
+use crate::domain::force::thrust_acceleration_pair_eci::ThrustAccelerationPairEci;
+use crate::domain::state::position_velocity_mass_pair_state_eci::PositionVelocityMassPairStateEci;
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_converter::StateConverter;
+use crate::domain::state::state_trait::StateVector;
+
+/// **入力の飽和のかけ方**
+#[derive(Debug, Clone, Copy)]
+pub enum Saturation {
+    PerAxis(f64),  // 各軸を ±上限で切る
+    Norm(f64),     // 方向を保ったままノルムを上限以下にする
+}
+
+impl Saturation {
+    pub fn apply(&self, u: &Array1<f64>) -> Array1<f64> {
+        match *self {
+            Saturation::PerAxis(limit) => u.mapv(|v| v.clamp(-limit, limit)),
+            Saturation::Norm(limit) => {
+                let norm = u.dot(u).sqrt();
+                if norm > limit { u * (limit / norm) } else { u.clone() }
+            }
+        }
+    }
+}
+
+/// **不感帯のかけ方**
+#[derive(Debug, Clone, Copy)]
+pub enum DeadBand {
+    PerAxis(f64),  // 閾値未満の軸を 0 にする
+    Norm(f64),     // ノルムが閾値未満なら全体を 0 にする
+}
+
+impl DeadBand {
+    pub fn apply(&self, u: &Array1<f64>) -> Array1<f64> {
+        match *self {
+            DeadBand::PerAxis(threshold) => u.mapv(|v| if v.abs() < threshold { 0.0 } else { v }),
+            DeadBand::Norm(threshold) => {
+                if u.dot(u).sqrt() < threshold { Array1::zeros(u.len()) } else { u.clone() }
+            }
+        }
+    }
+}
+
+/// **制御器の座標系の入力をシミュレータの入力に変換するときの基準の ECI 状態**
+pub trait ReferenceStateEci {
+    fn reference_state_eci(&self) -> PositionVelocityStateEci;
+}
+
+impl ReferenceStateEci for PositionVelocityStateEci {
+    fn reference_state_eci(&self) -> PositionVelocityStateEci {
+        self.clone()
+    }
+}
+
+/// **ペアでは chief を基準にする (LVLH は chief 基準)**
+impl ReferenceStateEci for PositionVelocityPairStateEci {
+    fn reference_state_eci(&self) -> PositionVelocityStateEci {
+        PositionVelocityStateEci::form_from_array(self.chief())
+    }
+}
+
+impl ReferenceStateEci for PositionVelocityMassPairStateEci {
+    fn reference_state_eci(&self) -> PositionVelocityStateEci {
+        let pair: PositionVelocityPairStateEci = self.convert();
+        pair.reference_state_eci()
+    }
+}
+
+/// **deputy の現在の推進剤質量 (kg)**
+/// 推進剤を状態量に持たない場合は None (質量一定)
+pub trait PropellantMass {
+    fn propellant_mass_deputy(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl PropellantMass for PositionVelocityStateEci {}
+
+impl PropellantMass for PositionVelocityPairStateEci {}
+
+impl PropellantMass for PositionVelocityStateLvlh {}
+
+impl PropellantMass for PositionVelocityMassPairStateEci {
+    fn propellant_mass_deputy(&self) -> Option<f64> {
+        Some(self.propellant_deputy())
+    }
+}
+
+/// **入力を飽和させる**
+#[derive(Debug, Clone)]
+pub struct SaturationAdapter<C, T, U> {
+    inner: C,
+    saturation: Saturation,
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<C, T, U> SaturationAdapter<C, T, U> {
+    pub fn new(inner: C, saturation: Saturation) -> Self {
+        Self { inner, saturation, _marker: PhantomData }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: Controller<T, U>, T: StateVector, U: Force> Controller<T, U> for SaturationAdapter<C, T, U> {
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let u = self.inner.compute_control_input(state, t);
+        U::form_from_array(self.saturation.apply(u.get_vector()))
+    }
+}
+
+/// **入力に不感帯をかける**
+#[derive(Debug, Clone)]
+pub struct DeadBandAdapter<C, T, U> {
+    inner: C,
+    dead_band: DeadBand,
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<C, T, U> DeadBandAdapter<C, T, U> {
+    pub fn new(inner: C, dead_band: DeadBand) -> Self {
+        Self { inner, dead_band, _marker: PhantomData }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: Controller<T, U>, T: StateVector, U: Force> Controller<T, U> for DeadBandAdapter<C, T, U> {
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let u = self.inner.compute_control_input(state, t);
+        U::form_from_array(self.dead_band.apply(u.get_vector()))
+    }
+}
+
+/// **真値の状態量 T2 を制御器の状態量 T に変換してから渡す**
+#[derive(Debug, Clone)]
+pub struct StateConversionAdapter<C, T2, T, U> {
+    inner: C,
+    _marker: PhantomData<(T2, T, U)>,
+}
+
+impl<C, T2, T, U> StateConversionAdapter<C, T2, T, U> {
+    pub fn new(inner: C) -> Self {
+        Self { inner, _marker: PhantomData }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C, T2, T, U> Controller<T2, U> for StateConversionAdapter<C, T2, T, U>
+where
+    C: Controller<T, U>,
+    T2: StateVector + StateConverter<T>,
+    T: StateVector,
+    U: Force,
+{
+    fn compute_control_input(&self, state: &T2, t: f64) -> U {
+        self.inner.compute_control_input(&state.convert(), t)
+    }
+}
+
+/// **指令をアクチュエータに通し, 実際に加わる入力を返す**
+/// アクチュエータは呼び出しごとに dt の間の指令を実行したとして状態を進める.
+/// 状態量が推進剤を持つ場合は, 指令の前に現在の推進剤質量をアクチュエータに渡す
+#[derive(Debug)]
+pub struct ActuatorAdapter<C, A, T, U: Force> {
+    inner: C,
+    actuator: RefCell<A>,
+    dt: f64,
+    last_log: RefCell<Option<ActuatorLog<U>>>,
+    _marker: PhantomData<T>,
+}
+
+impl<C, A, T, U: Force> ActuatorAdapter<C, A, T, U> {
+    pub fn new(inner: C, actuator: A, dt: f64) -> Self {
+        Self { inner, actuator: RefCell::new(actuator), dt, last_log: RefCell::new(None), _marker: PhantomData }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn actuator(&self) -> Ref<'_, A> {
+        self.actuator.borrow()
+    }
+
+    /// **最後の呼び出しの指令値と実際の出力**
+    pub fn last_log(&self) -> Option<ActuatorLog<U>> {
+        self.last_log.borrow().clone()
+    }
+}
+
+impl<C: Controller<T, U>, A: Actuator<U>, T: StateVector + PropellantMass, U: Force> Controller<T, U> for ActuatorAdapter<C, A, T, U> {
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let command = self.inner.compute_control_input(state, t);
+        let mut actuator = self.actuator.borrow_mut();
+        if let Some(propellant) = state.propellant_mass_deputy() {
+            actuator.update_propellant(propellant);
+        }
+        let delivered = actuator.actuate(&command, t, self.dt);
+        *self.last_log.borrow_mut() = Some(ActuatorLog::new(command, delivered.clone()));
+        delivered
+    }
+}
+
+/// **制御器の入力 U をシミュレータの入力 U2 に変換する (基準は `ReferenceStateEci`)**
+#[derive(Debug, Clone)]
+pub struct ForceConversionAdapter<C, T, U, U2> {
+    inner: C,
+    _marker: PhantomData<(T, U, U2)>,
+}
+
+impl<C, T, U, U2> ForceConversionAdapter<C, T, U, U2> {
+    pub fn new(inner: C) -> Self {
+        Self { inner, _marker: PhantomData }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C, T, U, U2> Controller<T, U2> for ForceConversionAdapter<C, T, U, U2>
+where
+    C: Controller<T, U>,
+    T: StateVector + ReferenceStateEci,
+    U: Force + ForceConverter<U2>,
+    U2: Force,
+{
+    fn compute_control_input(&self, state: &T, t: f64) -> U2 {
+        self.inner.compute_control_input(state, t).convert(&state.reference_state_eci())
+    }
+}
+
+/// **制御器の加速度入力 U を deputy の推力 (N) にして `ThrustAccelerationPairEci` に変換する**
+/// 推力は ECI の加速度に現在の質量 (乾燥質量 + 推進剤質量) を掛けたもの. 推進剤を持たない状態量では乾燥質量を使う
+#[derive(Debug, Clone)]
+pub struct ThrustConversionAdapter<C, T, U> {
+    inner: C,
+    dry_mass_deputy: f64,
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<C, T, U> ThrustConversionAdapter<C, T, U> {
+    pub fn new(inner: C, dry_mass_deputy: f64) -> Self {
+        Self { inner, dry_mass_deputy, _marker: PhantomData }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C, T, U> Controller<T, ThrustAccelerationPairEci> for ThrustConversionAdapter<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector + ReferenceStateEci + PropellantMass,
+    U: Force + ForceConverter<Force6dEci>,
+{
+    fn compute_control_input(&self, state: &T, t: f64) -> ThrustAccelerationPairEci {
+        let acceleration: Force6dEci = self.inner.compute_control_input(state, t).convert(&state.reference_state_eci());
+        let mass = self.dry_mass_deputy + state.propellant_mass_deputy().unwrap_or(0.0).max(0.0);
+        ThrustAccelerationPairEci::form_from_thrust(&(acceleration * mass))
+    }
+}
+
+/// **アダプタをつなげて制御器のパイプラインを組み立てる**
+/// 例: `controller.saturate(..).convert_state::<PairState>().actuate(actuator, dt).convert_force::<Force6dEci>()`
+pub trait ControllerPipeline<T: StateVector, U: Force>: Controller<T, U> + Sized {
+    fn saturate(self, saturation: Saturation) -> SaturationAdapter<Self, T, U> {
+        SaturationAdapter::new(self, saturation)
+    }
+
+    fn dead_band(self, dead_band: DeadBand) -> DeadBandAdapter<Self, T, U> {
+        DeadBandAdapter::new(self, dead_band)
+    }
+
+    fn convert_state<T2: StateVector + StateConverter<T>>(self) -> StateConversionAdapter<Self, T2, T, U> {
+        StateConversionAdapter::new(self)
+    }
+
+    fn actuate<A: Actuator<U>>(self, actuator: A, dt: f64) -> ActuatorAdapter<Self, A, T, U> {
+        ActuatorAdapter::new(self, actuator, dt)
+    }
+
+    fn convert_force<U2: Force>(self) -> ForceConversionAdapter<Self, T, U, U2>
+    where
+        T: ReferenceStateEci,
+        U: ForceConverter<U2>,
+    {
+        ForceConversionAdapter::new(self)
+    }
+
+    fn convert_to_thrust(self, dry_mass_deputy: f64) -> ThrustConversionAdapter<Self, T, U>
+    where
+        T: ReferenceStateEci + PropellantMass,
+        U: ForceConverter<Force6dEci>,
+    {
+        ThrustConversionAdapter::new(self, dry_mass_deputy)
+    }
+}
+
+impl<C: Controller<T, U>, T: StateVector, U: Force> ControllerPipeline<T, U> for C {}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::actuator::thruster_actuator::{Thruster, ThrusterActuator};
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::state::orbital_elements::OrbitalElements;
+#[cfg(test)]
+use crate::infrastructure::logger::loggable_trait::Loggable;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+/// **相対位置の -1e-5 倍を入力にする制御器**
+#[cfg(test)]
+struct ProportionalController;
+
+#[cfg(test)]
+impl Controller<PositionVelocityStateLvlh, Force3dLvlh> for ProportionalController {
+    fn compute_control_input(&self, state: &PositionVelocityStateLvlh, _t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(state.position() * -1e-5)
+    }
+}
+
+/// **指令の半分だけを出すアクチュエータ**
+#[cfg(test)]
+struct HalfActuator;
+
+#[cfg(test)]
+impl Actuator<Force3dLvlh> for HalfActuator {
+    fn actuate(&mut self, command: &Force3dLvlh, _t: f64, _dt: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(command.get_vector() * 0.5)
+    }
+}
+
+#[test]
+fn test_controller_pipeline() {
+    let u = arr1(&[3.0, -4.0, 0.5]);
+    assert_eq!(Saturation::PerAxis(1.0).apply(&u), arr1(&[1.0, -1.0, 0.5]));
+    let saturated = Saturation::Norm(1.0).apply(&u);
+    assert!((saturated.dot(&saturated).sqrt() - 1.0).abs() < 1e-12 && (saturated[0] / saturated[1] + 0.75).abs() < 1e-12);
+    assert_eq!(DeadBand::PerAxis(1.0).apply(&u), arr1(&[3.0, -4.0, 0.0]));
+    assert_eq!(DeadBand::Norm(10.0).apply(&u), arr1(&[0.0, 0.0, 0.0]));
+
+    // 真値のペアの状態から ECI のペアの入力までをつなげる
+    let a = CONSTANTS.radius + 500.0e3;
+    let chief = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, 0.0).unwrap();
+    let deputy = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, 300.0 / a).unwrap();
+    let pair: PositionVelocityPairStateEci = vec![chief, deputy].convert();
+    let pipeline = ProportionalController
+        .dead_band(DeadBand::PerAxis(1e-6))
+        .saturate(Saturation::PerAxis(1e-3))
+        .convert_state::<PositionVelocityPairStateEci>()
+        .actuate(HalfActuator, 1.0)
+        .convert_force::<Force6dEci>();
+    let force = pipeline.compute_control_input(&pair, 0.0);
+
+    // 手で変換した結果と一致する (deputy は約 300 m 前方なので y だけ飽和, 他は不感帯で 0)
+    let lvlh: PositionVelocityStateLvlh = pair.convert();
+    let command = Saturation::PerAxis(1e-3).apply(&DeadBand::PerAxis(1e-6).apply(&(lvlh.position() * -1e-5)));
+    assert!(command[0] == 0.0 && command[1] == -1e-3 && command[2] == 0.0);
+    let expected: Force6dEci = Force3dLvlh::form_from_array(command.clone() * 0.5).convert(&pair.reference_state_eci());
+    assert!((force.get_vector() - expected.get_vector()).iter().all(|v| v.abs() < 1e-15));
+
+    let log = pipeline.inner().last_log().unwrap();
+    let log_expected = ActuatorLog::new(Force3dLvlh::form_from_array(command.clone()), Force3dLvlh::form_from_array(command * 0.5));
+    assert_eq!(log.output_log(), log_expected.output_log());
+}
+
+#[test]
+fn test_thrust_conversion_uses_current_mass() {
+    let a = CONSTANTS.radius + 500.0e3;
+    let chief = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, 0.0).unwrap();
+    let deputy = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, 300.0 / a).unwrap();
+    let pair: PositionVelocityPairStateEci = vec![chief, deputy].convert();
+    let state = PositionVelocityMassPairStateEci::form_from_pair(&pair, 5.0, 2.0);
+
+    // 推進剤 2 kg, 乾燥質量 45 kg: アクチュエータと推力への換算はどちらも 47 kg を使う
+    let thrusters = [[1.0, 0.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, -1.0]]
+        .iter()
+        .map(|d| Thruster::new(arr1(d), 1.0))
+        .collect();
+    let actuator = ThrusterActuator::<Force3dLvlh>::new(thrusters, 50.0, 0.0, 0.0, 0.0, 0.0, 0).unwrap().with_dry_mass(45.0);
+    let pipeline = ProportionalController
+        .convert_state::<PositionVelocityMassPairStateEci>()
+        .actuate(actuator, 1.0)
+        .convert_to_thrust(45.0);
+    let force = pipeline.compute_control_input(&state, 0.0);
+    assert!((pipeline.inner().actuator().mass() - 47.0).abs() < 1e-12);
+
+    let lvlh: PositionVelocityStateLvlh = state.convert();
+    let acceleration: Force6dEci = Force3dLvlh::form_from_array(lvlh.position() * -1e-5).convert(&state.reference_state_eci());
+    let expected = acceleration.get_vector() * 47.0;
+    assert!((force.get_vector().slice(ndarray::s![0..6]).to_owned() - expected).iter().all(|v| v.abs() < 1e-9));
+    assert!(force.acceleration_deputy().iter().all(|v| *v == 0.0));
+}
@@ -0,0 +1,288 @@
+use ndarray::{Array2, arr1};
+
+use super::controller_trait::Controller;
+use super::mode_controller::mode_optimizer::ModeId;
+use super::mode_controller::wrapper::InputDefinedDynamics;
+use crate::domain::attitude::attitude_provider::{DragConfiguration, DragConfigurationHandle};
+use crate::domain::differentiable::differentiable_trait::Differentiable2d;
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::relative_orbital_elements::RelativeOrbitalElements;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+
+/// **差動抗力のモード (chief と deputy の抵抗の構成の組)**
+/// ModeId は 0: (高, 高), 1: (高, 低), 2: (低, 高), 3: (低, 低) の順 (chief, deputy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DragMode {
+    pub chief: DragConfiguration,
+    pub deputy: DragConfiguration,
+}
+
+impl DragMode {
+    pub const ALL: [DragMode; 4] = [
+        DragMode { chief: DragConfiguration::HighDrag, deputy: DragConfiguration::HighDrag },
+        DragMode { chief: DragConfiguration::HighDrag, deputy: DragConfiguration::LowDrag },
+        DragMode { chief: DragConfiguration::LowDrag, deputy: DragConfiguration::HighDrag },
+        DragMode { chief: DragConfiguration::LowDrag, deputy: DragConfiguration::LowDrag },
+    ];
+
+    pub fn mode_id(&self) -> ModeId {
+        let index = Self::ALL.iter().position(|mode| mode == self).unwrap_or(0);
+        ModeId::new(index)
+    }
+
+    pub fn from_mode_id(mode_id: ModeId) -> Option<Self> {
+        Self::ALL.iter().copied().find(|mode| mode.mode_id() == mode_id)
+    }
+}
+
+/// **各構成での抗力による減速度 (m/s^2, 進行方向の大きさ)**
+#[derive(Debug, Clone)]
+pub struct DifferentialDragModel {
+    pub chief_high: f64,
+    pub chief_low: f64,
+    pub deputy_high: f64,
+    pub deputy_low: f64,
+}
+
+impl DifferentialDragModel {
+    /// **chief に対する deputy の進行方向の相対加速度**
+    pub fn relative_acceleration(&self, mode: &DragMode) -> f64 {
+        let chief = match mode.chief {
+            DragConfiguration::HighDrag => self.chief_high,
+            DragConfiguration::LowDrag => self.chief_low,
+        };
+        let deputy = match mode.deputy {
+            DragConfiguration::HighDrag => self.deputy_high,
+            DragConfiguration::LowDrag => self.deputy_low,
+        };
+        chief - deputy
+    }
+}
+
+/// **差動抗力による進行方向の間隔の制御器**
+/// x = a δλ (進行方向の平均的な間隔), ẋ = -1.5 n a δa として切り替え面 s = (x - x_target) + τ ẋ を作り,
+/// s > deadband なら相対加速度が最大, s < -deadband なら最小, その間は絶対値が最小のモードを選ぶ.
+/// 選んだ構成は姿勢のハンドルに書き込み, 推力は出さない
+#[derive(Debug, Clone)]
+pub struct DifferentialDragController {
+    model: DifferentialDragModel,
+    target_along_track_m: f64,
+    time_constant: f64,
+    deadband_m: f64,
+    chief_configuration: DragConfigurationHandle,
+    deputy_configuration: DragConfigurationHandle,
+    mu: f64,
+}
+
+impl DifferentialDragController {
+    pub fn new(
+        model: DifferentialDragModel,
+        target_along_track_m: f64,
+        time_constant: f64,
+        deadband_m: f64,
+        chief_configuration: DragConfigurationHandle,
+        deputy_configuration: DragConfigurationHandle,
+        mu: f64,
+    ) -> Result<Self, &'static str> {
+        if time_constant <= 0.0 || deadband_m < 0.0 {
+            return Err("時定数は正, 不感帯は 0 以上である必要があります。");
+        }
+        Ok(Self { model, target_along_track_m, time_constant, deadband_m, chief_configuration, deputy_configuration, mu })
+    }
+
+    /// **切り替え面の値 (m)**
+    pub fn switching_function(&self, pair: &PositionVelocityPairStateEci) -> f64 {
+        let roe = RelativeOrbitalElements::from_pair(pair, self.mu);
+        let chief = RelativeOrbitalElements::nonsingular_elements(&PositionVelocityStateEci::form_from_array(pair.chief()), self.mu);
+        let a = chief[0];
+        let n = (self.mu / a.powi(3)).sqrt();
+        let along_track = a * roe.get_vector()[1];
+        let drift_rate = -1.5 * n * a * roe.get_vector()[0];
+        along_track - self.target_along_track_m + self.time_constant * drift_rate
+    }
+
+    pub fn select_mode(&self, pair: &PositionVelocityPairStateEci) -> DragMode {
+        let s = self.switching_function(pair);
+        let acceleration = |mode: &DragMode| self.model.relative_acceleration(mode);
+        let modes = DragMode::ALL.iter();
+        let selected = if s > self.deadband_m {
+            modes.max_by(|a, b| acceleration(a).total_cmp(&acceleration(b)))
+        } else if s < -self.deadband_m {
+            modes.min_by(|a, b| acceleration(a).total_cmp(&acceleration(b)))
+        } else {
+            modes.min_by(|a, b| acceleration(a).abs().total_cmp(&acceleration(b).abs()))
+        };
+        *selected.expect("モードがありません。")
+    }
+
+    /// **モードの構成を姿勢に反映する**
+    pub fn apply_mode(&self, mode: &DragMode) {
+        self.chief_configuration.set(mode.chief);
+        self.deputy_configuration.set(mode.deputy);
+    }
+
+    /// **ModeScheduler などで選んだ ModeId を姿勢に反映する**
+    pub fn apply_mode_id(&self, mode_id: ModeId) -> Result<(), &'static str> {
+        let mode = DragMode::from_mode_id(mode_id).ok_or("差動抗力のモードではありません。")?;
+        self.apply_mode(&mode);
+        Ok(())
+    }
+
+    pub fn model(&self) -> &DifferentialDragModel {
+        &self.model
+    }
+}
+
+impl Controller<PositionVelocityPairStateEci, Force3dLvlh> for DifferentialDragController {
+    fn compute_control_input(&self, state: &PositionVelocityPairStateEci, _t: f64) -> Force3dLvlh {
+        self.apply_mode(&self.select_mode(state));
+        Force3dLvlh::zeros()
+    }
+}
+
+/// **差動抗力のモードごとのダイナミクス (HCW + 一定の進行方向の相対加速度)**
+/// ModeScheduler で最適化するためのもので, 推力の入力は無視する
+#[derive(Debug, Clone)]
+pub struct DifferentialDragModeDynamics {
+    hcw: HcwDynamics,
+    relative_acceleration: f64,
+}
+
+impl DifferentialDragModeDynamics {
+    pub fn new(a: f64, model: &DifferentialDragModel, mode: &DragMode) -> Self {
+        Self {
+            hcw: HcwDynamics::new(a),
+            relative_acceleration: model.relative_acceleration(mode),
+        }
+    }
+}
+
+impl ContinuousDynamics<PositionVelocityStateLvlh, Force3dLvlh> for DifferentialDragModeDynamics {
+    fn compute_derivative(&self, state: &PositionVelocityStateLvlh, _: &Force3dLvlh, t: f64) -> PositionVelocityStateLvlh {
+        let drag = Force3dLvlh::form_from_array(arr1(&[0.0, self.relative_acceleration, 0.0]));
+        self.hcw.compute_derivative(state, &drag, t)
+    }
+}
+
+impl Differentiable2d<PositionVelocityStateLvlh, Force3dLvlh> for DifferentialDragModeDynamics {
+    fn differentiate(&self, x: &PositionVelocityStateLvlh, _: &Force3dLvlh, t: f64) -> Array2<f64> {
+        self.hcw.differentiate(x, &Force3dLvlh::zeros(), t)
+    }
+}
+
+impl InputDefinedDynamics<PositionVelocityStateLvlh, Force3dLvlh> for DifferentialDragModeDynamics {
+    fn get_input(&self, _: &PositionVelocityStateLvlh, _: f64) -> Force3dLvlh {
+        Force3dLvlh::zeros()
+    }
+
+    fn set_noise(&mut self, _: &Array2<f64>) {
+    }
+}
+
+#[cfg(test)]
+use std::cell::Cell;
+#[cfg(test)]
+use std::rc::Rc;
+#[cfg(test)]
+use crate::application::simulator::simulator::Simulator;
+#[cfg(test)]
+use crate::domain::attitude::attitude_provider::{LvlhPointingAttitude, SwitchableDragAttitude};
+#[cfg(test)]
+use crate::domain::disturbance::air_drag_disturbance::{AirDragStatePairEci, Surface};
+#[cfg(test)]
+use crate::domain::disturbance::atmosphere_model::ExponentialAtmosphere;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_2sat_2body::PairTwoBodyDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+#[cfg(test)]
+use crate::domain::force::force_6d_eci::Force6dEci;
+#[cfg(test)]
+use crate::domain::state::orbital_elements::OrbitalElements;
+#[cfg(test)]
+use crate::domain::state::state_converter::StateConverter;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_drag_mode_ids_and_mode_dynamics() {
+    for (k, mode) in DragMode::ALL.iter().enumerate() {
+        assert!(mode.mode_id() == ModeId::new(k));
+        assert_eq!(DragMode::from_mode_id(ModeId::new(k)), Some(*mode));
+    }
+    assert_eq!(DragMode::from_mode_id(ModeId::new(4)), None);
+
+    // モードのダイナミクスの差は進行方向の相対加速度だけ
+    let model = DifferentialDragModel { chief_high: 3e-6, chief_low: 1e-6, deputy_high: 2e-6, deputy_low: 5e-7 };
+    let a = CONSTANTS.radius + 400.0e3;
+    let state = PositionVelocityStateLvlh::form_from_list([10.0, -50.0, 0.0], [0.01, 0.0, 0.0]);
+    let high_low = DifferentialDragModeDynamics::new(a, &model, &DragMode::ALL[1]);
+    let low_high = DifferentialDragModeDynamics::new(a, &model, &DragMode::ALL[2]);
+    let diff = high_low.compute_derivative(&state, &Force3dLvlh::zeros(), 0.0).get_vector()
+        - low_high.compute_derivative(&state, &Force3dLvlh::zeros(), 0.0).get_vector();
+    let expected = (3e-6 - 5e-7) - (1e-6 - 2e-6);
+    assert!((diff[4] - expected).abs() < 1e-15);
+    assert!(diff.iter().enumerate().filter(|(i, _)| *i != 4).all(|(_, v)| v.abs() < 1e-15));
+}
+
+#[test]
+fn test_differential_drag_along_track_control() {
+    // 進行方向を向く面だけが大きい機体. ヨー 90 度で大きい面が流れと平行になる
+    let surface = |area_m2: f64, normal: [f64; 3]| Surface {
+        air_specularity: 0.4, specular_reflectivity: 0.1, diffuse_reflectivity: 0.3, area_m2, normal_direction: arr1(&normal),
+    };
+    let surfaces = vec![
+        surface(2.0, [0.0, -1.0, 0.0]), surface(2.0, [0.0, 1.0, 0.0]),
+        surface(0.2, [-1.0, 0.0, 0.0]), surface(0.2, [1.0, 0.0, 0.0]),
+    ];
+    let attitude = |configuration: &DragConfigurationHandle| Box::new(SwitchableDragAttitude::new(
+        LvlhPointingAttitude::new(0.0, 0.0, 0.0),
+        LvlhPointingAttitude::new(0.0, 0.0, std::f64::consts::FRAC_PI_2),
+        configuration.clone(),
+    ));
+    let chief_configuration = Rc::new(Cell::new(DragConfiguration::LowDrag));
+    let deputy_configuration = Rc::new(Cell::new(DragConfiguration::LowDrag));
+    let drag = AirDragStatePairEci::new(
+        18.0, 30.0, 3.0, 50.0, surfaces.clone(), attitude(&chief_configuration),
+        18.0, 30.0, 50.0, surfaces, attitude(&deputy_configuration),
+        Box::new(ExponentialAtmosphere::new()), None,
+    );
+
+    let a = CONSTANTS.radius + 350.0e3;
+    let chief = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, 0.0).unwrap();
+    let deputy = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, -100.0 / a).unwrap();
+    let pair: PositionVelocityPairStateEci = vec![chief, deputy].convert();
+    let mut simulator = Simulator::new(RungeKutta4Propagator, PairTwoBodyDynamics::new(), pair, 10.0, 2000, 0.0);
+    simulator.add_disturbance(Box::new(drag));
+
+    // 高度 350 km で大きい面が流れを受けると約 2e-5 m/s^2, 平行なら約 1/10
+    let model = DifferentialDragModel { chief_high: 2.0e-5, chief_low: 2.0e-6, deputy_high: 2.0e-5, deputy_low: 2.0e-6 };
+    let controller = DifferentialDragController::new(
+        model, 100.0, 1000.0, 2.0, chief_configuration.clone(), deputy_configuration.clone(), CONSTANTS.mu,
+    ).unwrap();
+
+    let mut switched = 0;
+    let mut previous = controller.select_mode(simulator.get_state());
+    for _ in 0..simulator.step {
+        let input = controller.compute_control_input(simulator.get_state(), simulator.t);
+        assert!(input.get_vector().iter().all(|v| *v == 0.0));
+        let mode = DragMode { chief: chief_configuration.get(), deputy: deputy_configuration.get() };
+        if mode != previous {
+            switched += 1;
+            previous = mode;
+        }
+        simulator.update(&Force6dEci::zeros());
+    }
+
+    // 後方 100 m から前方 100 m へ移り, ドリフトも止まる
+    let roe = RelativeOrbitalElements::from_pair(simulator.get_state(), CONSTANTS.mu);
+    assert!(switched > 0);
+    assert!((a * roe.get_vector()[1] - 100.0).abs() < 10.0);
+    assert!(controller.switching_function(simulator.get_state()).abs() < 20.0);
+}
@@ -0,0 +1,313 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Sub, Mul, Div};
+
+use ndarray::{Array1, Array2};
+use ndarray_linalg::Inverse;
+
+use super::controller_trait::Controller;
+use crate::domain::cost::quadric_cost::QuadraticCost;
+use crate::domain::differentiable::differentiable_trait::{Differentiable2d, InputDifferentiable2d};
+use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
+use crate::domain::dynamics::propagator::Propagator;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::state_trait::StateVector;
+
+/// **リカッチ行列 P を並べた状態量 (行優先で n × n)**
+#[derive(Debug, Clone)]
+pub struct RiccatiState {
+    p: Array1<f64>,
+}
+
+impl RiccatiState {
+    pub fn form_from_matrix(p: &Array2<f64>) -> Self {
+        Self { p: Array1::from_iter(p.iter().cloned()) }
+    }
+
+    pub fn matrix(&self) -> Array2<f64> {
+        let n = (self.p.len() as f64).sqrt().round() as usize;
+        Array2::from_shape_vec((n, n), self.p.to_vec()).expect("RiccatiState must be a square matrix")
+    }
+}
+
+impl StateVector for RiccatiState {
+    fn get_vector(&self) -> &Array1<f64> {
+        &self.p
+    }
+
+    fn form_from_array(vec: Array1<f64>) -> Self {
+        Self { p: vec }
+    }
+}
+
+impl Add for RiccatiState {
+    type Output = RiccatiState;
+    fn add(self, rhs: RiccatiState) -> RiccatiState {
+        self.add_vec(&rhs)
+    }
+}
+
+impl Sub for RiccatiState {
+    type Output = RiccatiState;
+    fn sub(self, rhs: RiccatiState) -> RiccatiState {
+        self.sub_vec(&rhs)
+    }
+}
+
+impl Mul<f64> for RiccatiState {
+    type Output = RiccatiState;
+    fn mul(self, scalar: f64) -> RiccatiState {
+        self.mul_scalar(scalar)
+    }
+}
+
+impl Div<f64> for RiccatiState {
+    type Output = RiccatiState;
+    fn div(self, scalar: f64) -> RiccatiState {
+        self.div_scalar(scalar)
+    }
+}
+
+/// **ノミナル軌道 x̄(t), ū(t)**
+/// 線形化の基準とフィードバックの基準に使う. 線形システムなら原点 (x̄ = 0, ū = 0) でよい
+pub type NominalTrajectory<'a, T, U> = &'a dyn Fn(f64) -> (T, U);
+
+/// **逆時間 τ = t_f - t でのリカッチ微分方程式**
+/// dP/dτ = A^T P + P A - P B R^{-1} B^T P + Q (A(t), B(t) はノミナル軌道 x̄(t), ū(t) まわりのヤコビアン)
+struct BackwardRiccatiDynamics<'a, T, U, D> {
+    dynamics: &'a D,
+    nominal: NominalTrajectory<'a, T, U>,
+    q_matrix: &'a Array2<f64>,
+    r_inv: &'a Array2<f64>,
+    t_last: f64,
+}
+
+impl<T, U, D> ContinuousDynamics<RiccatiState, U> for BackwardRiccatiDynamics<'_, T, U, D>
+where
+    T: StateVector,
+    U: Force,
+    D: Differentiable2d<T, U> + InputDifferentiable2d<T, U>,
+{
+    fn compute_derivative(&self, state: &RiccatiState, _input: &U, tau: f64) -> RiccatiState {
+        let t = self.t_last - tau;
+        let (nominal_state, nominal_input) = (self.nominal)(t);
+        let a = self.dynamics.differentiate(&nominal_state, &nominal_input, t);
+        let b = self.dynamics.differentiate_input(&nominal_state, &nominal_input, t);
+        let p = state.matrix();
+        let derivative = a.t().dot(&p) + p.dot(&a) - p.dot(&b).dot(self.r_inv).dot(&b.t()).dot(&p) + self.q_matrix;
+        RiccatiState::form_from_matrix(&derivative)
+    }
+}
+
+/// **有限時間 LQR 制御器**
+/// ノミナル軌道 x̄(t), ū(t) まわりに線形化したダイナミクスについて, 終端重み Q_f から
+/// リカッチ微分方程式を逆時間に積分してゲイン K(t) = R^{-1} B(t)^T P(t) を刻み dt ごとに保存し,
+/// 時刻に応じて線形補間して u = ū(t) - K(t) (x - x̄(t)) を返す.
+/// 終端時刻より後は終端のゲインとノミナル軌道を使う
+#[derive(Debug, Clone)]
+pub struct FiniteHorizonLqrController<T: StateVector, U: Force> {
+    gains: Vec<Array2<f64>>,
+    nominal_states: Vec<Array1<f64>>,
+    nominal_inputs: Vec<Array1<f64>>,
+    t0: f64,
+    dt: f64,
+    u_max: Option<f64>,
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<T, U> FiniteHorizonLqrController<T, U>
+where
+    T: StateVector,
+    U: Force + Add<Output = U> + Sub<Output = U> + Mul<f64, Output = U> + Div<f64, Output = U>,
+{
+    pub fn new<D, P>(
+        dynamics: &D,
+        cost: &QuadraticCost,
+        propagator: &P,
+        nominal: NominalTrajectory<'_, T, U>,
+        t0: f64,
+        dt: f64,
+        u_max: Option<f64>,
+    ) -> Result<Self, &'static str>
+    where
+        D: Differentiable2d<T, U> + InputDifferentiable2d<T, U>,
+        P: Propagator<RiccatiState, U>,
+    {
+        let t_last = cost.t_last();
+        if t_last <= t0 {
+            return Err("終端時刻が開始時刻以前です。");
+        }
+        let r_inv = cost.r_matrix().inv().map_err(|_| "R が正則ではありません。")?;
+        let riccati = BackwardRiccatiDynamics {
+            dynamics,
+            nominal,
+            q_matrix: cost.q_matrix(),
+            r_inv: &r_inv,
+            t_last,
+        };
+
+        let steps = ((t_last - t0) / dt).ceil() as usize;
+        let mut gains = Vec::with_capacity(steps + 1);
+        let mut nominal_states = Vec::with_capacity(steps + 1);
+        let mut nominal_inputs = Vec::with_capacity(steps + 1);
+        let mut push = |p: &RiccatiState, t: f64| {
+            let (nominal_state, nominal_input) = nominal(t);
+            let b = dynamics.differentiate_input(&nominal_state, &nominal_input, t);
+            gains.push(r_inv.dot(&b.t()).dot(&p.matrix()));
+            nominal_states.push(nominal_state.get_vector().clone());
+            nominal_inputs.push(nominal_input.get_vector().clone());
+        };
+
+        // 終端から逆向きに積分し, 最後に時刻順に並べ直す
+        let mut p = RiccatiState::form_from_matrix(cost.qf_matrix());
+        push(&p, t_last);
+        for k in 0..steps {
+            let tau = k as f64 * dt;
+            p = propagator.propagate_continuous(&p, &U::zeros(), &riccati, tau, dt);
+            push(&p, t_last - tau - dt);
+        }
+        gains.reverse();
+        nominal_states.reverse();
+        nominal_inputs.reverse();
+
+        Ok(Self {
+            gains,
+            nominal_states,
+            nominal_inputs,
+            t0: t_last - steps as f64 * dt,
+            dt,
+            u_max,
+            _marker: PhantomData,
+        })
+    }
+
+    /// **時刻 t のゲイン (線形補間)**
+    pub fn gain(&self, t: f64) -> Array2<f64> {
+        let (k, w) = self.interpolation_weight(t);
+        match w {
+            Some(w) => &self.gains[k] * (1.0 - w) + &self.gains[k + 1] * w,
+            None => self.gains[k].clone(),
+        }
+    }
+
+    /// **時刻 t のノミナル状態と入力 (線形補間)**
+    pub fn nominal(&self, t: f64) -> (Array1<f64>, Array1<f64>) {
+        let (k, w) = self.interpolation_weight(t);
+        match w {
+            Some(w) => (
+                &self.nominal_states[k] * (1.0 - w) + &self.nominal_states[k + 1] * w,
+                &self.nominal_inputs[k] * (1.0 - w) + &self.nominal_inputs[k + 1] * w,
+            ),
+            None => (self.nominal_states[k].clone(), self.nominal_inputs[k].clone()),
+        }
+    }
+
+    /// 時刻 t を挟む格子点の番号と補間の重み (範囲外なら端の格子点だけ)
+    fn interpolation_weight(&self, t: f64) -> (usize, Option<f64>) {
+        let s = ((t - self.t0) / self.dt).max(0.0);
+        let k = (s.floor() as usize).min(self.gains.len() - 1);
+        if k + 1 >= self.gains.len() {
+            return (k, None);
+        }
+        (k, Some(s - k as f64))
+    }
+}
+
+impl<T, U> Controller<T, U> for FiniteHorizonLqrController<T, U>
+where
+    T: StateVector,
+    U: Force + Add<Output = U> + Sub<Output = U> + Mul<f64, Output = U> + Div<f64, Output = U>,
+{
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let (nominal_state, nominal_input) = self.nominal(t);
+        let u = nominal_input - self.gain(t).dot(&(state.get_vector() - &nominal_state));
+        match self.u_max {
+            Some(u_max) => U::form_from_array(u.mapv(|v| v.clamp(-u_max, u_max))),
+            None => U::form_from_array(u),
+        }
+    }
+}
+
+#[cfg(test)]
+use super::lqr_controller::LqrController;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_2body::TwoBodyDynamics;
+#[cfg(test)]
+use crate::domain::force::force_3d_eci::Force3dEci;
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+#[cfg(test)]
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_finite_horizon_lqr_gain_schedule() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let (q, r, qf) = (Array2::<f64>::eye(6) * 1e-4, Array2::<f64>::eye(3), Array2::<f64>::eye(6) * 0.1);
+    let cost = QuadraticCost::new(q.clone(), r.clone(), qf, 2000.0);
+    let origin = |_: f64| (PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]), Force3dLvlh::zeros());
+
+    let controller = FiniteHorizonLqrController::<PositionVelocityStateLvlh, Force3dLvlh>::new(
+        &dynamics, &cost, &RungeKutta4Propagator, &origin, 0.0, 1.0, None,
+    )
+    .unwrap();
+
+    // 終端では K = R^{-1} B^T Q_f (速度成分に 0.1)
+    let terminal = controller.gain(2000.0);
+    assert!((terminal[[0, 3]] - 0.1).abs() < 1e-12 && terminal[[0, 0]].abs() < 1e-12);
+
+    // 終端から十分離れると無限時間 LQR のゲインに近づく
+    let lqr = LqrController::from_linear_system(&dynamics, &QuadraticCost::new(q, r, Array2::eye(6), 0.0), None).unwrap();
+    let diff = controller.gain(0.0) - lqr.gain();
+    assert!(diff.iter().all(|v| v.abs() < 1e-6 * lqr.gain().iter().fold(0.0_f64, |m, v| m.max(v.abs()))));
+
+    // 格子点の間は線形補間
+    let mid = controller.gain(1999.5);
+    let expected = (controller.gain(1999.0) + controller.gain(2000.0)) * 0.5;
+    assert!((mid - expected).iter().all(|v| v.abs() < 1e-12));
+}
+
+#[test]
+fn test_finite_horizon_lqr_tracks_nominal_orbit() {
+    // 円軌道のノミナル軌道まわりに二体問題を線形化する. 重力勾配の向きは軌道上で回転する
+    let dynamics = TwoBodyDynamics::new();
+    let r = CONSTANTS.radius + 500.0e3;
+    let n = (CONSTANTS.mu / r.powi(3)).sqrt();
+    let circular = |t: f64| (
+        PositionVelocityStateEci::form_from_list(
+            [r * (n * t).cos(), r * (n * t).sin(), 0.0],
+            [-r * n * (n * t).sin(), r * n * (n * t).cos(), 0.0],
+        ),
+        Force3dEci::zeros(),
+    );
+    let q = ndarray::Array2::from_diag(&ndarray::arr1(&[1e-6, 1e-6, 1e-6, 1e-3, 1e-3, 1e-3]));
+    let cost = QuadraticCost::new(q, Array2::eye(3), Array2::eye(6) * 1e-3, 3000.0);
+    let controller = FiniteHorizonLqrController::<PositionVelocityStateEci, Force3dEci>::new(
+        &dynamics, &cost, &RungeKutta4Propagator, &circular, 0.0, 1.0, None,
+    )
+    .unwrap();
+
+    // ノミナル軌道上ではノミナル入力 (0) を返す
+    let on_orbit = controller.compute_control_input(&circular(1234.5).0, 1234.5);
+    assert!(on_orbit.get_vector().iter().all(|u| u.abs() < 1e-9));
+
+    // ゲインは軌道上の位置に応じて変わる
+    let variation = controller.gain(n.recip() * std::f64::consts::FRAC_PI_2) - controller.gain(0.0);
+    assert!(variation.iter().any(|v| v.abs() > 1e-9));
+
+    // 100 m ずれた状態から出発してもノミナル軌道に追従する
+    let mut state = circular(0.0).0 + PositionVelocityStateEci::form_from_list([100.0, -50.0, 30.0], [0.0, 0.0, 0.0]);
+    for k in 0..3000 {
+        let t = k as f64;
+        let u = controller.compute_control_input(&state, t);
+        state = RungeKutta4Propagator.propagate_continuous(&state, &u, &dynamics, t, 1.0);
+    }
+    let error = state.get_vector() - circular(3000.0).0.get_vector();
+    assert!(error.slice(ndarray::s![0..3]).iter().all(|e| e.abs() < 1.0), "{:?}", error);
+}
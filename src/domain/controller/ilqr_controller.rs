@@ -0,0 +1,579 @@
+use ndarray::{Array1, Array2, concatenate, s, Axis};
+use ndarray_linalg::Inverse;
+
+use super::controller_trait::Controller;
+use super::mode_controller::mode_optimizer::CostAndDifferentiable;
+use crate::domain::differentiable::differentiable_trait::{Differentiable2d, NumericalDefferential};
+use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
+use crate::domain::dynamics::propagator::Propagator;
+use crate::domain::force::force_trait::Force;
+use crate::domain::math::formulations::Math;
+use crate::domain::state::state_trait::StateVector;
+
+/// 正則化 μ の初期値・倍率・上限
+const MU_INIT: f64 = 1e-6;
+const MU_FACTOR: f64 = 10.0;
+const MU_MAX: f64 = 1e10;
+/// 前進パスの直線探索のステップ幅
+const LINE_SEARCH_ALPHAS: [f64; 8] = [1.0, 0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625, 0.0078125];
+
+/// **入力の上下限 (各成分)**
+#[derive(Debug, Clone)]
+pub struct InputBounds {
+    pub lower: Array1<f64>,
+    pub upper: Array1<f64>,
+}
+
+impl InputBounds {
+    pub fn new(lower: Array1<f64>, upper: Array1<f64>) -> Self {
+        Self { lower, upper }
+    }
+
+    /// **全成分が ±u_max の対称な上下限**
+    pub fn symmetric(u_max: f64, dim: usize) -> Self {
+        Self::new(Array1::from_elem(dim, -u_max), Array1::from_elem(dim, u_max))
+    }
+
+    pub fn clamp(&self, u: &Array1<f64>) -> Array1<f64> {
+        Array1::from_iter(u.iter().zip(self.lower.iter().zip(self.upper.iter())).map(|(v, (l, h))| v.clamp(*l, *h)))
+    }
+}
+
+/// **iLQR の解 (ノミナル軌道とフィードフォワード・フィードバック)**
+/// states は horizon + 1 個, inputs とゲインは horizon 個
+#[derive(Debug, Clone)]
+pub struct IlqrSolution<T: StateVector, U: Force> {
+    pub states: Vec<T>,
+    pub inputs: Vec<U>,
+    pub feedforward: Vec<Array1<f64>>,
+    pub gains: Vec<Array2<f64>>,
+    pub cost: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// **iLQR / box-DDP による軌道最適化器**
+/// 離散化したコスト Σ L(x_k, u_k) dt + φ(x_N) を最小化する.
+/// 状態のヤコビアンは `Differentiable2d`, 入力のヤコビアンとコストの 1 階・2 階微分は数値微分で求める.
+/// 入力の上下限がある場合は Tassa らの box-DDP (後退パスで箱制約付き QP を解く) を使う
+#[derive(Debug, Clone)]
+pub struct IlqrOptimizer {
+    horizon: usize,
+    dt: f64,
+    max_iterations: usize,
+    tolerance: f64,  // コストの相対減少量がこれを下回ったら収束
+    bounds: Option<InputBounds>,
+}
+
+impl IlqrOptimizer {
+    pub fn new(horizon: usize, dt: f64, max_iterations: usize, tolerance: f64, bounds: Option<InputBounds>) -> Result<Self, &'static str> {
+        if horizon == 0 {
+            return Err("horizon は 1 以上である必要があります。");
+        }
+        if dt <= 0.0 {
+            return Err("刻み dt は正である必要があります。");
+        }
+        Ok(Self {
+            horizon,
+            dt,
+            max_iterations,
+            tolerance,
+            bounds,
+        })
+    }
+
+    /// **初期状態 x0 (時刻 t0) からの最適軌道を求める**
+    /// initial_inputs が None なら入力 0 から始める
+    pub fn optimize<T, U, D, C, P>(
+        &self,
+        dynamics: &D,
+        cost: &C,
+        propagator: &P,
+        x0: &T,
+        t0: f64,
+        initial_inputs: Option<Vec<U>>,
+    ) -> Result<IlqrSolution<T, U>, &'static str>
+    where
+        T: StateVector,
+        U: Force,
+        D: ContinuousDynamics<T, U> + Differentiable2d<T, U>,
+        C: CostAndDifferentiable<T, U> + ?Sized,
+        P: Propagator<T, U>,
+    {
+        let mut inputs: Vec<Array1<f64>> = match initial_inputs {
+            Some(inputs) if inputs.len() == self.horizon => inputs.iter().map(|u| self.clamp(u.get_vector())).collect(),
+            Some(_) => return Err("初期入力列の長さが horizon と一致しません。"),
+            None => vec![U::zeros().get_vector().clone(); self.horizon],
+        };
+        let m = inputs.first().map_or(0, |u| u.len());
+        let n = x0.get_vector().len();
+
+        let mut states = self.rollout(dynamics, propagator, x0, t0, &inputs);
+        let mut total_cost = self.total_cost(cost, &states, &inputs);
+        let mut feedforward = vec![Array1::<f64>::zeros(m); self.horizon];
+        let mut gains = vec![Array2::<f64>::zeros((m, n)); self.horizon];
+        let mut mu = MU_INIT;
+        let mut converged = false;
+        let mut iterations = 0;
+
+        while iterations < self.max_iterations && !converged {
+            iterations += 1;
+
+            // 後退パス (失敗したら正則化を強める)
+            let (expected, new_feedforward, new_gains) = loop {
+                match self.backward_pass(dynamics, cost, &states, &inputs, t0, mu, &feedforward) {
+                    Some(result) => break result,
+                    None => {
+                        mu *= MU_FACTOR;
+                        if mu > MU_MAX {
+                            return Err("iLQR の後退パスで正則化が上限に達しました。");
+                        }
+                    }
+                }
+            };
+
+            // 前進パス (直線探索)
+            let mut accepted = false;
+            for &alpha in LINE_SEARCH_ALPHAS.iter() {
+                let (candidate_states, candidate_inputs) =
+                    self.forward_pass(dynamics, propagator, x0, t0, &states, &inputs, &new_feedforward, &new_gains, alpha);
+                let candidate_cost = self.total_cost(cost, &candidate_states, &candidate_inputs);
+                let expected_reduction = -alpha * (expected.0 + alpha * expected.1);
+                let actual_reduction = total_cost - candidate_cost;
+                let ratio = if expected_reduction > 0.0 { actual_reduction / expected_reduction } else { actual_reduction.signum() };
+                if candidate_cost.is_finite() && ratio > 0.1 {
+                    converged = actual_reduction.abs() < self.tolerance * total_cost.abs().max(1e-300);
+                    states = candidate_states;
+                    inputs = candidate_inputs;
+                    total_cost = candidate_cost;
+                    accepted = true;
+                    break;
+                }
+            }
+
+            feedforward = new_feedforward;
+            gains = new_gains;
+            if accepted {
+                mu = (mu / MU_FACTOR).max(MU_INIT);
+            } else {
+                // 改善しない場合は期待減少量が十分小さければ収束とみなす
+                converged = -(expected.0 + expected.1) < self.tolerance * total_cost.abs().max(1e-300);
+                mu *= MU_FACTOR;
+                if mu > MU_MAX {
+                    break;
+                }
+            }
+        }
+
+        Ok(IlqrSolution {
+            states,
+            inputs: inputs.into_iter().map(U::form_from_array).collect(),
+            feedforward,
+            gains,
+            cost: total_cost,
+            iterations,
+            converged,
+        })
+    }
+
+    fn clamp(&self, u: &Array1<f64>) -> Array1<f64> {
+        match &self.bounds {
+            Some(bounds) => bounds.clamp(u),
+            None => u.clone(),
+        }
+    }
+
+    fn rollout<T, U, D, P>(&self, dynamics: &D, propagator: &P, x0: &T, t0: f64, inputs: &[Array1<f64>]) -> Vec<T>
+    where
+        T: StateVector,
+        U: Force,
+        D: ContinuousDynamics<T, U>,
+        P: Propagator<T, U>,
+    {
+        let mut states = vec![x0.clone()];
+        for (k, u) in inputs.iter().enumerate() {
+            let next = propagator.propagate_continuous(&states[k], &U::form_from_array(u.clone()), dynamics, t0 + k as f64 * self.dt, self.dt);
+            states.push(next);
+        }
+        states
+    }
+
+    fn total_cost<T, U, C>(&self, cost: &C, states: &[T], inputs: &[Array1<f64>]) -> f64
+    where
+        T: StateVector,
+        U: Force,
+        C: CostAndDifferentiable<T, U> + ?Sized,
+    {
+        let stage: f64 = states
+            .iter()
+            .zip(inputs.iter())
+            .map(|(x, u)| cost.stage_cost(x, &U::form_from_array(u.clone())) * self.dt)
+            .sum();
+        stage + cost.terminal_cost(&states[states.len() - 1])
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn forward_pass<T, U, D, P>(
+        &self,
+        dynamics: &D,
+        propagator: &P,
+        x0: &T,
+        t0: f64,
+        states: &[T],
+        inputs: &[Array1<f64>],
+        feedforward: &[Array1<f64>],
+        gains: &[Array2<f64>],
+        alpha: f64,
+    ) -> (Vec<T>, Vec<Array1<f64>>)
+    where
+        T: StateVector,
+        U: Force,
+        D: ContinuousDynamics<T, U>,
+        P: Propagator<T, U>,
+    {
+        let mut new_states = vec![x0.clone()];
+        let mut new_inputs = Vec::with_capacity(self.horizon);
+        for k in 0..self.horizon {
+            let dx = new_states[k].get_vector() - states[k].get_vector();
+            let u = self.clamp(&(&inputs[k] + &(&feedforward[k] * alpha) + gains[k].dot(&dx)));
+            let next = propagator.propagate_continuous(&new_states[k], &U::form_from_array(u.clone()), dynamics, t0 + k as f64 * self.dt, self.dt);
+            new_states.push(next);
+            new_inputs.push(u);
+        }
+        (new_states, new_inputs)
+    }
+
+    /// **後退パス: 期待減少量 (1 次, 2 次) とフィードフォワード k, ゲイン K を返す**
+    /// Q_uu が正定値にならなければ None
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn backward_pass<T, U, D, C>(
+        &self,
+        dynamics: &D,
+        cost: &C,
+        states: &[T],
+        inputs: &[Array1<f64>],
+        t0: f64,
+        mu: f64,
+        previous_feedforward: &[Array1<f64>],
+    ) -> Option<((f64, f64), Vec<Array1<f64>>, Vec<Array2<f64>>)>
+    where
+        T: StateVector,
+        U: Force,
+        D: ContinuousDynamics<T, U> + Differentiable2d<T, U>,
+        C: CostAndDifferentiable<T, U> + ?Sized,
+    {
+        let n = states[0].get_vector().len();
+        let m = inputs.first().map_or(0, |u| u.len());
+
+        let terminal = |z: &Array1<f64>| cost.terminal_cost(&T::form_from_array(z.clone()));
+        let (mut v_x, mut v_xx) = numerical_gradient_hessian(&terminal, states[self.horizon].get_vector());
+
+        let mut feedforward = vec![Array1::<f64>::zeros(m); self.horizon];
+        let mut gains = vec![Array2::<f64>::zeros((m, n)); self.horizon];
+        let mut expected = (0.0, 0.0);
+
+        for k in (0..self.horizon).rev() {
+            let t = t0 + k as f64 * self.dt;
+            let x = &states[k];
+            let u = U::form_from_array(inputs[k].clone());
+
+            // 離散化したダイナミクスのヤコビアン
+            let a = dynamics.differentiate(x, &u, t);
+            let b = NumericalDefferential::differentiate_input_numeric(&|x, u| dynamics.compute_derivative(x, u, t), x, &u);
+            let (f_x, f_u) = Math::discretize_zoh(&a, &b, self.dt);
+
+            // ステージコストの微分 (z = [x; u])
+            let z = concatenate![Axis(0), *x.get_vector(), inputs[k]];
+            let stage = |z: &Array1<f64>| {
+                cost.stage_cost(&T::form_from_array(z.slice(s![0..n]).to_owned()), &U::form_from_array(z.slice(s![n..]).to_owned())) * self.dt
+            };
+            let (l_z, l_zz) = numerical_gradient_hessian(&stage, &z);
+
+            let q_x = l_z.slice(s![0..n]).to_owned() + f_x.t().dot(&v_x);
+            let q_u = l_z.slice(s![n..]).to_owned() + f_u.t().dot(&v_x);
+            let q_xx = l_zz.slice(s![0..n, 0..n]).to_owned() + f_x.t().dot(&v_xx).dot(&f_x);
+            let q_uu = l_zz.slice(s![n.., n..]).to_owned() + f_u.t().dot(&v_xx).dot(&f_u);
+            let q_ux = l_zz.slice(s![n.., 0..n]).to_owned() + f_u.t().dot(&v_xx).dot(&f_x);
+            let q_uu_reg = &q_uu + &(Array2::<f64>::eye(m) * mu);
+            if !is_positive_definite(&q_uu_reg) {
+                return None;
+            }
+
+            let (k_ff, k_fb) = match &self.bounds {
+                None => {
+                    let q_uu_inv = q_uu_reg.inv().ok()?;
+                    (-q_uu_inv.dot(&q_u), -q_uu_inv.dot(&q_ux))
+                }
+                Some(bounds) => {
+                    let lower = &bounds.lower - &inputs[k];
+                    let upper = &bounds.upper - &inputs[k];
+                    let (k_ff, free) = box_qp(&q_uu_reg, &q_u, &lower, &upper, &previous_feedforward[k]);
+                    // 上下限に張り付いた成分のフィードバックは 0
+                    let free_index: Vec<usize> = (0..m).filter(|&i| free[i]).collect();
+                    let mut k_fb = Array2::<f64>::zeros((m, n));
+                    if !free_index.is_empty() {
+                        let h_ff = q_uu_reg.select(Axis(0), &free_index).select(Axis(1), &free_index);
+                        let gain_free = -h_ff.inv().ok()?.dot(&q_ux.select(Axis(0), &free_index));
+                        for (row, &i) in free_index.iter().enumerate() {
+                            k_fb.row_mut(i).assign(&gain_free.row(row));
+                        }
+                    }
+                    (k_ff, k_fb)
+                }
+            };
+
+            expected.0 += k_ff.dot(&q_u);
+            expected.1 += 0.5 * k_ff.dot(&q_uu.dot(&k_ff));
+
+            v_x = &q_x + &k_fb.t().dot(&q_uu.dot(&k_ff)) + k_fb.t().dot(&q_u) + q_ux.t().dot(&k_ff);
+            let v_xx_raw = &q_xx + &k_fb.t().dot(&q_uu).dot(&k_fb) + k_fb.t().dot(&q_ux) + q_ux.t().dot(&k_fb);
+            v_xx = (&v_xx_raw + &v_xx_raw.t()) * 0.5;
+
+            feedforward[k] = k_ff;
+            gains[k] = k_fb;
+        }
+
+        Some((expected, feedforward, gains))
+    }
+}
+
+/// **スカラー関数の勾配とヘッセ行列 (中心差分)**
+fn numerical_gradient_hessian(func: &dyn Fn(&Array1<f64>) -> f64, z: &Array1<f64>) -> (Array1<f64>, Array2<f64>) {
+    let dim = z.len();
+    let h: Array1<f64> = z.mapv(|v| 1e-4 * (1.0 + v.abs()));
+    let f0 = func(z);
+    let shifted = |i: usize, si: f64, j: usize, sj: f64| {
+        let mut w = z.clone();
+        w[i] += si * h[i];
+        w[j] += sj * h[j];
+        func(&w)
+    };
+
+    let mut gradient = Array1::<f64>::zeros(dim);
+    let mut hessian = Array2::<f64>::zeros((dim, dim));
+    for i in 0..dim {
+        let mut plus = z.clone();
+        let mut minus = z.clone();
+        plus[i] += h[i];
+        minus[i] -= h[i];
+        let (f_plus, f_minus) = (func(&plus), func(&minus));
+        gradient[i] = (f_plus - f_minus) / (2.0 * h[i]);
+        hessian[[i, i]] = (f_plus - 2.0 * f0 + f_minus) / (h[i] * h[i]);
+        for j in 0..i {
+            let value = (shifted(i, 1.0, j, 1.0) - shifted(i, 1.0, j, -1.0) - shifted(i, -1.0, j, 1.0) + shifted(i, -1.0, j, -1.0))
+                / (4.0 * h[i] * h[j]);
+            hessian[[i, j]] = value;
+            hessian[[j, i]] = value;
+        }
+    }
+    (gradient, hessian)
+}
+
+/// **コレスキー分解が可能か (対称行列の正定値判定)**
+fn is_positive_definite(matrix: &Array2<f64>) -> bool {
+    let dim = matrix.nrows();
+    let mut l = Array2::<f64>::zeros((dim, dim));
+    for i in 0..dim {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[[i, k]] * l[[j, k]]).sum();
+            if i == j {
+                let diagonal = matrix[[i, i]] - sum;
+                if diagonal <= 0.0 || !diagonal.is_finite() {
+                    return false;
+                }
+                l[[i, i]] = diagonal.sqrt();
+            } else {
+                l[[i, j]] = (matrix[[i, j]] - sum) / l[[j, j]];
+            }
+        }
+    }
+    true
+}
+
+/// **箱制約付き QP: min 1/2 x^T H x + g^T x  s.t. lower ≤ x ≤ upper**
+/// 射影ニュートン法で解き, 解と上下限に張り付いていない成分のフラグを返す
+fn box_qp(
+    h: &Array2<f64>,
+    g: &Array1<f64>,
+    lower: &Array1<f64>,
+    upper: &Array1<f64>,
+    x0: &Array1<f64>,
+) -> (Array1<f64>, Vec<bool>) {
+    let dim = g.len();
+    let project = |x: &Array1<f64>| {
+        Array1::from_iter(x.iter().zip(lower.iter().zip(upper.iter())).map(|(v, (l, u))| v.clamp(*l, *u)))
+    };
+    let objective = |x: &Array1<f64>| 0.5 * x.dot(&h.dot(x)) + g.dot(x);
+    let free_mask = |x: &Array1<f64>, grad: &Array1<f64>| -> Vec<bool> {
+        (0..dim)
+            .map(|i| !((x[i] <= lower[i] && grad[i] > 0.0) || (x[i] >= upper[i] && grad[i] < 0.0)))
+            .collect()
+    };
+
+    let mut x = project(x0);
+    let mut value = objective(&x);
+    for _ in 0..100 {
+        let grad = g + &h.dot(&x);
+        let free = free_mask(&x, &grad);
+        let free_index: Vec<usize> = (0..dim).filter(|&i| free[i]).collect();
+        if free_index.is_empty() {
+            break;
+        }
+        let grad_free = grad.select(Axis(0), &free_index);
+        if grad_free.dot(&grad_free).sqrt() < 1e-12 {
+            break;
+        }
+
+        // 自由な成分についてのニュートン方向
+        let h_ff = h.select(Axis(0), &free_index).select(Axis(1), &free_index);
+        let step_free = match h_ff.inv() {
+            Ok(inv) => -inv.dot(&grad_free),
+            Err(_) => -grad_free,
+        };
+        let mut direction = Array1::<f64>::zeros(dim);
+        for (row, &i) in free_index.iter().enumerate() {
+            direction[i] = step_free[row];
+        }
+
+        // 射影付きのアルミホ条件による直線探索
+        let mut step = 1.0;
+        let mut improved = false;
+        while step > 1e-10 {
+            let candidate = project(&(&x + &(&direction * step)));
+            let candidate_value = objective(&candidate);
+            if candidate_value <= value + 0.1 * grad.dot(&(&candidate - &x)) {
+                improved = (value - candidate_value).abs() > 1e-14 * (1.0 + value.abs());
+                x = candidate;
+                value = candidate_value;
+                break;
+            }
+            step *= 0.6;
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    let grad = g + &h.dot(&x);
+    let free = free_mask(&x, &grad);
+    (x, free)
+}
+
+/// **iLQR の解を追従する制御器**
+/// u = ū_k + K_k (x - x̄_k) を上下限で飽和させる (k は時刻 t を含む区間, 範囲外は端の区間)
+#[derive(Debug, Clone)]
+pub struct IlqrController<T: StateVector, U: Force> {
+    states: Vec<T>,
+    inputs: Vec<U>,
+    gains: Vec<Array2<f64>>,
+    t0: f64,
+    dt: f64,
+    bounds: Option<InputBounds>,
+}
+
+impl<T: StateVector, U: Force> IlqrController<T, U> {
+    pub fn new(solution: IlqrSolution<T, U>, t0: f64, dt: f64, bounds: Option<InputBounds>) -> Result<Self, &'static str> {
+        if solution.inputs.is_empty()
+            || solution.gains.len() != solution.inputs.len()
+            || solution.states.len() != solution.inputs.len() + 1
+        {
+            return Err("iLQR の解の長さが不正です。");
+        }
+        if dt <= 0.0 {
+            return Err("刻み dt は正である必要があります。");
+        }
+        Ok(Self {
+            states: solution.states,
+            inputs: solution.inputs,
+            gains: solution.gains,
+            t0,
+            dt,
+            bounds,
+        })
+    }
+
+    /// **最適化器と同じ刻み・上下限で解から制御器を作る**
+    pub fn from_optimizer(solution: IlqrSolution<T, U>, optimizer: &IlqrOptimizer, t0: f64) -> Result<Self, &'static str> {
+        Self::new(solution, t0, optimizer.dt, optimizer.bounds.clone())
+    }
+}
+
+impl<T: StateVector, U: Force> Controller<T, U> for IlqrController<T, U> {
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let k = (((t - self.t0) / self.dt + 1e-9).floor().max(0.0) as usize).min(self.inputs.len() - 1);
+        let dx = state.get_vector() - self.states[k].get_vector();
+        let u = self.inputs[k].get_vector() + &self.gains[k].dot(&dx);
+        match &self.bounds {
+            Some(bounds) => U::form_from_array(bounds.clamp(&u)),
+            None => U::form_from_array(u),
+        }
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::cost::quadric_cost::QuadraticCost;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_box_ddp_hcw_rendezvous() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let q = Array2::<f64>::zeros((6, 6));
+    let qf = Array2::from_diag(&arr1(&[1.0, 1.0, 1.0, 1e4, 1e4, 1e4]));
+    let cost = QuadraticCost::new(q, Array2::eye(3), qf, 1200.0);
+    let x0 = PositionVelocityStateLvlh::form_from_list([10.0, -150.0, 5.0], [0.0, 0.0, 0.0]);
+    let (dt, u_max) = (10.0, 5e-4);
+
+    let optimizer = IlqrOptimizer::new(120, dt, 50, 1e-6, Some(InputBounds::symmetric(u_max, 3))).unwrap();
+    let solution = optimizer
+        .optimize::<_, Force3dLvlh, _, _, _>(&dynamics, &cost, &RungeKutta4Propagator, &x0, 0.0, None)
+        .unwrap();
+    assert!(solution.converged);
+
+    // 入力は上下限内で, 上限に張り付く区間がある
+    assert!(solution.inputs.iter().all(|u| u.get_vector().iter().all(|v| v.abs() <= u_max + 1e-12)));
+    assert!(solution.inputs.iter().any(|u| u.get_vector().iter().any(|v| (v.abs() - u_max).abs() < 1e-9)));
+
+    // 終端で目標 (原点) に到達する
+    let terminal = solution.states.last().unwrap().get_vector();
+    assert!(terminal.slice(s![0..3]).iter().all(|v| v.abs() < 1.0));
+
+    // 制御器で閉ループを回すと最適軌道を再現する
+    let controller = IlqrController::from_optimizer(solution.clone(), &optimizer, 0.0).unwrap();
+    let mut state = x0.clone();
+    for k in 0..120 {
+        let t = k as f64 * dt;
+        let u = controller.compute_control_input(&state, t);
+        state = RungeKutta4Propagator.propagate_continuous(&state, &u, &dynamics, t, dt);
+    }
+    let diff = state.get_vector() - terminal;
+    assert!(diff.iter().all(|v| v.abs() < 1e-6));
+}
+
+#[test]
+fn test_ilqr_rejects_invalid_settings() {
+    assert!(IlqrOptimizer::new(0, 10.0, 50, 1e-6, None).is_err());
+    assert!(IlqrOptimizer::new(10, 0.0, 50, 1e-6, None).is_err());
+
+    // 入力列が空の解からは制御器を作らない
+    let empty = IlqrSolution::<PositionVelocityStateLvlh, Force3dLvlh> {
+        states: vec![PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0])],
+        inputs: Vec::new(),
+        feedforward: Vec::new(),
+        gains: Vec::new(),
+        cost: 0.0,
+        iterations: 0,
+        converged: true,
+    };
+    assert!(IlqrController::new(empty, 0.0, 10.0, None).is_err());
+}
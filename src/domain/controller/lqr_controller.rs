@@ -0,0 +1,89 @@
+use ndarray::{Array1, Array2};
+use ndarray_linalg::Inverse;
+
+use super::controller_trait::Controller;
+use crate::domain::cost::quadric_cost::QuadraticCost;
+use crate::domain::dynamics::dynamics_trait::LinearSystem;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::math::riccati::Riccati;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+
+/// **無限時間 LQR 制御器**
+/// u = -K x, K = R^{-1} B^T P (P は連続時間代数リカッチ方程式の解).
+/// u_max を与えると各軸を ±u_max で飽和させる
+#[derive(Debug, Clone)]
+pub struct LqrController {
+    gain: Array2<f64>,
+    u_max: Option<f64>,
+}
+
+impl LqrController {
+    pub fn new(
+        a_matrix: &Array2<f64>,
+        b_matrix: &Array2<f64>,
+        q_matrix: &Array2<f64>,
+        r_matrix: &Array2<f64>,
+        u_max: Option<f64>,
+    ) -> Result<Self, &'static str> {
+        let p = Riccati::solve_continuous(a_matrix, b_matrix, q_matrix, r_matrix)?;
+        let r_inv = r_matrix.inv().map_err(|_| "R が正則ではありません。")?;
+        let gain = r_inv.dot(&b_matrix.t()).dot(&p);
+        Ok(Self { gain, u_max })
+    }
+
+    /// **線形ダイナミクスと 2 次形式コストの Q, R からゲインを計算**
+    pub fn from_linear_system(dynamics: &dyn LinearSystem, cost: &QuadraticCost, u_max: Option<f64>) -> Result<Self, &'static str> {
+        Self::new(&dynamics.system_matrix(), &dynamics.input_matrix(), cost.q_matrix(), cost.r_matrix(), u_max)
+    }
+
+    pub fn gain(&self) -> &Array2<f64> {
+        &self.gain
+    }
+
+    pub fn compute_input(&self, x: &Array1<f64>) -> Array1<f64> {
+        let u = -self.gain.dot(x);
+        match self.u_max {
+            Some(u_max) => u.mapv(|v| v.clamp(-u_max, u_max)),
+            None => u,
+        }
+    }
+}
+
+impl Controller<PositionVelocityStateLvlh, Force3dLvlh> for LqrController {
+    fn compute_control_input(&self, state: &PositionVelocityStateLvlh, _t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(self.compute_input(state.get_vector()))
+    }
+}
+
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::{Propagator, RungeKutta4Propagator};
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_lqr_hcw_riccati_residual_and_regulation() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let cost = QuadraticCost::new(Array2::eye(6) * 1e-6, Array2::eye(3), Array2::eye(6), 0.0);
+    let (a, b) = (dynamics.system_matrix(), dynamics.input_matrix());
+
+    // リカッチ方程式の残差
+    let p = Riccati::solve_continuous(&a, &b, cost.q_matrix(), cost.r_matrix()).unwrap();
+    let residual = a.t().dot(&p) + p.dot(&a) - p.dot(&b).dot(&b.t()).dot(&p) + cost.q_matrix();
+    assert!(residual.iter().all(|v| v.abs() < 1e-9 * p.iter().fold(0.0_f64, |m, v| m.max(v.abs()))));
+
+    // 閉ループで相対位置が 0 に収束し, 入力は飽和値を超えない
+    let u_max = 1e-3;
+    let controller = LqrController::from_linear_system(&dynamics, &cost, Some(u_max)).unwrap();
+    let mut state = PositionVelocityStateLvlh::form_from_list([100.0, -200.0, 50.0], [0.0, 0.0, 0.0]);
+    for k in 0..20000 {
+        let t = k as f64;
+        let u = controller.compute_control_input(&state, t);
+        assert!(u.get_vector().iter().all(|v| v.abs() <= u_max));
+        state = RungeKutta4Propagator.propagate_continuous(&state, &u, &dynamics, t, 1.0);
+    }
+    assert!(state.get_vector().iter().all(|v| v.abs() < 1e-2));
+}
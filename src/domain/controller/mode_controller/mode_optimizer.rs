@@ -365,13 +365,13 @@ impl StateUpdater {
                 let noise = &passive_mode_map.noise_matrix[&passive_mode_new].clone();
                 let reset = passive_mode_map.reset[&(passive_mode_prev, passive_mode_new)];
                 // モード遷移前
-                let f1 = dynamics.compute_derivative(&prev_state, &U::zeros());
+                let f1 = dynamics.compute_derivative(&prev_state, &U::zeros(), t_index as f64 * dt);
                 let nabla_reset = passive_mode_map.nabla_reset[&(passive_mode_prev, passive_mode_new)](&prev_state);
                 let nabla_guard = passive_mode_map.nabla_guard[&(passive_mode_prev, passive_mode_new)](&prev_state);
                 // モード遷移
                 dynamics.set_noise(noise);
                 // モード遷移後
-                let f2 = dynamics.compute_derivative(&prev_state, &U::zeros());
+                let f2 = dynamics.compute_derivative(&prev_state, &U::zeros(), t_index as f64 * dt);
                 let pi = Self::calc_pi(&f1.get_vector(), &f2.get_vector(), &nabla_reset, &nabla_guard);
                 pi_schedule.insert(t_index, pi);
                 // リセットはf2の計算後に行う
@@ -383,6 +383,7 @@ impl StateUpdater {
                 &prev_state,
                 &U::form_from_array(Array1::zeros(prev_state.get_vector().len())), 
                 dynamics.as_continuous_dynamics(), 
+                t_index as f64 * dt,
                 dt);
 
             states.insert(t_index, new_state);
@@ -515,6 +516,7 @@ impl InsertionGradientCalculator {
         mode_dynamics_map: &ModeDynamicsMap<T, U>,
         t_index0: usize,
         t_index_last: usize,
+        dt: f64,
     ) -> (Array2<f64>, Vec<ModeId>) // 最適モードを追加
     where
         T: StateVector,
@@ -536,8 +538,8 @@ impl InsertionGradientCalculator {
             for m_index in 0..num_modes {
                 let mode_new = ModeId::new(m_index);
                 let dynamics_new = mode_dynamics_map.get_dynamics(mode_new).unwrap();
-                let x_dot_prev = dynamics_prev.compute_derivative(x_now, &U::zeros());
-                let x_dot_new = dynamics_new.compute_derivative(x_now, &U::zeros());
+                let x_dot_prev = dynamics_prev.compute_derivative(x_now, &U::zeros(), t_index as f64 * dt);
+                let x_dot_new = dynamics_new.compute_derivative(x_now, &U::zeros(), t_index as f64 * dt);
                 let grad_value = Self::compute_insertion_gradient::<T>(&p_now, &x_dot_prev, &x_dot_new);
 
                 d[(t_index, m_index)] = grad_value;
@@ -643,7 +645,8 @@ where
                 &adjoint_schedule, 
                 &self.mode_dynamics_map, 
                 self.t_index0, 
-                self.t_index_last)
+                self.t_index_last,
+                self.dt)
             };
 
             mode_schedule = { self.apply_armijo(
@@ -0,0 +1,370 @@
+use std::cell::RefCell;
+
+use ndarray::{Array1, Array2, s};
+
+use super::controller_trait::Controller;
+use crate::domain::cost::quadric_cost::QuadraticCost;
+use crate::domain::dynamics::dynamics_trait::LinearSystem;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::math::formulations::Math;
+use crate::domain::math::qp_solver::{AdmmQpSolver, QpConstraint};
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::logger::loggable_trait::Loggable;
+
+/// **進入禁止球 (中心 center, 半径 radius)**
+#[derive(Debug, Clone)]
+pub struct KeepOutSphere {
+    pub center: Array1<f64>,
+    pub radius: f64,
+}
+
+/// **接近コーン (頂点は原点, 軸 axis 方向に半頂角 half_angle_rad)**
+#[derive(Debug, Clone)]
+pub struct ApproachCone {
+    pub axis: Array1<f64>,
+    pub half_angle_rad: f64,
+}
+
+/// **MPC の制約**
+#[derive(Debug, Clone, Default)]
+pub struct MpcConstraints {
+    pub u_max: Option<f64>,            // 各軸の入力の上限
+    pub thrust_norm_max: Option<f64>,  // 入力の 2 ノルムの上限
+    pub keep_out: Option<KeepOutSphere>,
+    pub approach_cone: Option<ApproachCone>,
+}
+
+/// **直前の周期で QP を解いた結果**
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MpcSolveStatus {
+    Solved,
+    FallbackPrevious(&'static str),  // 解けなかったので前回の入力列をずらして使った
+    FallbackZero(&'static str),      // 解けず前回の入力列もないので 0 を使った
+}
+
+impl Loggable for MpcSolveStatus {
+    fn header(&self) -> String {
+        "mpc_status".to_string()
+    }
+
+    fn output_log(&self) -> String {
+        match self {
+            MpcSolveStatus::Solved => "0",
+            MpcSolveStatus::FallbackPrevious(_) => "1",
+            MpcSolveStatus::FallbackZero(_) => "2",
+        }
+        .to_string()
+    }
+}
+
+/// **線形モデル予測制御器**
+/// 零次ホールドで離散化したモデルで horizon ステップ先までの入力列を QP として解き,
+/// 最初の入力を返す. 進入禁止球と接近コーンは前回の解から予測した軌道まわりで線形化する.
+/// 入力は刻み dt の間保持する前提で, 同じ周期内では前回の解の入力を返す.
+/// QP が解けない・収束しない周期は前回の入力列をずらしたもの (なければ 0) を使い, `last_status` で知らせる
+pub struct ModelPredictiveController {
+    a_d: Array2<f64>,
+    b_d: Array2<f64>,
+    q_matrix: Array2<f64>,
+    r_matrix: Array2<f64>,
+    qf_matrix: Array2<f64>,
+    horizon: usize,
+    dt: f64,
+    constraints: MpcConstraints,
+    solver: AdmmQpSolver,
+    previous: RefCell<Option<(i64, Array1<f64>)>>,  // (周期番号, 入力列)
+    status: RefCell<(MpcSolveStatus, usize)>,       // (直前の結果, 解けなかった周期の数)
+}
+
+impl ModelPredictiveController {
+    pub fn new(
+        dynamics: &dyn LinearSystem,
+        cost: &QuadraticCost,
+        horizon: usize,
+        dt: f64,
+        constraints: MpcConstraints,
+        solver: AdmmQpSolver,
+    ) -> Result<Self, &'static str> {
+        if horizon == 0 {
+            return Err("horizon は 1 以上である必要があります。");
+        }
+        if dt <= 0.0 {
+            return Err("刻み dt は正である必要があります。");
+        }
+        if solver.rho <= 0.0 || solver.max_iterations == 0 {
+            return Err("QP ソルバの rho と反復回数は正である必要があります。");
+        }
+        let (a_d, b_d) = Math::discretize_zoh(&dynamics.system_matrix(), &dynamics.input_matrix(), dt);
+        Ok(Self {
+            a_d,
+            b_d,
+            q_matrix: cost.q_matrix().clone(),
+            r_matrix: cost.r_matrix().clone(),
+            qf_matrix: cost.qf_matrix().clone(),
+            horizon,
+            dt,
+            constraints,
+            solver,
+            previous: RefCell::new(None),
+            status: RefCell::new((MpcSolveStatus::Solved, 0)),
+        })
+    }
+
+    /// **予測行列 X = Φ x0 + Γ U (X = [x_1; ...; x_N], U = [u_0; ...; u_{N-1}])**
+    fn prediction_matrices(&self) -> (Array2<f64>, Array2<f64>) {
+        let (n, m, horizon) = (self.a_d.nrows(), self.b_d.ncols(), self.horizon);
+        let mut phi = Array2::<f64>::zeros((horizon * n, n));
+        let mut gamma = Array2::<f64>::zeros((horizon * n, horizon * m));
+
+        let mut a_power = Array2::<f64>::eye(n);
+        let mut a_power_b = Vec::with_capacity(horizon);  // A^k B
+        for k in 0..horizon {
+            a_power_b.push(a_power.dot(&self.b_d));
+            a_power = self.a_d.dot(&a_power);
+            phi.slice_mut(s![k * n..(k + 1) * n, ..]).assign(&a_power);
+        }
+        for k in 0..horizon {
+            for j in 0..=k {
+                gamma.slice_mut(s![k * n..(k + 1) * n, j * m..(j + 1) * m]).assign(&a_power_b[k - j]);
+            }
+        }
+        (phi, gamma)
+    }
+
+    fn input_scale(&self) -> f64 {
+        match (self.constraints.u_max, self.constraints.thrust_norm_max) {
+            (Some(u_max), Some(norm_max)) => u_max.min(norm_max),
+            (Some(u_max), None) => u_max,
+            (None, Some(norm_max)) => norm_max,
+            (None, None) => 1.0,
+        }
+    }
+
+    pub fn last_status(&self) -> MpcSolveStatus {
+        self.status.borrow().0
+    }
+
+    /// **QP が解けず代わりの入力列を使った周期の数**
+    pub fn failure_count(&self) -> usize {
+        self.status.borrow().1
+    }
+
+    /// **状態 x0 から入力列を解く (収束しなかった場合もエラー)**
+    pub fn solve_input_sequence(&self, x0: &Array1<f64>, warm_start: Option<&Array1<f64>>) -> Result<Array1<f64>, &'static str> {
+        let (n, m, horizon) = (self.a_d.nrows(), self.b_d.ncols(), self.horizon);
+        let (phi, gamma) = self.prediction_matrices();
+        let free_response = phi.dot(x0);
+
+        let mut q_bar = Array2::<f64>::zeros((horizon * n, horizon * n));
+        let mut r_bar = Array2::<f64>::zeros((horizon * m, horizon * m));
+        for k in 0..horizon {
+            let weight = if k + 1 == horizon { &self.qf_matrix } else { &self.q_matrix };
+            q_bar.slice_mut(s![k * n..(k + 1) * n, k * n..(k + 1) * n]).assign(weight);
+            r_bar.slice_mut(s![k * m..(k + 1) * m, k * m..(k + 1) * m]).assign(&self.r_matrix);
+        }
+        // 入力を上限で正規化して QP の条件を良くする (U = scale * Ũ)
+        let scale = self.input_scale();
+        let gamma = gamma * scale;
+        let p_matrix = (gamma.t().dot(&q_bar).dot(&gamma) + r_bar * scale.powi(2)) * 2.0;
+        let q_vector = gamma.t().dot(&q_bar).dot(&free_response) * 2.0;
+
+        let mut constraints = Vec::new();
+        if let Some(u_max) = self.constraints.u_max {
+            constraints.push(QpConstraint::Linear {
+                matrix: Array2::eye(horizon * m),
+                lower: Array1::from_elem(horizon * m, -u_max / scale),
+                upper: Array1::from_elem(horizon * m, u_max / scale),
+            });
+        }
+        if let Some(radius) = self.constraints.thrust_norm_max {
+            for k in 0..horizon {
+                let mut selector = Array2::<f64>::zeros((m, horizon * m));
+                selector.slice_mut(s![.., k * m..(k + 1) * m]).assign(&Array2::eye(m));
+                constraints.push(QpConstraint::NormBall { matrix: selector, radius: radius / scale });
+            }
+        }
+
+        // 位置の線形化制約 (行 g, 上限 h: g^T p_k ≤ h)
+        let warm_start = warm_start.map(|u| u / scale);
+        let nominal = match &warm_start {
+            Some(u) => &free_response + &gamma.dot(u),
+            None => free_response.clone(),
+        };
+        let mut rows: Vec<(usize, Array1<f64>, f64)> = Vec::new();
+        for k in 0..horizon {
+            let position = nominal.slice(s![k * n..k * n + 3]).to_owned();
+            if let Some(keep_out) = &self.constraints.keep_out {
+                // n^T (p - c) ≥ r (n は予測位置の中心からの方向)
+                let offset = &position - &keep_out.center;
+                let distance = offset.dot(&offset).sqrt();
+                if distance > 0.0 {
+                    let normal = offset / distance;
+                    rows.push((k, -&normal, -keep_out.radius - normal.dot(&keep_out.center)));
+                }
+            }
+            if let Some(cone) = &self.constraints.approach_cone {
+                // cosθ |p| - a^T p ≤ 0 を予測位置で線形化 (同次なので定数項は消える)
+                let norm = position.dot(&position).sqrt();
+                if norm > 0.0 {
+                    let axis = Math::normalize(&cone.axis);
+                    rows.push((k, &position * (cone.half_angle_rad.cos() / norm) - axis, 0.0));
+                }
+            }
+        }
+        if !rows.is_empty() {
+            let mut matrix = Array2::<f64>::zeros((rows.len(), horizon * m));
+            let mut upper = Array1::<f64>::zeros(rows.len());
+            for (i, (k, g, h)) in rows.iter().enumerate() {
+                let gamma_k = gamma.slice(s![k * n..k * n + 3, ..]);
+                matrix.row_mut(i).assign(&g.dot(&gamma_k));
+                upper[i] = h - g.dot(&free_response.slice(s![k * n..k * n + 3]));
+            }
+            constraints.push(QpConstraint::Linear {
+                matrix,
+                lower: Array1::from_elem(rows.len(), f64::NEG_INFINITY),
+                upper,
+            });
+        }
+
+        let solution = self.solver.solve(&p_matrix, &q_vector, &constraints, warm_start.as_ref())?;
+        if !solution.converged {
+            return Err("MPC の QP が収束しませんでした。");
+        }
+        Ok(solution.x * scale)
+    }
+
+    /// **QP が収束しきらなかった場合に備えて入力を上限内に収める**
+    fn saturate(&self, input: Array1<f64>) -> Array1<f64> {
+        let input = match self.constraints.u_max {
+            Some(u_max) => input.mapv(|u| u.clamp(-u_max, u_max)),
+            None => input,
+        };
+        match self.constraints.thrust_norm_max {
+            Some(norm_max) if input.dot(&input).sqrt() > norm_max => {
+                let norm = input.dot(&input).sqrt();
+                input * (norm_max / norm)
+            }
+            _ => input,
+        }
+    }
+
+    pub fn compute_input(&self, x0: &Array1<f64>, t: f64) -> Array1<f64> {
+        let m = self.b_d.ncols();
+        let k = (t / self.dt + 1e-9).floor() as i64;
+        let mut previous = self.previous.borrow_mut();
+
+        if let Some((index, sequence)) = previous.as_ref() {
+            if *index == k {
+                return self.saturate(sequence.slice(s![0..m]).to_owned());
+            }
+        }
+
+        // 前回の解を 1 周期ずらしてウォームスタートに使う
+        let warm_start = previous.as_ref().map(|(index, sequence)| {
+            let shift = ((k - index).max(0) as usize).min(self.horizon) * m;
+            let mut shifted = Array1::<f64>::zeros(sequence.len());
+            shifted.slice_mut(s![0..sequence.len() - shift]).assign(&sequence.slice(s![shift..]));
+            shifted
+        });
+        let (sequence, status) = match self.solve_input_sequence(x0, warm_start.as_ref()) {
+            Ok(sequence) => (sequence, MpcSolveStatus::Solved),
+            Err(message) => match warm_start {
+                Some(shifted) => (shifted, MpcSolveStatus::FallbackPrevious(message)),
+                None => (Array1::zeros(self.horizon * m), MpcSolveStatus::FallbackZero(message)),
+            },
+        };
+        let mut last_status = self.status.borrow_mut();
+        if status != MpcSolveStatus::Solved {
+            last_status.1 += 1;
+        }
+        last_status.0 = status;
+
+        let input = self.saturate(sequence.slice(s![0..m]).to_owned());
+        *previous = Some((k, sequence));
+        input
+    }
+}
+
+impl Controller<PositionVelocityStateLvlh, Force3dLvlh> for ModelPredictiveController {
+    fn compute_control_input(&self, state: &PositionVelocityStateLvlh, t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(self.compute_input(state.get_vector(), t))
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::{Propagator, RungeKutta4Propagator};
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_mpc_constraints_and_regulation() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let q = Array2::from_diag(&arr1(&[1e-4, 1e-4, 1e-4, 1.0, 1.0, 1.0]));
+    let cost = QuadraticCost::new(q.clone(), Array2::eye(3) * 1e3, q * 10.0, 0.0);
+    let (u_max, thrust_max) = (2e-3, 2.5e-3);
+    let constraints = MpcConstraints {
+        u_max: Some(u_max),
+        thrust_norm_max: Some(thrust_max),
+        keep_out: Some(KeepOutSphere { center: arr1(&[0.0, -100.0, 0.0]), radius: 20.0 }),
+        approach_cone: None,
+    };
+    let dt = 20.0;
+    let controller = ModelPredictiveController::new(&dynamics, &cost, 20, dt, constraints, AdmmQpSolver::new(1.0, 2000, 1e-4)).unwrap();
+
+    let mut state = PositionVelocityStateLvlh::form_from_list([5.0, -200.0, 0.0], [0.0, 0.0, 0.0]);
+    for k in 0..60 {
+        let t = k as f64 * dt;
+        let u = controller.compute_control_input(&state, t);
+        let u_vec = u.get_vector();
+        assert!(u_vec.iter().all(|v| v.abs() <= u_max + 1e-6));
+        assert!(u_vec.dot(u_vec).sqrt() <= thrust_max + 1e-6);
+        state = RungeKutta4Propagator.propagate_continuous(&state, &u, &dynamics, t, dt);
+
+        // 進入禁止球に入らない
+        let offset = state.position() - arr1(&[0.0, -100.0, 0.0]);
+        assert!(offset.dot(&offset).sqrt() > 19.0);
+    }
+    let position = state.position();
+    assert!(position.dot(&position).sqrt() < 5.0);
+    assert_eq!(controller.failure_count(), 0);
+}
+
+#[test]
+fn test_mpc_falls_back_when_qp_does_not_converge() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let q = Array2::from_diag(&arr1(&[1e-4, 1e-4, 1e-4, 1.0, 1.0, 1.0]));
+    let cost = QuadraticCost::new(q.clone(), Array2::eye(3) * 1e3, q * 10.0, 0.0);
+    let constraints = MpcConstraints { u_max: Some(2e-3), ..Default::default() };
+    let state = PositionVelocityStateLvlh::form_from_list([5.0, -200.0, 0.0], [0.0, 0.0, 0.0]);
+
+    // 1 反復では収束しないので, 最初の周期は 0 を返して知らせる
+    let controller = ModelPredictiveController::new(&dynamics, &cost, 20, 20.0, constraints, AdmmQpSolver::new(1.0, 1, 1e-9)).unwrap();
+    let u = controller.compute_control_input(&state, 0.0);
+    assert!(u.get_vector().iter().all(|v| *v == 0.0));
+    assert!(matches!(controller.last_status(), MpcSolveStatus::FallbackZero(_)));
+
+    // 前回の入力列があればそれをずらして使う
+    controller.compute_control_input(&state, 20.0);
+    assert!(matches!(controller.last_status(), MpcSolveStatus::FallbackPrevious(_)));
+    assert_eq!(controller.failure_count(), 2);
+}
+
+#[test]
+fn test_mpc_rejects_invalid_settings() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let cost = QuadraticCost::new(Array2::eye(6), Array2::eye(3), Array2::eye(6), 0.0);
+    let create = |horizon: usize, dt: f64, solver: AdmmQpSolver| {
+        ModelPredictiveController::new(&dynamics, &cost, horizon, dt, MpcConstraints::default(), solver)
+    };
+    assert!(create(20, 20.0, AdmmQpSolver::new(1.0, 100, 1e-4)).is_ok());
+    assert!(create(0, 20.0, AdmmQpSolver::new(1.0, 100, 1e-4)).is_err());
+    assert!(create(20, 0.0, AdmmQpSolver::new(1.0, 100, 1e-4)).is_err());
+    assert!(create(20, 20.0, AdmmQpSolver::new(0.0, 100, 1e-4)).is_err());
+    assert!(create(20, 20.0, AdmmQpSolver::new(1.0, 0, 1e-4)).is_err());
+}
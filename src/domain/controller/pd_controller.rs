@@ -0,0 +1,92 @@
+use ndarray::{Array1, Array2, s};
+
+use super::controller_trait::Controller;
+use crate::domain::dynamics::dynamics_trait::LinearSystem;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+
+/// **HCW のフィードフォワード補償付き PD 制御器**
+/// u = -Kp (r - r_ref) - Kd (v - v_ref) - A_a x. A_a は HCW の系行列の加速度の行 (3x6) で,
+/// 相対運動の結合項を打ち消して各軸を独立な 2 重積分系にする.
+/// u_max を与えると各軸を ±u_max で飽和させる
+#[derive(Debug, Clone)]
+pub struct PdController {
+    kp: Array1<f64>,  // 各軸の比例ゲイン (1/s²)
+    kd: Array1<f64>,  // 各軸の微分ゲイン (1/s)
+    target: PositionVelocityStateLvlh,
+    natural_acceleration: Array2<f64>,
+    u_max: Option<f64>,
+}
+
+impl PdController {
+    pub fn new(
+        dynamics: &dyn LinearSystem,
+        kp: Array1<f64>,
+        kd: Array1<f64>,
+        target: PositionVelocityStateLvlh,
+        u_max: Option<f64>,
+    ) -> Result<Self, &'static str> {
+        if kp.len() != 3 || kd.len() != 3 {
+            return Err("ゲインは 3 軸分必要です。");
+        }
+        if kp.iter().chain(kd.iter()).any(|k| *k <= 0.0) {
+            return Err("ゲインは正である必要があります。");
+        }
+        let natural_acceleration = dynamics.system_matrix().slice(s![3..6, ..]).to_owned();
+        Ok(Self { kp, kd, target, natural_acceleration, u_max })
+    }
+
+    pub fn target(&self) -> &PositionVelocityStateLvlh {
+        &self.target
+    }
+
+    pub fn compute_input(&self, x: &Array1<f64>) -> Array1<f64> {
+        let error = x - self.target.get_vector();
+        let u = -(&self.kp * &error.slice(s![0..3])) - &self.kd * &error.slice(s![3..6]) - self.natural_acceleration.dot(x);
+        match self.u_max {
+            Some(u_max) => u.mapv(|v| v.clamp(-u_max, u_max)),
+            None => u,
+        }
+    }
+}
+
+impl Controller<PositionVelocityStateLvlh, Force3dLvlh> for PdController {
+    fn compute_control_input(&self, state: &PositionVelocityStateLvlh, _t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(self.compute_input(state.get_vector()))
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::{Propagator, RungeKutta4Propagator};
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_pd_hcw_station_keeping() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let target = PositionVelocityStateLvlh::form_from_list([0.0, 50.0, 0.0], [0.0, 0.0, 0.0]);
+    let u_max = 1e-3;
+    let controller = PdController::new(&dynamics, arr1(&[1e-4, 1e-4, 1e-4]), arr1(&[2e-2, 2e-2, 2e-2]), target.clone(), Some(u_max)).unwrap();
+    assert!(PdController::new(&dynamics, arr1(&[1e-4, 1e-4]), arr1(&[2e-2, 2e-2, 2e-2]), target.clone(), None).is_err());
+
+    // 目標点では補償項だけが残り, HCW の自由運動を打ち消す
+    let u = controller.compute_input(target.get_vector());
+    assert!(u.iter().all(|v| v.abs() < 1e-15));
+
+    // 閉ループで目標の相対位置に収束し, 入力は飽和値を超えない
+    let mut state = PositionVelocityStateLvlh::form_from_list([100.0, -200.0, 50.0], [0.0, 0.0, 0.0]);
+    for k in 0..4000 {
+        let t = k as f64;
+        let u = controller.compute_control_input(&state, t);
+        assert!(u.get_vector().iter().all(|v| v.abs() <= u_max));
+        state = RungeKutta4Propagator.propagate_continuous(&state, &u, &dynamics, t, 1.0);
+    }
+    let error = state.get_vector() - target.get_vector();
+    assert!(error.iter().all(|v| v.abs() < 1e-2), "{:?}", error);
+}
\ No newline at end of file
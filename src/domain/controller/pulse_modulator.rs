@@ -0,0 +1,248 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use ndarray::Array1;
+
+use super::controller_trait::Controller;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::state_trait::StateVector;
+
+/// **時刻 t が含まれる制御周期の番号**
+fn period_index(t: f64, period: f64) -> i64 {
+    (t / period + 1e-9).floor() as i64
+}
+
+/// **パルス幅変調 (PWM)**
+/// 制御周期の始めに内側の制御器の連続指令を取り出し, 各軸を
+/// デューティ比 |u| / u_on だけ ±u_on でオン, 残りをオフにする
+pub struct PulseWidthModulator<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    controller: C,
+    period: f64,    // 制御周期 (s)
+    on_level: f64,  // オン時の出力 u_on
+    duty: RefCell<Option<(i64, Array1<f64>)>>,  // (周期番号, 各軸の符号付きデューティ比)
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<C, T, U> PulseWidthModulator<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    pub fn new(controller: C, period: f64, on_level: f64) -> Result<Self, &'static str> {
+        if period <= 0.0 {
+            return Err("制御周期は正である必要があります。");
+        }
+        if on_level <= 0.0 {
+            return Err("オン時の出力は正である必要があります。");
+        }
+        Ok(Self {
+            controller,
+            period,
+            on_level,
+            duty: RefCell::new(None),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<C, T, U> Controller<T, U> for PulseWidthModulator<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let k = period_index(t, self.period);
+        let mut duty = self.duty.borrow_mut();
+        if duty.as_ref().map(|(index, _)| *index) != Some(k) {
+            let command = self.controller.compute_control_input(state, k as f64 * self.period);
+            *duty = Some((k, command.get_vector().mapv(|u| (u / self.on_level).clamp(-1.0, 1.0))));
+        }
+
+        let (_, duty) = duty.as_ref().unwrap();
+        let elapsed = t - k as f64 * self.period;
+        U::form_from_array(duty.mapv(|d| {
+            if elapsed < d.abs() * self.period {
+                d.signum() * self.on_level
+            } else {
+                0.0
+            }
+        }))
+    }
+}
+
+/// **PWPF 変調器の内部状態**
+#[derive(Debug, Clone)]
+struct PwpfState {
+    index: Option<i64>,
+    filter: Array1<f64>,
+    output: Array1<f64>,
+}
+
+/// **パルス幅パルス周波数変調 (PWPF)**
+/// 指令と出力の差を一次遅れフィルタ (ゲイン K_m, 時定数 τ_m) に通し,
+/// シュミットトリガ (オン閾値 U_on, オフ閾値 U_off) で各軸の ±u_on / 0 を決める.
+/// 出力は制御周期ごとに更新して保持する
+pub struct PulseWidthPulseFrequencyModulator<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    controller: C,
+    period: f64,         // 制御周期 (s)
+    on_level: f64,       // オン時の出力 u_on
+    gain: f64,           // フィルタゲイン K_m
+    time_constant: f64,  // フィルタ時定数 τ_m (s)
+    on_threshold: f64,   // オン閾値 U_on
+    off_threshold: f64,  // オフ閾値 U_off
+    state: RefCell<PwpfState>,
+    _marker: PhantomData<(T, U)>,
+}
+
+impl<C, T, U> PulseWidthPulseFrequencyModulator<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    pub fn new(
+        controller: C,
+        period: f64,
+        on_level: f64,
+        gain: f64,
+        time_constant: f64,
+        on_threshold: f64,
+        off_threshold: f64,
+    ) -> Result<Self, &'static str> {
+        if period <= 0.0 {
+            return Err("制御周期は正である必要があります。");
+        }
+        if on_level <= 0.0 {
+            return Err("オン時の出力は正である必要があります。");
+        }
+        if time_constant <= 0.0 {
+            return Err("フィルタの時定数は正である必要があります。");
+        }
+        Ok(Self {
+            controller,
+            period,
+            on_level,
+            gain,
+            time_constant,
+            on_threshold,
+            off_threshold,
+            state: RefCell::new(PwpfState { index: None, filter: Array1::zeros(0), output: Array1::zeros(0) }),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<C, T, U> Controller<T, U> for PulseWidthPulseFrequencyModulator<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let k = period_index(t, self.period);
+        let mut pwpf = self.state.borrow_mut();
+
+        if pwpf.index != Some(k) {
+            let command = self.controller.compute_control_input(state, k as f64 * self.period).get_vector().clone();
+            if pwpf.filter.len() != command.len() {
+                pwpf.filter = Array1::zeros(command.len());
+                pwpf.output = Array1::zeros(command.len());
+            }
+
+            let phi = (-self.period / self.time_constant).exp();
+            pwpf.filter = phi * &pwpf.filter + self.gain * (1.0 - phi) * (&command - &pwpf.output);
+
+            for i in 0..command.len() {
+                let f = pwpf.filter[i];
+                if pwpf.output[i] == 0.0 {
+                    if f.abs() > self.on_threshold {
+                        pwpf.output[i] = f.signum() * self.on_level;
+                    }
+                } else if f.abs() < self.off_threshold {
+                    pwpf.output[i] = 0.0;
+                }
+            }
+            pwpf.index = Some(k);
+        }
+
+        U::form_from_array(pwpf.output.clone())
+    }
+}
+
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+
+#[cfg(test)]
+struct ConstantController {
+    command: [f64; 3],
+}
+
+#[cfg(test)]
+impl Controller<PositionVelocityStateLvlh, Force3dLvlh> for ConstantController {
+    fn compute_control_input(&self, _state: &PositionVelocityStateLvlh, _t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_list(self.command)
+    }
+}
+
+#[test]
+fn test_pwm_duty_cycle() {
+    let state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let pwm = PulseWidthModulator::new(ConstantController { command: [0.25, -0.5, 0.0] }, 1.0, 1.0).unwrap();
+
+    let dt = 0.01;
+    let mut average = Array1::<f64>::zeros(3);
+    for k in 0..1000 {
+        let u = pwm.compute_control_input(&state, k as f64 * dt);
+        assert!(u.get_vector().iter().all(|v| *v == 0.0 || v.abs() == 1.0));
+        average = average + u.get_vector() * dt / 10.0;
+    }
+
+    // 周期平均は連続指令に一致する
+    assert!((average[0] - 0.25).abs() < 1e-9);
+    assert!((average[1] + 0.5).abs() < 1e-9);
+    assert!(average[2].abs() < 1e-12);
+}
+
+#[test]
+fn test_pwpf_average_tracks_command() {
+    let state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let pwpf = PulseWidthPulseFrequencyModulator::new(ConstantController { command: [0.3, 0.0, -0.6] }, 0.01, 1.0, 10.0, 0.5, 0.5, 0.1).unwrap();
+
+    let n = 20000;
+    let mut sum = Array1::<f64>::zeros(3);
+    for k in 0..n {
+        let u = pwpf.compute_control_input(&state, k as f64 * 0.01);
+        assert!(u.get_vector().iter().all(|v| *v == 0.0 || v.abs() == 1.0));
+        sum = sum + u.get_vector();
+    }
+    let average = sum / n as f64;
+
+    // オンオフ列の平均はおおむね連続指令に一致し, 指令 0 の軸は噴かない
+    assert!((average[0] - 0.3).abs() < 0.05);
+    assert!((average[2] + 0.6).abs() < 0.05);
+    assert!(average[1] == 0.0);
+}
+
+#[test]
+fn test_pulse_modulators_reject_invalid_settings() {
+    let controller = || ConstantController { command: [0.3, 0.0, -0.6] };
+    assert!(PulseWidthModulator::new(controller(), 0.0, 1.0).is_err());
+    assert!(PulseWidthModulator::new(controller(), 1.0, 0.0).is_err());
+    assert!(PulseWidthPulseFrequencyModulator::new(controller(), 0.0, 1.0, 10.0, 0.5, 0.5, 0.1).is_err());
+    assert!(PulseWidthPulseFrequencyModulator::new(controller(), 0.01, 0.0, 10.0, 0.5, 0.5, 0.1).is_err());
+    assert!(PulseWidthPulseFrequencyModulator::new(controller(), 0.01, 1.0, 10.0, 0.0, 0.5, 0.1).is_err());
+}
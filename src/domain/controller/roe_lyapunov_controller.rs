@@ -0,0 +1,144 @@
+use ndarray::{Array1, Array2, arr2, s};
+
+use super::controller_trait::Controller;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::relative_orbital_elements::RelativeOrbitalElements;
+use crate::domain::state::state_trait::StateVector;
+
+/// **相対軌道要素のリアプノフ制御器 (連続推力)**
+/// ガウスの惑星方程式の制御行列 B (ほぼ円軌道の近似) を使い,
+/// V = ½ Δα̃ᵀ W Δα̃ を減らす入力 u = -k (n a)² B̃ᵀ W Δα̃ を deputy の RTN (chief の LVLH) で出す.
+/// δλ は推力で直接動かしにくいので, δa の誤差に -c Δδλ を加えてケプラー運動のドリフトで縮める
+/// (c = 2 / (3 n τ), τ は δλ を縮める時定数). 目標の δa は 0 とする.
+/// u_max を与えると方向を保ったまま各軸が ±u_max 以内になるよう縮める
+#[derive(Debug, Clone)]
+pub struct RoeLyapunovController {
+    target: RelativeOrbitalElements,
+    weights: Array1<f64>,      // [δa (δλ 込み), δex, δey, δix, δiy] の重み
+    convergence_rate: f64,     // k (1/s)
+    drift_time_constant: f64,  // τ (s)
+    u_max: Option<f64>,
+    mu: f64,
+}
+
+impl RoeLyapunovController {
+    pub fn new(
+        target: RelativeOrbitalElements,
+        weights: Array1<f64>,
+        convergence_rate: f64,
+        drift_time_constant: f64,
+        u_max: Option<f64>,
+        mu: f64,
+    ) -> Result<Self, &'static str> {
+        if weights.len() != 5 || weights.iter().any(|w| *w <= 0.0) {
+            return Err("重みは 5 個の正の値である必要があります。");
+        }
+        if convergence_rate <= 0.0 || drift_time_constant <= 0.0 {
+            return Err("収束の速さと時定数は正である必要があります。");
+        }
+        Ok(Self { target, weights, convergence_rate, drift_time_constant, u_max, mu })
+    }
+
+    /// **ガウスの惑星方程式の制御行列 (ほぼ円軌道, 6x3)**
+    /// d(δα)/dt = B u, u は RTN の推力加速度, u_mean は平均引数緯度
+    pub fn control_matrix(a: f64, u_mean: f64, mu: f64) -> Array2<f64> {
+        let n = (mu / a.powi(3)).sqrt();
+        let (sin_u, cos_u) = u_mean.sin_cos();
+        arr2(&[
+            [0.0, 2.0, 0.0],
+            [-2.0, 0.0, 0.0],
+            [sin_u, 2.0 * cos_u, 0.0],
+            [-cos_u, 2.0 * sin_u, 0.0],
+            [0.0, 0.0, cos_u],
+            [0.0, 0.0, sin_u],
+        ]) / (n * a)
+    }
+
+    pub fn target(&self) -> &RelativeOrbitalElements {
+        &self.target
+    }
+
+    /// **RTN の推力加速度**
+    pub fn compute_input(&self, pair: &PositionVelocityPairStateEci) -> Array1<f64> {
+        let chief = RelativeOrbitalElements::nonsingular_elements(&PositionVelocityStateEci::form_from_array(pair.chief()), self.mu);
+        let (a, u_mean) = (chief[0], chief[5]);
+        let n = (self.mu / a.powi(3)).sqrt();
+        let coupling = 2.0 / (3.0 * n * self.drift_time_constant);
+
+        // δa と δλ をまとめた 5 次元の誤差と制御行列
+        let error = RelativeOrbitalElements::from_pair(pair, self.mu).error_from(&self.target);
+        let mut reduced_error = error.slice(s![1..6]).to_owned();
+        reduced_error[0] = error[0] - coupling * error[1];
+        let b = Self::control_matrix(a, u_mean, self.mu);
+        let mut reduced_b = b.slice(s![1..6, ..]).to_owned();
+        let row = &b.row(0) - &(&b.row(1) * coupling);
+        reduced_b.row_mut(0).assign(&row);
+
+        let u = -reduced_b.t().dot(&(&self.weights * &reduced_error)) * (self.convergence_rate * (n * a).powi(2));
+        match self.u_max {
+            Some(u_max) => {
+                let peak = u.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+                if peak > u_max { u * (u_max / peak) } else { u }
+            }
+            None => u,
+        }
+    }
+}
+
+impl Controller<PositionVelocityPairStateEci, Force3dLvlh> for RoeLyapunovController {
+    fn compute_control_input(&self, state: &PositionVelocityPairStateEci, _t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(self.compute_input(state))
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::application::simulator::simulator::Simulator;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_2sat_2body::PairTwoBodyDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+#[cfg(test)]
+use crate::domain::force::force_converter::ForceConverter;
+#[cfg(test)]
+use crate::domain::force::force_6d_eci::Force6dEci;
+#[cfg(test)]
+use crate::domain::state::orbital_elements::OrbitalElements;
+#[cfg(test)]
+use crate::domain::state::state_converter::StateConverter;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_roe_lyapunov_reconfiguration() {
+    let mu = CONSTANTS.mu;
+    let a = CONSTANTS.radius + 500.0e3;
+    let chief = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, 0.0).unwrap();
+    let deputy = OrbitalElements::form_from_elements(a, 0.0, 1.0, 0.0, 0.3, -300.0 / a).unwrap();
+    let pair: PositionVelocityPairStateEci = vec![chief, deputy].convert();
+
+    // 300 m 後方から 100 m 後方の 200 m の相対 e/i ベクトルの編隊へ
+    let target = RelativeOrbitalElements::form_from_list([0.0, -100.0 / a, 0.0, 200.0 / a, 0.0, 200.0 / a]);
+    let u_max = 1e-4;
+    let controller = RoeLyapunovController::new(target.clone(), arr1(&[1.0, 1.0, 1.0, 1.0, 1.0]), 1e-3, 3000.0, Some(u_max), mu).unwrap();
+
+    let dt = 10.0;
+    let mut simulator = Simulator::new(RungeKutta4Propagator, PairTwoBodyDynamics::new(), pair.clone(), dt, 3000, 0.0);
+    let initial_error = RelativeOrbitalElements::from_pair(&pair, mu).error_from(&target) * a;
+    for _ in 0..simulator.step {
+        let input = controller.compute_control_input(simulator.get_state(), simulator.t);
+        assert!(input.get_vector().iter().all(|v| v.abs() <= u_max * (1.0 + 1e-12)));
+        let chief = PositionVelocityStateEci::form_from_array(simulator.get_state().chief());
+        let force: Force6dEci = input.convert(&chief);
+        simulator.update(&force);
+    }
+
+    // 各相対軌道要素の誤差 (m) が初期値より十分小さくなる
+    let error = RelativeOrbitalElements::from_pair(simulator.get_state(), mu).error_from(&target) * a;
+    assert!(initial_error.iter().fold(0.0_f64, |m, v| m.max(v.abs())) > 199.0);
+    assert!(error.iter().all(|v| v.abs() < 5.0), "{:?}", error);
+}
@@ -0,0 +1,177 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use super::controller_trait::Controller;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::state_trait::StateVector;
+
+/// **サンプル & ホールドの内部状態**
+#[derive(Debug, Clone)]
+struct HoldState<U: Force> {
+    sample_index: u64,            // 次に評価する周期の番号
+    next_sample_time: f64,        // 揺らぎを加えた次の評価時刻
+    pending: VecDeque<(f64, U)>,  // (出力される時刻, 入力) の計算中の入力
+    held: U,
+    last_sample_time: Option<f64>,
+    rng: StdRng,
+}
+
+/// **サンプル & ホールドの制御器**
+/// 内側の制御器を制御周期 (t0 + k period) ごとにだけ評価し, その間は出力を保持する.
+/// 各周期の評価時刻には ±jitter の一様な揺らぎを加え, 計算結果は delay だけ遅れて出力に反映する.
+/// 最初の出力が出るまでは 0 を返す. シミュレータの刻みが周期より粗い場合は 1 回の呼び出しで 1 回だけ評価する
+#[derive(Debug)]
+pub struct SampleAndHoldController<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    controller: C,
+    t0: f64,
+    period: f64,
+    delay: f64,
+    jitter: f64,
+    state: RefCell<HoldState<U>>,
+    _marker: PhantomData<T>,
+}
+
+impl<C, T, U> SampleAndHoldController<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    pub fn new(controller: C, t0: f64, period: f64, delay: f64, jitter: f64, seed: u64) -> Result<Self, &'static str> {
+        if period <= 0.0 {
+            return Err("制御周期は正である必要があります。");
+        }
+        if delay < 0.0 || jitter < 0.0 {
+            return Err("計算遅れと揺らぎは 0 以上である必要があります。");
+        }
+        if 2.0 * jitter >= period {
+            return Err("揺らぎは制御周期の半分未満である必要があります。");
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let next_sample_time = t0 + Self::sample_jitter(jitter, &mut rng);
+        let state = HoldState {
+            sample_index: 0,
+            next_sample_time,
+            pending: VecDeque::new(),
+            held: U::zeros(),
+            last_sample_time: None,
+            rng,
+        };
+        Ok(Self { controller, t0, period, delay, jitter, state: RefCell::new(state), _marker: PhantomData })
+    }
+
+    fn sample_jitter(jitter: f64, rng: &mut StdRng) -> f64 {
+        if jitter > 0.0 { rng.gen_range(-jitter..=jitter) } else { 0.0 }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.controller
+    }
+
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    /// **最後に内側の制御器を評価した時刻**
+    pub fn last_sample_time(&self) -> Option<f64> {
+        self.state.borrow().last_sample_time
+    }
+}
+
+impl<C, T, U> Controller<T, U> for SampleAndHoldController<C, T, U>
+where
+    C: Controller<T, U>,
+    T: StateVector,
+    U: Force,
+{
+    fn compute_control_input(&self, state: &T, t: f64) -> U {
+        let mut hold = self.state.borrow_mut();
+        let eps = 1e-9 * self.period;
+
+        if t + eps >= hold.next_sample_time {
+            let input = self.controller.compute_control_input(state, t);
+            hold.pending.push_back((t + self.delay, input));
+            hold.last_sample_time = Some(t);
+
+            // 通り過ぎた周期は評価せずに飛ばす
+            while t + eps >= hold.next_sample_time {
+                hold.sample_index += 1;
+                let nominal = self.t0 + hold.sample_index as f64 * self.period;
+                hold.next_sample_time = nominal + Self::sample_jitter(self.jitter, &mut hold.rng);
+            }
+        }
+
+        while hold.pending.front().is_some_and(|(t_ready, _)| *t_ready <= t + eps) {
+            let (_, input) = hold.pending.pop_front().unwrap();
+            hold.held = input;
+        }
+        hold.held.clone()
+    }
+}
+
+#[cfg(test)]
+use std::cell::Cell;
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+
+/// **評価した時刻を入力として返し, 呼び出し回数を数える制御器**
+#[cfg(test)]
+struct ClockController {
+    calls: Cell<usize>,
+}
+
+#[cfg(test)]
+impl Controller<PositionVelocityStateLvlh, Force3dLvlh> for ClockController {
+    fn compute_control_input(&self, _state: &PositionVelocityStateLvlh, t: f64) -> Force3dLvlh {
+        self.calls.set(self.calls.get() + 1);
+        Force3dLvlh::form_from_array(arr1(&[t, 0.0, 0.0]))
+    }
+}
+
+#[test]
+fn test_sample_and_hold_period_delay_jitter() {
+    let state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let dt = 0.02;
+
+    // 1 Hz で評価し, 0.3 s 遅れて出力する
+    let controller = SampleAndHoldController::new(ClockController { calls: Cell::new(0) }, 0.0, 1.0, 0.3, 0.0, 0).unwrap();
+    for k in 0..250 {
+        let t = k as f64 * dt;
+        let u = controller.compute_control_input(&state, t);
+        let expected = if t < 0.3 - 1e-9 { 0.0 } else { ((t - 0.3) + 1e-9).floor() };
+        assert!((u.get_vector()[0] - expected).abs() < 1e-9, "t = {}, u = {}", t, u.get_vector()[0]);
+    }
+    assert_eq!(controller.inner().calls.get(), 5);
+    assert!((controller.last_sample_time().unwrap() - 4.0).abs() < 1e-9);
+
+    // 揺らぎがあっても評価は周期ごとに 1 回で, 評価時刻は公称時刻から ±jitter (刻み分の誤差を含む) 以内
+    let jitter = 0.1;
+    let controller = SampleAndHoldController::new(ClockController { calls: Cell::new(0) }, 0.0, 1.0, 0.0, jitter, 7).unwrap();
+    let mut sample_times = Vec::new();
+    for k in 0..5000 {
+        let u = controller.compute_control_input(&state, k as f64 * dt);
+        let t_sample = controller.last_sample_time().unwrap_or(-1.0);
+        if t_sample >= 0.0 && sample_times.last() != Some(&t_sample) {
+            assert_eq!(u.get_vector()[0], t_sample);
+            sample_times.push(t_sample);
+        }
+    }
+    assert_eq!(controller.inner().calls.get(), 100);
+    assert!(sample_times.iter().enumerate().all(|(k, t)| (t - k as f64).abs() <= jitter + dt));
+    assert!(sample_times.iter().any(|t| (t - t.round()).abs() > dt));
+
+    assert!(SampleAndHoldController::new(ClockController { calls: Cell::new(0) }, 0.0, 1.0, 0.0, 0.5, 0).is_err());
+}
\ No newline at end of file
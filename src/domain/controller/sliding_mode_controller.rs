@@ -0,0 +1,108 @@
+use ndarray::{Array1, Array2, s};
+
+use super::controller_trait::Controller;
+use crate::domain::dynamics::dynamics_trait::LinearSystem;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+
+/// **境界層付きのスライディングモード制御器**
+/// 切り替え面 s = (v - v_ref) + λ (r - r_ref) に対し
+/// u = -A_a x - λ (v - v_ref) - η sat(s / φ). A_a は HCW の系行列の加速度の行 (3x6).
+/// sat は ±1 で飽和する線形関数で, 境界層 |s| < φ の中では高ゲインの線形制御になりチャタリングを抑える.
+/// 境界層の外では未知の外乱加速度が η 未満なら s は有限時間で境界層に入る.
+/// u_max を与えると各軸を ±u_max で飽和させる
+#[derive(Debug, Clone)]
+pub struct SlidingModeController {
+    lambda: f64,          // 切り替え面の傾き (1/s)
+    eta: f64,             // 到達則のゲイン (m/s²)
+    boundary_layer: f64,  // φ (m/s)
+    target: PositionVelocityStateLvlh,
+    natural_acceleration: Array2<f64>,
+    u_max: Option<f64>,
+}
+
+impl SlidingModeController {
+    pub fn new(
+        dynamics: &dyn LinearSystem,
+        lambda: f64,
+        eta: f64,
+        boundary_layer: f64,
+        target: PositionVelocityStateLvlh,
+        u_max: Option<f64>,
+    ) -> Result<Self, &'static str> {
+        if lambda <= 0.0 || eta <= 0.0 || boundary_layer <= 0.0 {
+            return Err("λ, η と境界層の厚さは正である必要があります。");
+        }
+        let natural_acceleration = dynamics.system_matrix().slice(s![3..6, ..]).to_owned();
+        Ok(Self { lambda, eta, boundary_layer, target, natural_acceleration, u_max })
+    }
+
+    pub fn target(&self) -> &PositionVelocityStateLvlh {
+        &self.target
+    }
+
+    /// **切り替え関数 s (3 軸)**
+    pub fn sliding_surface(&self, x: &Array1<f64>) -> Array1<f64> {
+        let error = x - self.target.get_vector();
+        &error.slice(s![3..6]) + &(&error.slice(s![0..3]) * self.lambda)
+    }
+
+    pub fn compute_input(&self, x: &Array1<f64>) -> Array1<f64> {
+        let error = x - self.target.get_vector();
+        let reaching = self.sliding_surface(x).mapv(|v| (v / self.boundary_layer).clamp(-1.0, 1.0)) * self.eta;
+        let u = -self.natural_acceleration.dot(x) - &error.slice(s![3..6]) * self.lambda - reaching;
+        match self.u_max {
+            Some(u_max) => u.mapv(|v| v.clamp(-u_max, u_max)),
+            None => u,
+        }
+    }
+}
+
+impl Controller<PositionVelocityStateLvlh, Force3dLvlh> for SlidingModeController {
+    fn compute_control_input(&self, state: &PositionVelocityStateLvlh, _t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(self.compute_input(state.get_vector()))
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::{Propagator, RungeKutta4Propagator};
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_sliding_mode_hcw_with_disturbance() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let target = PositionVelocityStateLvlh::form_from_list([0.0, 50.0, 0.0], [0.0, 0.0, 0.0]);
+    let u_max = 1e-3;
+    let controller = SlidingModeController::new(&dynamics, 0.01, 5e-4, 1e-2, target.clone(), Some(u_max)).unwrap();
+    assert!(SlidingModeController::new(&dynamics, 0.01, 5e-4, 0.0, target.clone(), None).is_err());
+
+    // モデル化していない一定の外乱加速度 (η 未満) があっても目標の近くに留まる
+    let disturbance = arr1(&[5e-6, -5e-6, 5e-6]);
+    let mut state = PositionVelocityStateLvlh::form_from_list([20.0, -30.0, 10.0], [0.0, 0.0, 0.0]);
+    let mut previous_input: Option<Array1<f64>> = None;
+    for k in 0..4000 {
+        let t = k as f64;
+        let u = controller.compute_control_input(&state, t);
+        assert!(u.get_vector().iter().all(|v| v.abs() <= u_max));
+
+        // 境界層に入った後は入力が符号の切り替えで振動しない
+        if k > 3000 {
+            let previous = previous_input.as_ref().unwrap();
+            assert!((u.get_vector() - previous).iter().all(|v| v.abs() < 1e-6));
+        }
+        previous_input = Some(u.get_vector().clone());
+        let total = Force3dLvlh::form_from_array(u.get_vector() + &disturbance);
+        state = RungeKutta4Propagator.propagate_continuous(&state, &total, &dynamics, t, 1.0);
+    }
+    let surface = controller.sliding_surface(state.get_vector());
+    assert!(surface.iter().all(|v| v.abs() < 1e-2), "{:?}", surface);
+    let error = state.get_vector() - target.get_vector();
+    assert!(error.slice(s![0..3]).iter().all(|v| v.abs() < 0.1), "{:?}", error);
+}
\ No newline at end of file
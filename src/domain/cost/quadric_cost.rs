@@ -2,7 +2,7 @@ use ndarray::{Array1, Array2};
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::force::force_trait::Force;
 use crate::domain::cost::cost_trait::Cost;
-use crate::domain::differentiable::differentiable_trait::Differentiable1d;
+use crate::domain::differentiable::differentiable_trait::{Differentiable1d, InputDifferentiable1d};
 
 /// **LQR の 2 次形式のコスト関数**
 #[derive(Clone)]
@@ -22,6 +22,22 @@ impl QuadraticCost {
             t_last,
         }
     }
+
+    pub fn q_matrix(&self) -> &Array2<f64> {
+        &self.q_matrix
+    }
+
+    pub fn r_matrix(&self) -> &Array2<f64> {
+        &self.r_matrix
+    }
+
+    pub fn qf_matrix(&self) -> &Array2<f64> {
+        &self.qf_matrix
+    }
+
+    pub fn t_last(&self) -> f64 {
+        self.t_last
+    }
 }
 
 impl<T, U> Cost<T, U> for QuadraticCost
@@ -48,16 +64,31 @@ where
     T: StateVector,
     U: Force,
 {
-    #[allow(unused_variables)]
-    fn differentiate(&self, x: &T, v: &U, t: f64) -> Array1<f64> {
+    /// ステージコストの状態に関する勾配 Q x (終端では Q_f x).
+    /// 勾配は 1/2 を付けたコスト 1/2 x^T Q x について返す (モードスケジューラの随伴変数もこの規約)
+    fn differentiate(&self, x: &T, _v: &U, t: f64) -> Array1<f64> {
         let x_vec = x.get_vector();
         if t >= self.t_last {
             // 終端コストの微分
             self.qf_matrix.dot(x_vec)
         } else {
             // ステージコストの微分
-            let x_grad = self.q_matrix.dot(x_vec);
-            x_grad
+            self.q_matrix.dot(x_vec)
+        }
+    }
+}
+
+impl<T, U> InputDifferentiable1d<T, U> for QuadraticCost
+where
+    T: StateVector,
+    U: Force,
+{
+    /// ステージコストの入力に関する勾配 R u (状態の勾配と同じく 1/2 u^T R u について. 終端コストは入力に依存しない)
+    fn differentiate_input(&self, _x: &T, v: &U, t: f64) -> Array1<f64> {
+        if t >= self.t_last {
+            Array1::zeros(v.get_vector().len())
+        } else {
+            self.r_matrix.dot(v.get_vector())
         }
     }
 }
@@ -65,36 +96,101 @@ where
 #[cfg(test)]
 use ndarray::arr1;
 #[cfg(test)]
-use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
 #[cfg(test)]
-use crate::domain::force::force_3d_eci::Force3dEci;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
 
 #[test]
 fn test_quadratic_cost() {
     // 状態と制御の次元数
-    let state_dim = 6;  // 修正: 状態次元を 6 に統一
-    let control_dim = 3; // 修正: 制御次元を 3 に統一
+    let state_dim = 6;
+    let control_dim = 3;
 
     // Q, R, Q_f の行列を定義
-    let q_matrix = Array2::<f64>::eye(state_dim);
+    let q_matrix = Array2::<f64>::eye(state_dim) * 2.0;
     let r_matrix = Array2::<f64>::eye(control_dim);
-    let qf_matrix = Array2::<f64>::eye(state_dim);
+    let qf_matrix = Array2::<f64>::eye(state_dim) * 3.0;
 
     // 終端時刻
     let t_last = 10.0;
 
     // コスト関数のインスタンスを作成
-    let cost_function = QuadraticCost::new(q_matrix, r_matrix, qf_matrix, t_last);
+    let cost_function = QuadraticCost::new(q_matrix.clone(), r_matrix, qf_matrix.clone(), t_last);
 
     // 状態と制御入力を仮定
-    let x = PositionVelocityPairStateEci::form_from_array(arr1(&[1.0, 2.0, 3.0, 0.0, 0.0, 0.0])); // 修正: 状態の次元を6に
-    let v = Force3dEci::form_from_array(arr1(&[0.5, 1.5, 0.0])); // 修正: 制御の次元を3に
+    let x = PositionVelocityStateLvlh::form_from_array(arr1(&[1.0, 2.0, 3.0, 0.0, 0.0, 0.0]));
+    let v = Force3dLvlh::form_from_array(arr1(&[0.5, 1.5, 0.0]));
 
-    // t = 5.0 での勾配
+    // t = 5.0 での勾配は Q x, t = 10.0 (終端) では Q_f x
     let grad_before_t_last = cost_function.differentiate(&x, &v, 5.0);
-    println!("Gradient at t=5.0: {:?}", grad_before_t_last);
-
-    // t = 10.0 (終端) での勾配
+    assert_eq!(grad_before_t_last, q_matrix.dot(x.get_vector()));
     let grad_at_t_last = cost_function.differentiate(&x, &v, 10.0);
-    println!("Gradient at t=10.0: {:?}", grad_at_t_last);
+    assert_eq!(grad_at_t_last, qf_matrix.dot(x.get_vector()));
+
+    // 勾配の差分から求めたヘッセ行列は Q, Q_f
+    let epsilon = 1e-6;
+    for (t, expected) in [(5.0, &q_matrix), (10.0, &qf_matrix)] {
+        for j in 0..state_dim {
+            let mut plus = x.get_vector().clone();
+            let mut minus = x.get_vector().clone();
+            plus[j] += epsilon;
+            minus[j] -= epsilon;
+            let column = (cost_function.differentiate(&PositionVelocityStateLvlh::form_from_array(plus), &v, t)
+                - cost_function.differentiate(&PositionVelocityStateLvlh::form_from_array(minus), &v, t))
+                / (2.0 * epsilon);
+            assert!((&column - &expected.column(j)).iter().all(|h| h.abs() < 1e-6));
+        }
+    }
+}
+
+#[test]
+fn test_quadratic_cost_input_gradient() {
+    let r_matrix = ndarray::arr2(&[[2.0, 0.5, 0.0], [0.5, 1.0, 0.0], [0.0, 0.0, 3.0]]);
+    let cost_function = QuadraticCost::new(Array2::eye(6), r_matrix, Array2::eye(6), 10.0);
+    let x = PositionVelocityStateLvlh::form_from_array(arr1(&[1.0, 2.0, 3.0, 0.1, 0.2, 0.3]));
+    let v = Force3dLvlh::form_from_array(arr1(&[0.5, -1.5, 0.2]));
+
+    // 1/2 を付けたコストの中心差分との比較
+    let analytical = cost_function.differentiate_input(&x, &v, 5.0);
+    let epsilon = 1e-6;
+    for j in 0..3 {
+        let mut plus = v.get_vector().clone();
+        let mut minus = v.get_vector().clone();
+        plus[j] += epsilon;
+        minus[j] -= epsilon;
+        let numerical = 0.5 * (cost_function.stage_cost(&x, &Force3dLvlh::form_from_array(plus))
+            - cost_function.stage_cost(&x, &Force3dLvlh::form_from_array(minus)))
+            / (2.0 * epsilon);
+        assert!((analytical[j] - numerical).abs() < 1e-6);
+    }
+
+    // 終端では入力に依存しない
+    assert!(cost_function.differentiate_input(&x, &v, 10.0).iter().all(|g| *g == 0.0));
+}
+
+#[test]
+fn test_quadratic_cost_state_gradient() {
+    let q_matrix = Array2::from_diag(&arr1(&[1.0, 2.0, 3.0, 0.5, 0.1, 4.0])) + Array2::<f64>::ones((6, 6)) * 0.1;
+    let qf_matrix = Array2::<f64>::eye(6) * 5.0;
+    let cost_function = QuadraticCost::new(q_matrix, Array2::eye(3), qf_matrix, 10.0);
+    let x = PositionVelocityStateLvlh::form_from_array(arr1(&[1.0, -2.0, 3.0, 0.1, 0.2, -0.3]));
+    let v = Force3dLvlh::form_from_array(arr1(&[0.5, -1.5, 0.2]));
+
+    // ステージ・終端とも 1/2 を付けたコストの中心差分と一致する
+    let stage = |x: &PositionVelocityStateLvlh| cost_function.stage_cost(x, &v);
+    let terminal = |x: &PositionVelocityStateLvlh| Cost::<_, Force3dLvlh>::terminal_cost(&cost_function, x);
+    let epsilon = 1e-6;
+    for (t, cost) in [(5.0, &stage as &dyn Fn(&PositionVelocityStateLvlh) -> f64), (10.0, &terminal)] {
+        let analytical = cost_function.differentiate(&x, &v, t);
+        for i in 0..6 {
+            let mut plus = x.get_vector().clone();
+            let mut minus = x.get_vector().clone();
+            plus[i] += epsilon;
+            minus[i] -= epsilon;
+            let numerical = 0.5 * (cost(&PositionVelocityStateLvlh::form_from_array(plus))
+                - cost(&PositionVelocityStateLvlh::form_from_array(minus)))
+                / (2.0 * epsilon);
+            assert!((analytical[i] - numerical).abs() < 1e-6, "t = {}, i = {}: {} vs {}", t, i, analytical[i], numerical);
+        }
+    }
 }
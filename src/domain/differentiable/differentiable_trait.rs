@@ -10,6 +10,11 @@ pub trait Differentiable2d<T: StateVector, U: Force> {
     fn differentiate(&self, x: &T, v: &U, t: f64) -> Array2<f64>;
 }
 
+/// **入力に関するヤコビアン ∂f/∂u**
+pub trait InputDifferentiable2d<T: StateVector, U: Force> {
+    fn differentiate_input(&self, x: &T, v: &U, t: f64) -> Array2<f64>;
+}
+
 pub struct NumericalDefferential<T,U>{
     _marker: PhantomData<T>,
     _marker2: PhantomData<U>
@@ -64,6 +69,28 @@ impl<T: StateVector, U: Force> NumericalDefferential<T, U>{
 
         gradient
     }
+
+    /// **入力に関するヤコビアン ∂f/∂u (中心差分)**
+    /// 解析的な `InputDifferentiable2d` を持たないダイナミクス向け
+    pub fn differentiate_input_numeric(func: &dyn Fn(&T, &U) -> T, x: &T, v: &U) -> Array2<f64> {
+        let dim = x.get_vector().len();
+        let input_dim = v.get_vector().len();
+        let mut jacobian = Array2::<f64>::zeros((dim, input_dim));
+
+        for j in 0..input_dim {
+            let epsilon = 1e-6 * (1.0 + v.get_vector()[j].abs());
+            let mut perturbed_plus = v.get_vector().clone();
+            let mut perturbed_minus = v.get_vector().clone();
+            perturbed_plus[j] += epsilon;
+            perturbed_minus[j] -= epsilon;
+
+            let f_plus = func(x, &U::form_from_array(perturbed_plus));
+            let f_minus = func(x, &U::form_from_array(perturbed_minus));
+            jacobian.column_mut(j).assign(&((f_plus.get_vector() - f_minus.get_vector()) / (2.0 * epsilon)));
+        }
+
+        jacobian
+    }
 }
 
 
@@ -76,3 +103,8 @@ pub trait Differentiable1d<T: StateVector, U: Force> {
         grad
     }
 }
+
+/// **入力に関する勾配 ∂L/∂u (コスト関数用)**
+pub trait InputDifferentiable1d<T: StateVector, U: Force> {
+    fn differentiate_input(&self, x: &T, v: &U, t: f64) -> Array1<f64>;
+}
@@ -1,3 +1,10 @@
 pub mod disturbance_trait;
 pub mod j2_disturbance;
-pub mod air_drag_disturbance;
\ No newline at end of file
+pub mod air_drag_disturbance;
+pub mod atmosphere_model;
+pub mod space_weather;
+pub mod wind_model;
+pub mod stochastic_disturbance;
+pub mod relative_disturbance;
+pub mod earth_radiation_disturbance;
+pub mod variable_mass_disturbance;
\ No newline at end of file
@@ -5,43 +5,40 @@ use crate::domain::state::state_trait::StateVector;
 use crate::domain::{math::formulations::Math, state::position_velocity_state_eci::PositionVelocityStateEci};
 use crate::domain::force::force_3d_eci::Force3dEci;
 use crate::domain::force::force_trait::Force;
-use crate::infrastructure::settings::constants::CONSTANTS;
 use crate::domain::force::force_6d_eci::Force6dEci;
+use crate::infrastructure::settings::constants::CONSTANTS;
+use super::atmosphere_model::AtmosphereModel;
+use super::wind_model::WindModel;
+use crate::domain::attitude::attitude_provider::AttitudeProvider;
 
 use std::f64::consts::PI;
 use ndarray::{Array1, arr1};
 use statrs::function::erf::erf;
 
+/// **空力・放射圧を受ける面**
+/// 法線は機体座標系の外向きの単位ベクトル. 空気抵抗・放射圧のどちらも, 法線が流れの来る側
+/// (機体の速度の向き) や光源の側を向いた面だけが流れや光を受ける
 #[derive(Debug, Clone)]
 pub struct Surface {
     pub normal_direction: Array1<f64>,
     pub area_m2: f64,
     pub air_specularity: f64,
+    pub specular_reflectivity: f64,  // 光の鏡面反射率
+    pub diffuse_reflectivity: f64,   // 光の拡散反射率 (吸収率 = 1 - 鏡面 - 拡散)
 }
 
 pub trait AirDragForInertiaState<T: StateVector> {
-    fn calc_air_density(&self, altitude: f64) -> f64 {
-        let altitude_km = altitude / 1000.0;
-        let (scale_height_km, base_height_km, base_rho_kg_m3) = match altitude_km {
-            a if a > 1000.0 => (268.0, 1000.0, 3.019E-15),
-            a if (900.0..1000.0).contains(&a) => (181.05, 900.0, 5.245E-15),
-            a if (800.0..900.0).contains(&a) => (124.64, 800.0, 1.170E-14),
-            a if (700.0..800.0).contains(&a) => (88.667, 700.0, 3.614E-14),
-            a if (600.0..700.0).contains(&a) => (71.835, 600.0, 1.454E-13),
-            a if (500.0..600.0).contains(&a) => (63.822, 500.0, 6.967E-13),
-            a if (400.0..500.0).contains(&a) => (58.515, 400.0, 3.725E-12),
-            a if (300.0..400.0).contains(&a) => (53.298, 350.0, 9.158E-12),
-            a if (200.0..300.0).contains(&a) => (45.546, 250.0, 7.248E-11),
-            a if (100.0..200.0).contains(&a) => (37.105, 200.0, 2.789E-10),
-            a if (80.0..100.0).contains(&a) => (9.473, 120.0, 2.438E-8),
-            a if (60.0..80.0).contains(&a) => (7.714, 60.0, 3.206E-4),
-            a if (40.0..60.0).contains(&a) => (7.554, 40.0, 3.972E-3),
-            a if (20.0..40.0).contains(&a) => (6.682, 30.0, 1.774E-2),
-            a if (0.0..20.0).contains(&a) => (7.249, 0.0, 1.225),
-            _ => (7.249, 0.0, 0.0),
-        };
-
-        base_rho_kg_m3 * f64::exp(-(altitude_km - base_height_km) / scale_height_km)
+    fn atmosphere(&self) -> &dyn AtmosphereModel;
+    fn wind(&self) -> Option<&dyn WindModel>;
+
+    /// **大気に対する相対速度 v - ω_E × r - v_wind (ECI)**
+    fn calc_relative_velocity(&self, position: &Array1<f64>, velocity: &Array1<f64>, t: f64) -> Array1<f64> {
+        let earth_rotation = arr1(&[0.0, 0.0, CONSTANTS.earth_rotation_rate]);
+        let mut relative_velocity = velocity - &Math::cross_product(&earth_rotation, position);
+        if let Some(wind) = self.wind() {
+            relative_velocity = &relative_velocity - &wind.calc_wind_velocity(position, t);
+        }
+        relative_velocity
     }
 
     fn calc_function_pi(&self, s: f64) -> f64 {
@@ -63,10 +60,16 @@ pub trait AirDragForInertiaState<T: StateVector> {
         wall_temperature: f64,
         molecular_temperature: f64,
         surfaces: &Vec<Surface>,
+        attitude: &dyn AttitudeProvider,
+        t: f64,
     ) -> Array1<f64> {
-        let altitude = position.dot(&position).sqrt() - CONSTANTS.radius;
+        let body_to_eci = attitude.body_to_eci(&position, &velocity, t);
+        let velocity = self.calc_relative_velocity(&position, &velocity, t);
         let velocity_norm = velocity.dot(&velocity).sqrt();
-        let air_density = self.calc_air_density(altitude);
+        if velocity_norm == 0.0 {
+            return arr1(&[0.0, 0.0, 0.0]);
+        }
+        let air_density = self.atmosphere().calc_air_density(&position, t);
         let speed = (molecular_weight * velocity_norm * velocity_norm
             / (2.0 * CONSTANTS.boltzmann_constant * wall_temperature))
             .sqrt();
@@ -74,13 +77,15 @@ pub trait AirDragForInertiaState<T: StateVector> {
         let mut force = arr1(&[0.0, 0.0, 0.0]);
 
         for surface in surfaces {
-            let cos_theta = surface.normal_direction.dot(&velocity) / velocity_norm;
-            if cos_theta > 0.0 {
+            // 外向き法線が機体の速度の向き (流れの来る側) を向いた面だけが流れを受ける
+            let normal_direction = body_to_eci.dot(&surface.normal_direction);
+            let cos_theta = normal_direction.dot(&velocity) / velocity_norm;
+            if cos_theta < 0.0 {
                 continue;
             }
 
             let sin_theta = (1.0 - cos_theta.powi(2)).sqrt();
-            let speed_n = -speed * cos_theta;
+            let speed_n = speed * cos_theta;
             let speed_t = -speed * sin_theta;
             let diffuse = 1.0 - surface.air_specularity;
 
@@ -94,9 +99,14 @@ pub trait AirDragForInertiaState<T: StateVector> {
             let normal_coefficient = k * cn;
             let tangential_coefficient = k * ct;
 
-            let tangential_direction = Math::normalize(&Math::cross_product(&velocity, &surface.normal_direction));
-
-            force = &force + normal_coefficient * &surface.normal_direction + tangential_coefficient * tangential_direction;
+            force = &force - normal_coefficient * &normal_direction;
+            // せん断力は流れと法線が張る面内で, 相対速度の接線成分 v - (v·n)n に沿う (ct < 0 なので逆向き).
+            // 流れが面に垂直なときは接線方向が定まらない (接線力も 0)
+            let tangential_velocity = &velocity - velocity.dot(&normal_direction) * &normal_direction;
+            let tangential_norm = tangential_velocity.dot(&tangential_velocity).sqrt();
+            if tangential_norm > 1e-12 * velocity_norm {
+                force = &force + tangential_coefficient / tangential_norm * &tangential_velocity;
+            }
         }
 
         force /= mass;
@@ -112,6 +122,9 @@ pub struct AirDragStateEci {
     molecular_temperature: f64,
     mass: f64,
     surfaces: Vec<Surface>,
+    attitude: Box<dyn AttitudeProvider>,
+    atmosphere: Box<dyn AtmosphereModel>,
+    wind: Option<Box<dyn WindModel>>,
 }
 
 
@@ -122,6 +135,9 @@ impl AirDragStateEci {
         molecular_temperature: f64,
         mass: f64,
         surfaces: Vec<Surface>,
+        attitude: Box<dyn AttitudeProvider>,
+        atmosphere: Box<dyn AtmosphereModel>,
+        wind: Option<Box<dyn WindModel>>,
     ) -> Self {
         Self {
             molecular_weight,
@@ -129,16 +145,26 @@ impl AirDragStateEci {
             molecular_temperature,
             mass,
             surfaces,
+            attitude,
+            atmosphere,
+            wind,
         }
     }
 }
 
 impl AirDragForInertiaState<PositionVelocityStateEci> for AirDragStateEci {
+    fn atmosphere(&self) -> &dyn AtmosphereModel {
+        self.atmosphere.as_ref()
+    }
+
+    fn wind(&self) -> Option<&dyn WindModel> {
+        self.wind.as_deref()
+    }
 }
 
 
 impl DisturbanceCalculator<PositionVelocityStateEci, Force3dEci> for AirDragStateEci {
-    fn calc_force(&self, state_eci: &PositionVelocityStateEci) -> Force3dEci {
+    fn calc_force(&self, state_eci: &PositionVelocityStateEci, t: f64) -> Force3dEci {
         Force3dEci::form_from_array(self.calc_force_(
             state_eci.position(),
             state_eci.velocity(),
@@ -147,6 +173,8 @@ impl DisturbanceCalculator<PositionVelocityStateEci, Force3dEci> for AirDragStat
             self.wall_temperature,
             self.molecular_temperature,
             &self.surfaces,
+            self.attitude.as_ref(),
+            t,
         ))
     }
 }
@@ -159,10 +187,14 @@ pub struct AirDragStatePairEci {
     molecular_temperature: f64,
     mass_chief: f64,
     surfaces_chief: Vec<Surface>,
+    attitude_chief: Box<dyn AttitudeProvider>,
     molecular_weight_deputy: f64,
     wall_temperature_deputy: f64,
     mass_deputy: f64,
     surfaces_deputy: Vec<Surface>,
+    attitude_deputy: Box<dyn AttitudeProvider>,
+    atmosphere: Box<dyn AtmosphereModel>,
+    wind: Option<Box<dyn WindModel>>,
 }
 
 impl AirDragStatePairEci {
@@ -172,10 +204,14 @@ impl AirDragStatePairEci {
         molecular_temperature: f64,
         mass_chief: f64,
         surfaces_chief: Vec<Surface>,
+        attitude_chief: Box<dyn AttitudeProvider>,
         molecular_weight_deputy: f64,
         wall_temperature_deputy: f64,
         mass_deputy: f64,
         surfaces_deputy: Vec<Surface>,
+        attitude_deputy: Box<dyn AttitudeProvider>,
+        atmosphere: Box<dyn AtmosphereModel>,
+        wind: Option<Box<dyn WindModel>>,
     ) -> Self {
         Self {
             molecular_weight_chief,
@@ -183,19 +219,30 @@ impl AirDragStatePairEci {
             molecular_temperature,
             mass_chief,
             surfaces_chief,
+            attitude_chief,
             molecular_weight_deputy,
             wall_temperature_deputy,
             mass_deputy,
             surfaces_deputy,
+            attitude_deputy,
+            atmosphere,
+            wind,
         }
     }
 }
 
 impl AirDragForInertiaState<PositionVelocityPairStateEci> for AirDragStatePairEci {
+    fn atmosphere(&self) -> &dyn AtmosphereModel {
+        self.atmosphere.as_ref()
+    }
+
+    fn wind(&self) -> Option<&dyn WindModel> {
+        self.wind.as_deref()
+    }
 }
 
 impl DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci> for AirDragStatePairEci {
-    fn calc_force(&self, state_eci: &PositionVelocityPairStateEci) -> Force6dEci {
+    fn calc_force(&self, state_eci: &PositionVelocityPairStateEci, t: f64) -> Force6dEci {
         let state_vec: Vec<PositionVelocityStateEci> = state_eci.convert();
         let state_chief = &state_vec[0];
         let state_deputy = &state_vec[1];
@@ -208,6 +255,8 @@ impl DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci> for AirDrag
             self.wall_temperature_chief,
             self.molecular_temperature,
             &self.surfaces_chief,
+            self.attitude_chief.as_ref(),
+            t,
         );
         let force_deputy = self.calc_force_(
             state_deputy.position(),
@@ -217,6 +266,8 @@ impl DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci> for AirDrag
             self.wall_temperature_deputy,
             self.molecular_temperature,
             &self.surfaces_deputy,
+            self.attitude_deputy.as_ref(),
+            t,
         );
 
         Force6dEci::form_from_list([
@@ -225,3 +276,89 @@ impl DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci> for AirDrag
         ])
     }
 }
+
+#[cfg(test)]
+use super::atmosphere_model::ExponentialAtmosphere;
+#[cfg(test)]
+use crate::domain::attitude::attitude_provider::{InertialAttitude, LvlhPointingAttitude};
+
+#[test]
+fn test_air_drag_corotating_atmosphere() {
+    let surfaces = vec![
+        Surface { air_specularity: 0.4, specular_reflectivity: 0.1, diffuse_reflectivity: 0.3, area_m2: 1.0, normal_direction: arr1(&[0.0, 1.0, 0.0]) },
+        Surface { air_specularity: 0.4, specular_reflectivity: 0.1, diffuse_reflectivity: 0.3, area_m2: 1.0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
+    ];
+    let drag = AirDragStateEci::new(
+        18.0, 30.0, 3.0, 50.0, surfaces,
+        Box::new(InertialAttitude::new()),
+        Box::new(ExponentialAtmosphere::new()),
+        None,
+    );
+    let r = CONSTANTS.radius + 400.0e3;
+
+    // 大気と共回転している物体には抗力が働かない
+    let corotating = PositionVelocityStateEci::form_from_list([r, 0.0, 0.0], [0.0, CONSTANTS.earth_rotation_rate * r, 0.0]);
+    let force = drag.calc_force(&corotating, 0.0).get_vector().clone();
+    assert!(force.iter().all(|f| f.abs() < 1e-20));
+
+    // 順行軌道では相対風速が小さくなる分, 抗力も小さくなる
+    let v = (CONSTANTS.mu / r).sqrt();
+    let prograde = drag.calc_force(&PositionVelocityStateEci::form_from_list([r, 0.0, 0.0], [0.0, v, 0.0]), 0.0).get_vector().clone();
+    let retrograde = drag.calc_force(&PositionVelocityStateEci::form_from_list([r, 0.0, 0.0], [0.0, -v, 0.0]), 0.0).get_vector().clone();
+    assert!(prograde[1] < 0.0);
+    assert!(prograde[1].abs() < retrograde[1].abs());
+}
+
+#[test]
+fn test_air_drag_body_fixed_surfaces() {
+    // 進行方向 (LVLH y 軸) を向く面だけが大きい機体
+    let surfaces = vec![
+        Surface { air_specularity: 0.4, specular_reflectivity: 0.1, diffuse_reflectivity: 0.3, area_m2: 2.0, normal_direction: arr1(&[0.0, 1.0, 0.0]) },
+        Surface { air_specularity: 0.4, specular_reflectivity: 0.1, diffuse_reflectivity: 0.3, area_m2: 0.2, normal_direction: arr1(&[1.0, 0.0, 0.0]) },
+    ];
+    let r = CONSTANTS.radius + 400.0e3;
+    let v = (CONSTANTS.mu / r).sqrt();
+    let state = PositionVelocityStateEci::form_from_list([0.0, r, 0.0], [-v, 0.0, 0.0]);
+    let drag_force = |attitude: Box<dyn AttitudeProvider>| {
+        let drag = AirDragStateEci::new(
+            18.0, 30.0, 3.0, 50.0, surfaces.clone(), attitude, Box::new(ExponentialAtmosphere::new()), None,
+        );
+        drag.calc_force(&state, 0.0).get_vector().clone()
+    };
+
+    // 慣性系固定では大きい面が流れに当たらない
+    let inertial = drag_force(Box::new(InertialAttitude::new()));
+    let lvlh = drag_force(Box::new(LvlhPointingAttitude::new(0.0, 0.0, 0.0)));
+    let yawed = drag_force(Box::new(LvlhPointingAttitude::new(0.0, 0.0, std::f64::consts::FRAC_PI_2)));
+    let norm = |f: &Array1<f64>| f.dot(f).sqrt();
+    assert!(norm(&inertial) < norm(&lvlh));
+    assert!(norm(&yawed) < norm(&lvlh));
+}
+
+#[test]
+fn test_air_drag_inclined_plate_shear_in_plane() {
+    // 流れに 45 度傾いた平板. 極の上空では大気の共回転による相対速度がない
+    let normal = arr1(&[1.0, 1.0, 0.0]) / 2.0_f64.sqrt();
+    let plate = vec![
+        Surface { air_specularity: 0.4, specular_reflectivity: 0.1, diffuse_reflectivity: 0.3, area_m2: 1.0, normal_direction: normal.clone() },
+    ];
+    let drag = AirDragStateEci::new(
+        18.0, 30.0, 3.0, 50.0, plate,
+        Box::new(InertialAttitude::new()),
+        Box::new(ExponentialAtmosphere::new()),
+        None,
+    );
+    let r = CONSTANTS.radius + 400.0e3;
+    let velocity = arr1(&[(CONSTANTS.mu / r).sqrt(), 0.0, 0.0]);
+    let state = PositionVelocityStateEci::form_from_list([0.0, 0.0, r], [velocity[0], 0.0, 0.0]);
+    let force = drag.calc_force(&state, 0.0).get_vector().clone();
+
+    // 力は流れと法線の張る面内にあり, 減速する向きで, 法線方向だけでなく接線方向の成分も持つ
+    let out_of_plane = Math::normalize(&Math::cross_product(&velocity, &normal));
+    let norm = force.dot(&force).sqrt();
+    assert!(norm > 0.0);
+    assert!(force.dot(&out_of_plane).abs() < 1e-12 * norm);
+    assert!(force[0] < 0.0);
+    let tangential = &force - force.dot(&normal) * &normal;
+    assert!(tangential.dot(&tangential).sqrt() > 1e-3 * norm);
+}
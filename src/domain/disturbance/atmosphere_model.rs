@@ -0,0 +1,297 @@
+use std::fmt::Debug;
+use std::f64::consts::PI;
+
+use ndarray::Array1;
+
+use crate::domain::math::formulations::Math;
+use super::space_weather::SpaceWeather;
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+/// **大気密度モデルのトレイト**
+pub trait AtmosphereModel: Debug {
+    /// 位置 (ECI, m) と時刻 (エポックからの経過秒) から大気密度 (kg/m^3) を返す
+    fn calc_air_density(&self, position_eci: &Array1<f64>, t: f64) -> f64;
+}
+
+/// **指数関数大気モデル (高度テーブル)**
+#[derive(Debug, Clone, Default)]
+pub struct ExponentialAtmosphere;
+
+impl ExponentialAtmosphere {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn calc_density_from_altitude(&self, altitude: f64) -> f64 {
+        let altitude_km = altitude / 1000.0;
+        let (scale_height_km, base_height_km, base_rho_kg_m3) = match altitude_km {
+            a if a > 1000.0 => (268.0, 1000.0, 3.019E-15),
+            a if (900.0..1000.0).contains(&a) => (181.05, 900.0, 5.245E-15),
+            a if (800.0..900.0).contains(&a) => (124.64, 800.0, 1.170E-14),
+            a if (700.0..800.0).contains(&a) => (88.667, 700.0, 3.614E-14),
+            a if (600.0..700.0).contains(&a) => (71.835, 600.0, 1.454E-13),
+            a if (500.0..600.0).contains(&a) => (63.822, 500.0, 6.967E-13),
+            a if (400.0..500.0).contains(&a) => (58.515, 400.0, 3.725E-12),
+            a if (300.0..400.0).contains(&a) => (53.298, 350.0, 9.158E-12),
+            a if (200.0..300.0).contains(&a) => (45.546, 250.0, 7.248E-11),
+            a if (100.0..200.0).contains(&a) => (37.105, 200.0, 2.789E-10),
+            a if (80.0..100.0).contains(&a) => (9.473, 120.0, 2.438E-8),
+            a if (60.0..80.0).contains(&a) => (7.714, 60.0, 3.206E-4),
+            a if (40.0..60.0).contains(&a) => (7.554, 40.0, 3.972E-3),
+            a if (20.0..40.0).contains(&a) => (6.682, 30.0, 1.774E-2),
+            a if (0.0..20.0).contains(&a) => (7.249, 0.0, 1.225),
+            _ => (7.249, 0.0, 0.0),
+        };
+
+        base_rho_kg_m3 * f64::exp(-(altitude_km - base_height_km) / scale_height_km)
+    }
+}
+
+impl AtmosphereModel for ExponentialAtmosphere {
+    fn calc_air_density(&self, position_eci: &Array1<f64>, _t: f64) -> f64 {
+        let altitude = position_eci.dot(position_eci).sqrt() - CONSTANTS.radius;
+        self.calc_density_from_altitude(altitude)
+    }
+}
+
+// Harris-Priester の密度テーブル (Montenbruck & Gill, 平均太陽活動)
+// [高度 (km), 最小密度 (g/km^3), 最大密度 (g/km^3)]
+const HARRIS_PRIESTER_TABLE: [(f64, f64, f64); 50] = [
+    (100.0, 4.974e+05, 4.974e+05),
+    (120.0, 2.490e+04, 2.490e+04),
+    (130.0, 8.377e+03, 8.710e+03),
+    (140.0, 3.899e+03, 4.059e+03),
+    (150.0, 2.122e+03, 2.215e+03),
+    (160.0, 1.263e+03, 1.344e+03),
+    (170.0, 8.008e+02, 8.758e+02),
+    (180.0, 5.283e+02, 6.010e+02),
+    (190.0, 3.617e+02, 4.297e+02),
+    (200.0, 2.557e+02, 3.162e+02),
+    (210.0, 1.839e+02, 2.396e+02),
+    (220.0, 1.341e+02, 1.853e+02),
+    (230.0, 9.949e+01, 1.455e+02),
+    (240.0, 7.488e+01, 1.157e+02),
+    (250.0, 5.709e+01, 9.308e+01),
+    (260.0, 4.403e+01, 7.555e+01),
+    (270.0, 3.430e+01, 6.182e+01),
+    (280.0, 2.697e+01, 5.095e+01),
+    (290.0, 2.139e+01, 4.226e+01),
+    (300.0, 1.708e+01, 3.526e+01),
+    (320.0, 1.099e+01, 2.511e+01),
+    (340.0, 7.214e+00, 1.819e+01),
+    (360.0, 4.824e+00, 1.337e+01),
+    (380.0, 3.274e+00, 9.955e+00),
+    (400.0, 2.249e+00, 7.492e+00),
+    (420.0, 1.558e+00, 5.684e+00),
+    (440.0, 1.091e+00, 4.355e+00),
+    (460.0, 7.701e-01, 3.362e+00),
+    (480.0, 5.474e-01, 2.612e+00),
+    (500.0, 3.916e-01, 2.042e+00),
+    (520.0, 2.819e-01, 1.605e+00),
+    (540.0, 2.042e-01, 1.267e+00),
+    (560.0, 1.488e-01, 1.005e+00),
+    (580.0, 1.092e-01, 7.997e-01),
+    (600.0, 8.070e-02, 6.390e-01),
+    (620.0, 6.012e-02, 5.123e-01),
+    (640.0, 4.519e-02, 4.121e-01),
+    (660.0, 3.430e-02, 3.325e-01),
+    (680.0, 2.632e-02, 2.691e-01),
+    (700.0, 2.043e-02, 2.185e-01),
+    (720.0, 1.607e-02, 1.779e-01),
+    (740.0, 1.281e-02, 1.452e-01),
+    (760.0, 1.036e-02, 1.190e-01),
+    (780.0, 8.496e-03, 9.776e-02),
+    (800.0, 7.069e-03, 8.059e-02),
+    (840.0, 4.680e-03, 5.741e-02),
+    (880.0, 3.200e-03, 4.210e-02),
+    (920.0, 2.210e-03, 3.130e-02),
+    (960.0, 1.560e-03, 2.360e-02),
+    (1000.0, 1.150e-03, 1.810e-02),
+];
+
+/// **Harris-Priester 大気モデル (昼側の膨らみを考慮)**
+#[derive(Debug, Clone)]
+pub struct HarrisPriesterAtmosphere {
+    epoch_jd: f64,  // t = 0 のユリウス日
+    exponent: f64,  // cos^n(psi/2) の指数 (低傾斜: 2, 極軌道: 6)
+    lag_rad: f64,   // 膨らみの太陽方向からの遅れ角
+}
+
+impl HarrisPriesterAtmosphere {
+    pub fn new(epoch_jd: f64, exponent: f64) -> Self {
+        Self {
+            epoch_jd,
+            exponent,
+            lag_rad: 30.0 * PI / 180.0,
+        }
+    }
+}
+
+impl AtmosphereModel for HarrisPriesterAtmosphere {
+    fn calc_air_density(&self, position_eci: &Array1<f64>, t: f64) -> f64 {
+        let r_norm = position_eci.dot(position_eci).sqrt();
+        let altitude_km = (r_norm - CONSTANTS.radius) / 1000.0;
+        let (h_min, _, _) = HARRIS_PRIESTER_TABLE[0];
+        let (h_max, _, _) = HARRIS_PRIESTER_TABLE[HARRIS_PRIESTER_TABLE.len() - 1];
+        if !(h_min..h_max).contains(&altitude_km) {
+            return 0.0;
+        }
+
+        // 膨らみの頂点方向
+        let sun = Math::sun_position_eci(self.epoch_jd + t / 86400.0);
+        let (ra_sun, dec_sun) = Math::right_ascension_declination(&sun);
+        let bulge = [
+            dec_sun.cos() * (ra_sun + self.lag_rad).cos(),
+            dec_sun.cos() * (ra_sun + self.lag_rad).sin(),
+            dec_sun.sin(),
+        ];
+        let cos_psi = (bulge[0] * position_eci[0] + bulge[1] * position_eci[1] + bulge[2] * position_eci[2]) / r_norm;
+        let cos_pow = (0.5 * (1.0 + cos_psi)).max(0.0).powf(0.5 * self.exponent);
+
+        // 高度で補間
+        let index = HARRIS_PRIESTER_TABLE
+            .windows(2)
+            .position(|w| (w[0].0..w[1].0).contains(&altitude_km))
+            .unwrap();
+        let (h0, rho_min0, rho_max0) = HARRIS_PRIESTER_TABLE[index];
+        let (h1, rho_min1, rho_max1) = HARRIS_PRIESTER_TABLE[index + 1];
+        let scale_min = (h0 - h1) / (rho_min1 / rho_min0).ln();
+        let scale_max = (h0 - h1) / (rho_max1 / rho_max0).ln();
+        let rho_min = rho_min0 * ((h0 - altitude_km) / scale_min).exp();
+        let rho_max = rho_max0 * ((h0 - altitude_km) / scale_max).exp();
+
+        // g/km^3 -> kg/m^3
+        (rho_min + (rho_max - rho_min) * cos_pow) * 1e-12
+    }
+}
+
+// 120 km 境界での各成分 [モル質量 (kg/mol), 数密度 (1/m^3), 熱拡散係数]
+const JACCHIA_SPECIES: [(f64, f64, f64); 5] = [
+    (28.0134e-3, 3.726e17, 0.0),   // N2
+    (15.9994e-3, 9.275e16, 0.0),   // O
+    (31.9988e-3, 4.000e16, 0.0),   // O2
+    (39.948e-3, 1.700e15, 0.0),    // Ar
+    (4.0026e-3, 3.400e13, -0.38),  // He
+];
+const JACCHIA_BOUNDARY_ALTITUDE: f64 = 120.0e3;
+const JACCHIA_BOUNDARY_TEMPERATURE: f64 = 360.0;
+const AVOGADRO_CONSTANT: f64 = 6.02214076e23;
+
+/// **Jacchia-71 型大気モデル (F10.7, Ap 駆動)**
+/// 外圏温度は Jacchia の式, 120 km 以上の温度・密度は Bates-Walker プロファイルの拡散平衡で求める
+#[derive(Debug)]
+pub struct Jacchia71Atmosphere {
+    epoch_jd: f64,  // t = 0 のユリウス日
+    space_weather: Box<dyn SpaceWeather>,
+    low_altitude: ExponentialAtmosphere,
+}
+
+impl Jacchia71Atmosphere {
+    pub fn new(epoch_jd: f64, space_weather: Box<dyn SpaceWeather>) -> Self {
+        Self {
+            epoch_jd,
+            space_weather,
+            low_altitude: ExponentialAtmosphere::new(),
+        }
+    }
+
+    /// **外圏温度 (K)**
+    pub fn calc_exospheric_temperature(&self, position_eci: &Array1<f64>, t: f64) -> f64 {
+        let deg = PI / 180.0;
+        let julian_date = self.epoch_jd + t / 86400.0;
+        let f107 = self.space_weather.f107(julian_date);
+        let f107a = self.space_weather.f107a(julian_date);
+        let ap = self.space_weather.ap(julian_date);
+
+        // 夜間最低温度
+        let t_c = 379.0 + 3.24 * f107a + 1.3 * (f107 - f107a);
+
+        // 日変化
+        let sun = Math::sun_position_eci(julian_date);
+        let (ra_sun, dec_sun) = Math::right_ascension_declination(&sun);
+        let (ra_sat, lat_sat) = Math::right_ascension_declination(position_eci);
+        let hour_angle = ra_sat - ra_sun;
+        let eta = 0.5 * (lat_sat - dec_sun).abs();
+        let theta = 0.5 * (lat_sat + dec_sun).abs();
+        let tau_raw = hour_angle - 37.0 * deg + 6.0 * deg * (hour_angle + 43.0 * deg).sin();
+        let tau = (tau_raw + PI).rem_euclid(2.0 * PI) - PI;
+        let sin_theta = theta.sin().powf(2.2);
+        let t_l = t_c * (1.0 + 0.3 * (sin_theta + (eta.cos().powf(2.2) - sin_theta) * (0.5 * tau).cos().powi(3)));
+
+        // 地磁気活動
+        let delta_t_geomagnetic = ap + 100.0 * (1.0 - (-0.08 * ap).exp());
+
+        t_l + delta_t_geomagnetic
+    }
+}
+
+impl AtmosphereModel for Jacchia71Atmosphere {
+    fn calc_air_density(&self, position_eci: &Array1<f64>, t: f64) -> f64 {
+        let r_norm = position_eci.dot(position_eci).sqrt();
+        let altitude = r_norm - CONSTANTS.radius;
+        if altitude < JACCHIA_BOUNDARY_ALTITUDE {
+            return self.low_altitude.calc_density_from_altitude(altitude);
+        }
+
+        let t_inf = self.calc_exospheric_temperature(position_eci, t);
+        let t_120 = JACCHIA_BOUNDARY_TEMPERATURE;
+
+        // Walker の温度勾配パラメータ (1/m)
+        let x = (t_inf - 800.0) / (750.0 + 1.722e-4 * (t_inf - 800.0).powi(2));
+        let s = 0.0291e-3 * (-0.5 * x * x).exp();
+
+        // 120 km からのジオポテンシャル高度
+        let r_120 = CONSTANTS.radius + JACCHIA_BOUNDARY_ALTITUDE;
+        let zeta = (altitude - JACCHIA_BOUNDARY_ALTITUDE) * r_120 / r_norm;
+        let g_120 = CONSTANTS.mu / (r_120 * r_120);
+        let temperature = t_inf - (t_inf - t_120) * (-s * zeta).exp();
+
+        JACCHIA_SPECIES.iter()
+            .map(|&(molar_mass, n_120, alpha)| {
+                let mass = molar_mass / AVOGADRO_CONSTANT;
+                let gamma = mass * g_120 / (s * CONSTANTS.boltzmann_constant * t_inf);
+                let number_density = n_120
+                    * (t_120 / temperature).powf(1.0 + alpha + gamma)
+                    * (-s * gamma * zeta).exp();
+                number_density * mass
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use super::space_weather::ConstantSpaceWeather;
+
+#[test]
+fn test_atmosphere_models_density_order() {
+    let epoch_jd = 2460676.5; // 2025-01-01 00:00 UTC
+    let position = arr1(&[CONSTANTS.radius + 400.0e3, 0.0, 0.0]);
+
+    let exponential = ExponentialAtmosphere::new().calc_air_density(&position, 0.0);
+    let harris_priester = HarrisPriesterAtmosphere::new(epoch_jd, 6.0).calc_air_density(&position, 0.0);
+    let jacchia = Jacchia71Atmosphere::new(epoch_jd, Box::new(ConstantSpaceWeather::new(150.0, 150.0, 15.0))).calc_air_density(&position, 0.0);
+
+    // 400 km ではいずれも 1e-12 ~ 1e-11 kg/m^3 のオーダー
+    for rho in [exponential, harris_priester, jacchia] {
+        assert!(rho > 1e-13 && rho < 1e-10, "unexpected density {}", rho);
+    }
+
+    // 太陽活動が高いほど密度は大きい
+    let jacchia_high = Jacchia71Atmosphere::new(epoch_jd, Box::new(ConstantSpaceWeather::new(250.0, 250.0, 15.0))).calc_air_density(&position, 0.0);
+    assert!(jacchia_high > jacchia);
+}
+
+#[test]
+fn test_harris_priester_diurnal_bulge() {
+    let epoch_jd = 2460676.5;
+    let model = HarrisPriesterAtmosphere::new(epoch_jd, 2.0);
+    let sun = Math::sun_position_eci(epoch_jd);
+    let sun_hat = &sun / sun.dot(&sun).sqrt();
+    let r = CONSTANTS.radius + 500.0e3;
+
+    let rho_day = model.calc_air_density(&(&sun_hat * r), 0.0);
+    let rho_night = model.calc_air_density(&(&sun_hat * -r), 0.0);
+    assert!(rho_day > rho_night);
+}
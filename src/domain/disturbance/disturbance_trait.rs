@@ -1,6 +1,14 @@
+use rand::rngs::StdRng;
+
 use crate::domain::force::force_trait::Force;
 use crate::domain::state::state_trait::StateVector;
 
+/// **外乱計算のトレイト**
+/// t はシミュレーション開始からの経過時間 (s)
 pub trait DisturbanceCalculator<T: StateVector, U: Force> {
-    fn calc_force(&self, state: &T) -> U;
+    fn calc_force(&self, state: &T, t: f64) -> U;
+
+    /// **確率的な外乱の内部状態を 1 ステップ進める (決定論的な外乱では何もしない)**
+    /// ステップ内では値を保持し, 伝搬の各段で同じ値を使う
+    fn update_stochastic(&mut self, _t: f64, _dt: f64, _rng: &mut StdRng) {}
 }
\ No newline at end of file
@@ -0,0 +1,554 @@
+use std::fmt::Debug;
+use std::f64::consts::PI;
+
+use ndarray::{Array1, arr1};
+
+use super::air_drag_disturbance::Surface;
+use super::disturbance_trait::DisturbanceCalculator;
+use super::relative_disturbance::ReferenceOrbit;
+use crate::domain::attitude::attitude_provider::AttitudeProvider;
+use crate::domain::force::force_3d_eci::Force3dEci;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_6d_eci::Force6dEci;
+use crate::domain::force::force_trait::Force;
+use crate::domain::math::formulations::Math;
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_converter::StateConverter;
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+/// **地表のアルベド・赤外放射率のマップ**
+pub trait EarthRadiationMap: Debug {
+    /// 緯度 (rad) とユリウス日からアルベドを返す
+    fn albedo(&self, latitude: f64, julian_date: f64) -> f64;
+    /// 緯度 (rad) とユリウス日から赤外放射率を返す
+    fn emissivity(&self, latitude: f64, julian_date: f64) -> f64;
+}
+
+/// **Knocke のアルベド・放射率モデル (緯度と季節の 2 次ルジャンドル展開)**
+#[derive(Debug, Clone, Default)]
+pub struct KnockeEarthRadiation;
+
+impl KnockeEarthRadiation {
+    const REFERENCE_JD: f64 = 2444960.5;  // 1981-12-22
+    const PERIOD_DAYS: f64 = 365.25;
+
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn seasonal_cos(julian_date: f64) -> f64 {
+        (2.0 * PI * (julian_date - Self::REFERENCE_JD) / Self::PERIOD_DAYS).cos()
+    }
+}
+
+impl EarthRadiationMap for KnockeEarthRadiation {
+    fn albedo(&self, latitude: f64, julian_date: f64) -> f64 {
+        let p1 = latitude.sin();
+        let p2 = 0.5 * (3.0 * p1 * p1 - 1.0);
+        let a1 = 0.10 * Self::seasonal_cos(julian_date);
+        0.34 + a1 * p1 + 0.29 * p2
+    }
+
+    fn emissivity(&self, latitude: f64, julian_date: f64) -> f64 {
+        let p1 = latitude.sin();
+        let p2 = 0.5 * (3.0 * p1 * p1 - 1.0);
+        let e1 = -0.07 * Self::seasonal_cos(julian_date);
+        0.68 + e1 * p1 - 0.18 * p2
+    }
+}
+
+/// **地表の緯度経度格子 (各セルの中心方向と面積)**
+#[derive(Debug, Clone)]
+pub struct EarthSurfaceGrid {
+    centers: Vec<Array1<f64>>,  // 単位ベクトル (ECI)
+    latitudes: Vec<f64>,
+    areas_m2: Vec<f64>,
+}
+
+impl EarthSurfaceGrid {
+    pub fn new(latitude_divisions: usize, longitude_divisions: usize) -> Self {
+        let radius = CONSTANTS.radius;
+        let d_lat = PI / latitude_divisions as f64;
+        let d_lon = 2.0 * PI / longitude_divisions as f64;
+
+        let mut centers = Vec::new();
+        let mut latitudes = Vec::new();
+        let mut areas_m2 = Vec::new();
+        for i in 0..latitude_divisions {
+            let lat_low = -PI / 2.0 + i as f64 * d_lat;
+            let latitude = lat_low + 0.5 * d_lat;
+            let area = radius * radius * d_lon * ((lat_low + d_lat).sin() - lat_low.sin());
+            for j in 0..longitude_divisions {
+                let longitude = (j as f64 + 0.5) * d_lon;
+                centers.push(arr1(&[
+                    latitude.cos() * longitude.cos(),
+                    latitude.cos() * longitude.sin(),
+                    latitude.sin(),
+                ]));
+                latitudes.push(latitude);
+                areas_m2.push(area);
+            }
+        }
+
+        Self { centers, latitudes, areas_m2 }
+    }
+
+    /// **組み込みの粗い格子 (10 度刻み)**
+    pub fn coarse() -> Self {
+        Self::new(18, 36)
+    }
+}
+
+pub trait EarthRadiationForInertiaState<T: StateVector> {
+    fn grid(&self) -> &EarthSurfaceGrid;
+    fn radiation_map(&self) -> &dyn EarthRadiationMap;
+    fn epoch_jd(&self) -> f64;
+
+    /// **アルベド・赤外放射による加速度 (ECI)**
+    /// 各セルを Lambert 面とみなし, 衛星から見えるセルの放射照度を面ごとに放射圧へ換算する.
+    /// 面の力は F = P A cosθ [(1 - ρs) s + 2 (ρs cosθ + ρd / 3) (-n)] (s: 光の進む向き, n: `Surface` の外向き法線)
+    fn calc_force_(
+        &self,
+        position: Array1<f64>,
+        velocity: Array1<f64>,
+        mass: f64,
+        surfaces: &[Surface],
+        attitude: &dyn AttitudeProvider,
+        t: f64,
+    ) -> Array1<f64> {
+        let julian_date = self.epoch_jd() + t / 86400.0;
+        let sun_direction = Math::normalize(&Math::sun_position_eci(julian_date));
+        let body_to_eci = attitude.body_to_eci(&position, &velocity, t);
+        let normals: Vec<Array1<f64>> = surfaces.iter()
+            .map(|surface| body_to_eci.dot(&surface.normal_direction))
+            .collect();
+        let grid = self.grid();
+
+        let mut force = arr1(&[0.0, 0.0, 0.0]);
+        for ((center, latitude), area) in grid.centers.iter().zip(&grid.latitudes).zip(&grid.areas_m2) {
+            let cell_position = CONSTANTS.radius * center;
+            let line_of_sight = &position - &cell_position;
+            let distance = line_of_sight.dot(&line_of_sight).sqrt();
+            let direction = &line_of_sight / distance;  // セル → 衛星 (光の進む向き)
+            let cos_emission = center.dot(&direction);
+            if cos_emission <= 0.0 {
+                continue;
+            }
+
+            let view_factor = cos_emission * area / (PI * distance * distance);
+            let cos_sun = center.dot(&sun_direction).max(0.0);
+            let albedo = self.radiation_map().albedo(*latitude, julian_date) * cos_sun;
+            let emissivity = self.radiation_map().emissivity(*latitude, julian_date) / 4.0;
+            let pressure = CONSTANTS.solar_flux * (albedo + emissivity) * view_factor / CONSTANTS.speed_of_light;
+
+            for (surface, normal) in surfaces.iter().zip(&normals) {
+                // 外向き法線がセルの方を向いている面だけが照らされる
+                let cos_theta = -normal.dot(&direction);
+                if cos_theta <= 0.0 {
+                    continue;
+                }
+                let k = pressure * surface.area_m2 * cos_theta;
+                force = &force + k * (1.0 - surface.specular_reflectivity) * &direction
+                    - k * 2.0 * (surface.specular_reflectivity * cos_theta + surface.diffuse_reflectivity / 3.0) * normal;
+            }
+        }
+
+        force / mass
+    }
+}
+
+#[derive(Debug)]
+pub struct EarthRadiationStateEci {
+    mass: f64,
+    surfaces: Vec<Surface>,
+    attitude: Box<dyn AttitudeProvider>,
+    epoch_jd: f64,
+    grid: EarthSurfaceGrid,
+    radiation_map: Box<dyn EarthRadiationMap>,
+}
+
+impl EarthRadiationStateEci {
+    pub fn new(
+        mass: f64,
+        surfaces: Vec<Surface>,
+        attitude: Box<dyn AttitudeProvider>,
+        epoch_jd: f64,
+        grid: EarthSurfaceGrid,
+        radiation_map: Box<dyn EarthRadiationMap>,
+    ) -> Self {
+        Self { mass, surfaces, attitude, epoch_jd, grid, radiation_map }
+    }
+}
+
+impl EarthRadiationForInertiaState<PositionVelocityStateEci> for EarthRadiationStateEci {
+    fn grid(&self) -> &EarthSurfaceGrid {
+        &self.grid
+    }
+
+    fn radiation_map(&self) -> &dyn EarthRadiationMap {
+        self.radiation_map.as_ref()
+    }
+
+    fn epoch_jd(&self) -> f64 {
+        self.epoch_jd
+    }
+}
+
+impl DisturbanceCalculator<PositionVelocityStateEci, Force3dEci> for EarthRadiationStateEci {
+    fn calc_force(&self, state_eci: &PositionVelocityStateEci, t: f64) -> Force3dEci {
+        Force3dEci::form_from_array(self.calc_force_(
+            state_eci.position(),
+            state_eci.velocity(),
+            self.mass,
+            &self.surfaces,
+            self.attitude.as_ref(),
+            t,
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct EarthRadiationStatePairEci {
+    mass_chief: f64,
+    surfaces_chief: Vec<Surface>,
+    attitude_chief: Box<dyn AttitudeProvider>,
+    mass_deputy: f64,
+    surfaces_deputy: Vec<Surface>,
+    attitude_deputy: Box<dyn AttitudeProvider>,
+    epoch_jd: f64,
+    grid: EarthSurfaceGrid,
+    radiation_map: Box<dyn EarthRadiationMap>,
+}
+
+impl EarthRadiationStatePairEci {
+    pub fn new(
+        mass_chief: f64,
+        surfaces_chief: Vec<Surface>,
+        attitude_chief: Box<dyn AttitudeProvider>,
+        mass_deputy: f64,
+        surfaces_deputy: Vec<Surface>,
+        attitude_deputy: Box<dyn AttitudeProvider>,
+        epoch_jd: f64,
+        grid: EarthSurfaceGrid,
+        radiation_map: Box<dyn EarthRadiationMap>,
+    ) -> Self {
+        Self {
+            mass_chief,
+            surfaces_chief,
+            attitude_chief,
+            mass_deputy,
+            surfaces_deputy,
+            attitude_deputy,
+            epoch_jd,
+            grid,
+            radiation_map,
+        }
+    }
+}
+
+impl EarthRadiationForInertiaState<PositionVelocityPairStateEci> for EarthRadiationStatePairEci {
+    fn grid(&self) -> &EarthSurfaceGrid {
+        &self.grid
+    }
+
+    fn radiation_map(&self) -> &dyn EarthRadiationMap {
+        self.radiation_map.as_ref()
+    }
+
+    fn epoch_jd(&self) -> f64 {
+        self.epoch_jd
+    }
+}
+
+impl DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci> for EarthRadiationStatePairEci {
+    fn calc_force(&self, state_eci: &PositionVelocityPairStateEci, t: f64) -> Force6dEci {
+        let state_vec: Vec<PositionVelocityStateEci> = state_eci.convert();
+        let state_chief = &state_vec[0];
+        let state_deputy = &state_vec[1];
+
+        let force_chief = self.calc_force_(
+            state_chief.position(),
+            state_chief.velocity(),
+            self.mass_chief,
+            &self.surfaces_chief,
+            self.attitude_chief.as_ref(),
+            t,
+        );
+        let force_deputy = self.calc_force_(
+            state_deputy.position(),
+            state_deputy.velocity(),
+            self.mass_deputy,
+            &self.surfaces_deputy,
+            self.attitude_deputy.as_ref(),
+            t,
+        );
+
+        Force6dEci::form_from_list([
+            force_chief[0], force_chief[1], force_chief[2],
+            force_deputy[0], force_deputy[1], force_deputy[2],
+        ])
+    }
+}
+
+/// **アルベド・赤外放射による差分加速度 (LVLH)**
+/// 両機とも基準軌道上にあるとみなし, 面の構成・質量・姿勢の違いによる加速度の差を返す
+#[derive(Debug)]
+pub struct DifferentialEarthRadiationLvlh {
+    mass_chief: f64,
+    surfaces_chief: Vec<Surface>,
+    attitude_chief: Box<dyn AttitudeProvider>,
+    mass_deputy: f64,
+    surfaces_deputy: Vec<Surface>,
+    attitude_deputy: Box<dyn AttitudeProvider>,
+    reference: ReferenceOrbit,
+    epoch_jd: f64,
+    grid: EarthSurfaceGrid,
+    radiation_map: Box<dyn EarthRadiationMap>,
+}
+
+impl DifferentialEarthRadiationLvlh {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mass_chief: f64,
+        surfaces_chief: Vec<Surface>,
+        attitude_chief: Box<dyn AttitudeProvider>,
+        mass_deputy: f64,
+        surfaces_deputy: Vec<Surface>,
+        attitude_deputy: Box<dyn AttitudeProvider>,
+        reference: ReferenceOrbit,
+        epoch_jd: f64,
+        grid: EarthSurfaceGrid,
+        radiation_map: Box<dyn EarthRadiationMap>,
+    ) -> Self {
+        Self {
+            mass_chief,
+            surfaces_chief,
+            attitude_chief,
+            mass_deputy,
+            surfaces_deputy,
+            attitude_deputy,
+            reference,
+            epoch_jd,
+            grid,
+            radiation_map,
+        }
+    }
+}
+
+impl EarthRadiationForInertiaState<PositionVelocityStateLvlh> for DifferentialEarthRadiationLvlh {
+    fn grid(&self) -> &EarthSurfaceGrid {
+        &self.grid
+    }
+
+    fn radiation_map(&self) -> &dyn EarthRadiationMap {
+        self.radiation_map.as_ref()
+    }
+
+    fn epoch_jd(&self) -> f64 {
+        self.epoch_jd
+    }
+}
+
+impl DisturbanceCalculator<PositionVelocityStateLvlh, Force3dLvlh> for DifferentialEarthRadiationLvlh {
+    fn calc_force(&self, _state: &PositionVelocityStateLvlh, t: f64) -> Force3dLvlh {
+        let position = self.reference.position_eci(t);
+        let velocity = self.reference.velocity_eci(t);
+
+        let force_chief = self.calc_force_(
+            position.clone(),
+            velocity.clone(),
+            self.mass_chief,
+            &self.surfaces_chief,
+            self.attitude_chief.as_ref(),
+            t,
+        );
+        let force_deputy = self.calc_force_(
+            position,
+            velocity,
+            self.mass_deputy,
+            &self.surfaces_deputy,
+            self.attitude_deputy.as_ref(),
+            t,
+        );
+
+        Force3dLvlh::form_from_array(self.reference.mat_eci2lvlh(t).dot(&(force_deputy - force_chief)))
+    }
+}
+
+#[cfg(test)]
+use crate::domain::attitude::attitude_provider::InertialAttitude;
+#[cfg(test)]
+use super::air_drag_disturbance::AirDragStateEci;
+#[cfg(test)]
+use super::atmosphere_model::ExponentialAtmosphere;
+
+/// **アルベドが 0 で赤外放射率が一様なマップ**
+#[cfg(test)]
+#[derive(Debug)]
+struct UniformEmissivity(f64);
+
+#[cfg(test)]
+impl EarthRadiationMap for UniformEmissivity {
+    fn albedo(&self, _latitude: f64, _julian_date: f64) -> f64 {
+        0.0
+    }
+
+    fn emissivity(&self, _latitude: f64, _julian_date: f64) -> f64 {
+        self.0
+    }
+}
+
+#[test]
+fn test_knocke_albedo_range() {
+    let map = KnockeEarthRadiation::new();
+    for k in 0..=18 {
+        let latitude = -PI / 2.0 + k as f64 * PI / 18.0;
+        let albedo = map.albedo(latitude, 2460676.5);
+        let emissivity = map.emissivity(latitude, 2460676.5);
+        assert!((0.0..1.0).contains(&albedo));
+        assert!((0.0..1.0).contains(&emissivity));
+    }
+    // 極域の方が赤道より明るい
+    assert!(map.albedo(PI / 2.0 * 0.9, 2460676.5) > map.albedo(0.0, 2460676.5));
+}
+
+#[test]
+fn test_earth_radiation_dayside_and_nightside() {
+    let epoch_jd = 2460676.5;
+    let surfaces: Vec<Surface> = [
+        [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0],
+        [-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0],
+    ]
+    .iter()
+    .map(|n| Surface {
+        normal_direction: arr1(n),
+        area_m2: 1.0,
+        air_specularity: 0.4,
+        specular_reflectivity: 0.1,
+        diffuse_reflectivity: 0.3,
+    })
+    .collect();
+    let radiation = EarthRadiationStateEci::new(
+        50.0, surfaces, Box::new(InertialAttitude::new()), epoch_jd,
+        EarthSurfaceGrid::coarse(), Box::new(KnockeEarthRadiation::new()),
+    );
+
+    let r = CONSTANTS.radius + 500.0e3;
+    let sun_direction = Math::normalize(&Math::sun_position_eci(epoch_jd));
+    let radial_acceleration = |direction: &Array1<f64>| {
+        let position = r * direction;
+        let state = PositionVelocityStateEci::form_from_array(ndarray::concatenate![
+            ndarray::Axis(0), position, arr1(&[0.0, 0.0, 0.0])
+        ]);
+        radiation.calc_force(&state, 0.0).get_vector().dot(direction)
+    };
+
+    // 昼側はアルベド + 赤外, 夜側は赤外のみ. どちらも地球から離れる向き
+    let dayside = radial_acceleration(&sun_direction);
+    let nightside = radial_acceleration(&(-&sun_direction));
+    assert!(nightside > 0.0);
+    assert!(dayside > nightside);
+    assert!(dayside < 1e-6);
+}
+
+#[test]
+fn test_earth_radiation_flat_plate_matches_analytic() {
+    // 地球の方を向いた平板. 一様な放射輝度 L の球冠 (半頂角 α, sin α = R / r) から受ける力は
+    // F = L A / c [(1 + ρs) I2 + 2/3 ρd I1] (I1 = π sin²α, I2 = 2π/3 (1 - cos³α)) で天頂向き
+    let (emissivity, specular, diffuse, area, mass) = (0.6, 0.2, 0.5, 2.0, 10.0);
+    let plate = Surface {
+        normal_direction: arr1(&[-1.0, 0.0, 0.0]),
+        area_m2: area,
+        air_specularity: 0.0,
+        specular_reflectivity: specular,
+        diffuse_reflectivity: diffuse,
+    };
+    let radiation = EarthRadiationStateEci::new(
+        mass, vec![plate], Box::new(InertialAttitude::new()), 2460676.5,
+        EarthSurfaceGrid::new(180, 360), Box::new(UniformEmissivity(emissivity)),
+    );
+
+    let r = CONSTANTS.radius + 500.0e3;
+    let state = PositionVelocityStateEci::form_from_list([r, 0.0, 0.0], [0.0, 7.6e3, 0.0]);
+    let acceleration = radiation.calc_force(&state, 0.0).get_vector().clone();
+
+    let sin_alpha = CONSTANTS.radius / r;
+    let cos_alpha = (1.0 - sin_alpha * sin_alpha).sqrt();
+    let i1 = PI * sin_alpha * sin_alpha;
+    let i2 = 2.0 * PI / 3.0 * (1.0 - cos_alpha.powi(3));
+    let radiance = CONSTANTS.solar_flux * emissivity / 4.0 / PI;
+    let expected = radiance * area / CONSTANTS.speed_of_light
+        * ((1.0 + specular) * i2 + 2.0 / 3.0 * diffuse * i1) / mass;
+
+    assert!((acceleration[0] - expected).abs() < 1e-2 * expected, "{} vs {}", acceleration[0], expected);
+    assert!(acceleration[1].abs() < 1e-3 * expected && acceleration[2].abs() < 1e-3 * expected);
+
+    // 地球と反対を向いた面は照らされない
+    let back = Surface { normal_direction: arr1(&[1.0, 0.0, 0.0]), ..radiation.surfaces[0].clone() };
+    let radiation = EarthRadiationStateEci::new(
+        mass, vec![back], Box::new(InertialAttitude::new()), 2460676.5,
+        EarthSurfaceGrid::new(180, 360), Box::new(UniformEmissivity(emissivity)),
+    );
+    assert!(radiation.calc_force(&state, 0.0).get_vector().iter().all(|a| *a == 0.0));
+}
+
+#[test]
+fn test_differential_earth_radiation_lvlh() {
+    let plate = |area_m2: f64| vec![Surface {
+        normal_direction: arr1(&[-1.0, 0.0, 0.0]),
+        area_m2,
+        air_specularity: 0.0,
+        specular_reflectivity: 0.1,
+        diffuse_reflectivity: 0.3,
+    }];
+    let differential = |area_deputy: f64| DifferentialEarthRadiationLvlh::new(
+        10.0, plate(1.0), Box::new(InertialAttitude::new()),
+        10.0, plate(area_deputy), Box::new(InertialAttitude::new()),
+        ReferenceOrbit::new(CONSTANTS.radius + 500.0e3, 0.0, 0.0, 0.0), 2460676.5,
+        EarthSurfaceGrid::coarse(), Box::new(UniformEmissivity(0.6)),
+    );
+    let state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+
+    // 同じ構成なら差は 0. 副機の地球を向いた面 (t = 0 で天底向き) が大きいと天頂向きの差が出る
+    assert!(differential(1.0).calc_force(&state, 0.0).get_vector().iter().all(|a| a.abs() < 1e-20));
+    let acceleration = differential(2.0).calc_force(&state, 0.0).get_vector().clone();
+    assert!(acceleration[0] > 0.0);
+}
+
+#[test]
+fn test_surface_normal_convention_asymmetric_body() {
+    // 前後・天頂天底で面積と反射率が異なる機体. 空気抵抗と放射圧で同じ外向き法線を使う
+    let surface = |n: [f64; 3], area_m2: f64, specular_reflectivity: f64, diffuse_reflectivity: f64| Surface {
+        normal_direction: arr1(&n), area_m2, air_specularity: 0.4, specular_reflectivity, diffuse_reflectivity,
+    };
+    let body = |front: f64, back: f64, nadir: f64, zenith: f64, zenith_specular: f64| vec![
+        surface([0.0, 1.0, 0.0], front, 0.1, 0.3),
+        surface([0.0, -1.0, 0.0], back, 0.1, 0.3),
+        surface([-1.0, 0.0, 0.0], nadir, 0.2, 0.5),
+        surface([1.0, 0.0, 0.0], zenith, zenith_specular, 0.1),
+    ];
+    let r = CONSTANTS.radius + 400.0e3;
+    let state = PositionVelocityStateEci::form_from_list([r, 0.0, 0.0], [0.0, (CONSTANTS.mu / r).sqrt(), 0.0]);
+    let drag = |surfaces: Vec<Surface>| AirDragStateEci::new(
+        18.0, 30.0, 3.0, 50.0, surfaces, Box::new(InertialAttitude::new()), Box::new(ExponentialAtmosphere::new()), None,
+    ).calc_force(&state, 0.0).get_vector().clone();
+    let radiation = |surfaces: Vec<Surface>| EarthRadiationStateEci::new(
+        50.0, surfaces, Box::new(InertialAttitude::new()), 2460676.5,
+        EarthSurfaceGrid::coarse(), Box::new(UniformEmissivity(0.6)),
+    ).calc_force(&state, 0.0).get_vector().clone();
+
+    // 抗力は進行方向 (+y) を向いた面で決まり, 後ろの面は流れを受けない
+    let front_heavy = drag(body(2.0, 0.5, 1.5, 0.3, 0.8));
+    assert!(front_heavy[1] < 0.0);
+    assert!(front_heavy[1].abs() > drag(body(0.5, 2.0, 1.5, 0.3, 0.8))[1].abs());
+    assert_eq!(front_heavy, drag(body(2.0, 1.0, 1.5, 0.3, 0.8)));
+
+    // 放射圧は地球 (-x) を向いた面で決まり, 天頂を向いた面は照らされない
+    let nadir_heavy = radiation(body(2.0, 0.5, 1.5, 0.3, 0.8));
+    assert!(nadir_heavy[0] > 0.0);
+    assert!(nadir_heavy[0] > radiation(body(2.0, 0.5, 0.3, 1.5, 0.8))[0]);
+    assert_eq!(nadir_heavy, radiation(body(2.0, 0.5, 1.5, 1.0, 0.0)));
+}
@@ -44,7 +44,7 @@ impl J2StateEci {
 impl J2ForInertiaState<PositionVelocityStateEci> for J2StateEci {}
 
 impl DisturbanceCalculator<PositionVelocityStateEci, Force3dEci> for J2StateEci {
-    fn calc_force(&self, state_eci: &PositionVelocityStateEci) -> Force3dEci {
+    fn calc_force(&self, state_eci: &PositionVelocityStateEci, _t: f64) -> Force3dEci {
         let force_lvlh = Force3dLvlh::form_from_array(self.calc_force_(
             state_eci.position(),
             state_eci,
@@ -65,7 +65,7 @@ impl J2StatePairEci {
 impl J2ForInertiaState<PositionVelocityPairStateEci> for J2StatePairEci {}
 
 impl DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci> for J2StatePairEci {
-    fn calc_force(&self, state_eci: &PositionVelocityPairStateEci) -> Force6dEci {
+    fn calc_force(&self, state_eci: &PositionVelocityPairStateEci, _t: f64) -> Force6dEci {
         let state_vec: Vec<PositionVelocityStateEci> = state_eci.convert();
         let state_chief = &state_vec[0];
         let state_deputy = &state_vec[1];
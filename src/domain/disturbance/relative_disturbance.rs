@@ -0,0 +1,174 @@
+use ndarray::{Array1, Array2, arr1};
+
+use super::disturbance_trait::DisturbanceCalculator;
+use super::atmosphere_model::AtmosphereModel;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::math::formulations::Math;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+/// **相対運動の基準となる円軌道**
+/// 引数緯度 u(t) = u0 + n t で回る
+#[derive(Debug, Clone)]
+pub struct ReferenceOrbit {
+    a: f64,
+    n: f64,
+    i_rad: f64,
+    raan_rad: f64,
+    u0_rad: f64,
+}
+
+impl ReferenceOrbit {
+    pub fn new(a: f64, i_rad: f64, raan_rad: f64, u0_rad: f64) -> Self {
+        Self {
+            a,
+            n: (CONSTANTS.mu / a.powi(3)).sqrt(),
+            i_rad,
+            raan_rad,
+            u0_rad,
+        }
+    }
+
+    fn orbit_to_eci(&self, t: f64) -> Array2<f64> {
+        Math::pqw_to_eci_matrix(self.i_rad, self.u0_rad + self.n * t, self.raan_rad)
+    }
+
+    pub fn position_eci(&self, t: f64) -> Array1<f64> {
+        self.orbit_to_eci(t).dot(&arr1(&[self.a, 0.0, 0.0]))
+    }
+
+    pub fn velocity_eci(&self, t: f64) -> Array1<f64> {
+        self.orbit_to_eci(t).dot(&arr1(&[0.0, self.a * self.n, 0.0]))
+    }
+
+    pub fn mat_eci2lvlh(&self, t: f64) -> Array2<f64> {
+        Math::mat_eci2lvlh(&self.position_eci(t), &self.velocity_eci(t))
+    }
+}
+
+/// **J2 による加速度 (ECI)**
+pub fn j2_acceleration_eci(position: &Array1<f64>) -> Array1<f64> {
+    let r2 = position.dot(position);
+    let r = r2.sqrt();
+    let z2_r2 = position[2].powi(2) / r2;
+    let factor = -1.5 * CONSTANTS.j2 * CONSTANTS.mu * CONSTANTS.radius.powi(2) / r.powi(5);
+
+    arr1(&[
+        factor * position[0] * (1.0 - 5.0 * z2_r2),
+        factor * position[1] * (1.0 - 5.0 * z2_r2),
+        factor * position[2] * (3.0 - 5.0 * z2_r2),
+    ])
+}
+
+/// **基準軌道まわりで線形化した J2 の差分加速度 (LVLH)**
+/// δa = C ∇a_J2(r_ref) C^T δr (C: ECI → LVLH)
+#[derive(Debug, Clone)]
+pub struct DifferentialJ2Lvlh {
+    reference: ReferenceOrbit,
+}
+
+impl DifferentialJ2Lvlh {
+    pub fn new(reference: ReferenceOrbit) -> Self {
+        Self { reference }
+    }
+
+    /// **基準位置での J2 加速度の勾配 (LVLH, 中心差分)**
+    pub fn calc_gradient(&self, t: f64) -> Array2<f64> {
+        let position = self.reference.position_eci(t);
+        let eci2lvlh = self.reference.mat_eci2lvlh(t);
+        let h = 1.0;
+
+        let mut gradient_eci = Array2::<f64>::zeros((3, 3));
+        for j in 0..3 {
+            let mut offset = Array1::<f64>::zeros(3);
+            offset[j] = h;
+            let da = (j2_acceleration_eci(&(&position + &offset)) - j2_acceleration_eci(&(&position - &offset))) / (2.0 * h);
+            gradient_eci.column_mut(j).assign(&da);
+        }
+
+        eci2lvlh.dot(&gradient_eci).dot(&eci2lvlh.t())
+    }
+}
+
+impl DisturbanceCalculator<PositionVelocityStateLvlh, Force3dLvlh> for DifferentialJ2Lvlh {
+    fn calc_force(&self, state: &PositionVelocityStateLvlh, t: f64) -> Force3dLvlh {
+        Force3dLvlh::form_from_array(self.calc_gradient(t).dot(&state.position()))
+    }
+}
+
+/// **弾道係数の差による差分空気抵抗 (LVLH)**
+/// 弾道係数 B = m / (C_D A) (kg/m^2). 密度・相対風速は基準軌道上で評価する
+#[derive(Debug)]
+pub struct DifferentialAirDragLvlh {
+    ballistic_coefficient_chief: f64,
+    ballistic_coefficient_deputy: f64,
+    reference: ReferenceOrbit,
+    atmosphere: Box<dyn AtmosphereModel>,
+}
+
+impl DifferentialAirDragLvlh {
+    pub fn new(
+        ballistic_coefficient_chief: f64,
+        ballistic_coefficient_deputy: f64,
+        reference: ReferenceOrbit,
+        atmosphere: Box<dyn AtmosphereModel>,
+    ) -> Self {
+        Self {
+            ballistic_coefficient_chief,
+            ballistic_coefficient_deputy,
+            reference,
+            atmosphere,
+        }
+    }
+}
+
+impl DisturbanceCalculator<PositionVelocityStateLvlh, Force3dLvlh> for DifferentialAirDragLvlh {
+    fn calc_force(&self, _state: &PositionVelocityStateLvlh, t: f64) -> Force3dLvlh {
+        let position = self.reference.position_eci(t);
+        let earth_rotation = arr1(&[0.0, 0.0, CONSTANTS.earth_rotation_rate]);
+        let relative_velocity = self.reference.velocity_eci(t) - Math::cross_product(&earth_rotation, &position);
+        let relative_velocity_lvlh = self.reference.mat_eci2lvlh(t).dot(&relative_velocity);
+
+        let air_density = self.atmosphere.calc_air_density(&position, t);
+        let speed = relative_velocity.dot(&relative_velocity).sqrt();
+        let k = -0.5 * air_density * speed
+            * (1.0 / self.ballistic_coefficient_deputy - 1.0 / self.ballistic_coefficient_chief);
+        Force3dLvlh::form_from_array(k * relative_velocity_lvlh)
+    }
+}
+
+#[cfg(test)]
+use super::atmosphere_model::ExponentialAtmosphere;
+
+#[test]
+fn test_differential_j2_matches_direct_difference() {
+    let reference = ReferenceOrbit::new(CONSTANTS.radius + 500.0e3, 0.9, 0.3, 0.7);
+    let j2 = DifferentialJ2Lvlh::new(reference.clone());
+    let t = 600.0;
+    let relative = PositionVelocityStateLvlh::form_from_list([100.0, -200.0, 50.0], [0.0, 0.0, 0.0]);
+
+    let linearized = j2.calc_force(&relative, t).get_vector().clone();
+
+    let eci2lvlh = reference.mat_eci2lvlh(t);
+    let position = reference.position_eci(t);
+    let deputy = &position + &eci2lvlh.t().dot(&arr1(&[100.0, -200.0, 50.0]));
+    let direct = eci2lvlh.dot(&(j2_acceleration_eci(&deputy) - j2_acceleration_eci(&position)));
+
+    let error = &linearized - &direct;
+    assert!(error.dot(&error).sqrt() < 1e-3 * direct.dot(&direct).sqrt());
+}
+
+#[test]
+fn test_differential_air_drag_direction() {
+    let reference = ReferenceOrbit::new(CONSTANTS.radius + 400.0e3, 0.9, 0.0, 0.0);
+    let state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+
+    // deputy の方が抵抗を受けやすいと進行方向に遅れる
+    let drag = DifferentialAirDragLvlh::new(20.0, 10.0, reference.clone(), Box::new(ExponentialAtmosphere::new()));
+    assert!(drag.calc_force(&state, 0.0).get_vector()[1] < 0.0);
+
+    // 弾道係数が同じなら差分は 0
+    let drag = DifferentialAirDragLvlh::new(10.0, 10.0, reference, Box::new(ExponentialAtmosphere::new()));
+    assert!(drag.calc_force(&state, 0.0).get_vector().iter().all(|f| f.abs() < 1e-20));
+}
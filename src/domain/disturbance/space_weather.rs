@@ -0,0 +1,118 @@
+use std::fmt::Debug;
+
+/// **宇宙天気 (太陽フラックス・地磁気指数) の入力トレイト**
+pub trait SpaceWeather: Debug {
+    /// 前日の F10.7 (sfu)
+    fn f107(&self, julian_date: f64) -> f64;
+    /// F10.7 の 81 日中心平均 (sfu)
+    fn f107a(&self, julian_date: f64) -> f64;
+    /// 日平均の地磁気指数 Ap
+    fn ap(&self, julian_date: f64) -> f64;
+}
+
+/// **一定値の宇宙天気**
+#[derive(Debug, Clone)]
+pub struct ConstantSpaceWeather {
+    f107: f64,
+    f107a: f64,
+    ap: f64,
+}
+
+impl ConstantSpaceWeather {
+    pub fn new(f107: f64, f107a: f64, ap: f64) -> Self {
+        Self { f107, f107a, ap }
+    }
+}
+
+impl SpaceWeather for ConstantSpaceWeather {
+    fn f107(&self, _julian_date: f64) -> f64 {
+        self.f107
+    }
+
+    fn f107a(&self, _julian_date: f64) -> f64 {
+        self.f107a
+    }
+
+    fn ap(&self, _julian_date: f64) -> f64 {
+        self.ap
+    }
+}
+
+/// **1 日分の宇宙天気データ**
+#[derive(Debug, Clone)]
+pub struct SpaceWeatherRecord {
+    pub julian_date: f64,  // その日の 0h UTC のユリウス日
+    pub f107: f64,
+    pub f107a: f64,
+    pub ap: f64,
+}
+
+/// **日毎の宇宙天気テーブル (時刻で線形補間)**
+#[derive(Debug, Clone)]
+pub struct SpaceWeatherTable {
+    records: Vec<SpaceWeatherRecord>,
+}
+
+impl SpaceWeatherTable {
+    pub fn new(mut records: Vec<SpaceWeatherRecord>) -> Result<Self, &'static str> {
+        if records.is_empty() {
+            return Err("宇宙天気データが空です。");
+        }
+        records.sort_by(|a, b| a.julian_date.total_cmp(&b.julian_date));
+        Ok(Self { records })
+    }
+
+    pub fn records(&self) -> &Vec<SpaceWeatherRecord> {
+        &self.records
+    }
+
+    /// テーブル範囲外は端の値で保持する
+    fn interpolate(&self, julian_date: f64, value: fn(&SpaceWeatherRecord) -> f64) -> f64 {
+        let first = &self.records[0];
+        let last = &self.records[self.records.len() - 1];
+        if julian_date <= first.julian_date {
+            return value(first);
+        }
+        if julian_date >= last.julian_date {
+            return value(last);
+        }
+
+        let index = self.records.partition_point(|r| r.julian_date <= julian_date) - 1;
+        let r0 = &self.records[index];
+        let r1 = &self.records[index + 1];
+        let ratio = (julian_date - r0.julian_date) / (r1.julian_date - r0.julian_date);
+        value(r0) + (value(r1) - value(r0)) * ratio
+    }
+}
+
+impl SpaceWeather for SpaceWeatherTable {
+    fn f107(&self, julian_date: f64) -> f64 {
+        self.interpolate(julian_date - 1.0, |r| r.f107)
+    }
+
+    fn f107a(&self, julian_date: f64) -> f64 {
+        self.interpolate(julian_date, |r| r.f107a)
+    }
+
+    fn ap(&self, julian_date: f64) -> f64 {
+        self.interpolate(julian_date, |r| r.ap)
+    }
+}
+
+#[test]
+fn test_space_weather_table_interpolation() {
+    let table = SpaceWeatherTable::new(vec![
+        SpaceWeatherRecord { julian_date: 2460677.5, f107: 200.0, f107a: 160.0, ap: 20.0 },
+        SpaceWeatherRecord { julian_date: 2460676.5, f107: 100.0, f107a: 150.0, ap: 10.0 },
+    ])
+    .unwrap();
+
+    // 日の中間では線形補間
+    assert!((table.ap(2460677.0) - 15.0).abs() < 1e-9);
+    assert!((table.f107a(2460677.0) - 155.0).abs() < 1e-9);
+    // F10.7 は前日の値
+    assert!((table.f107(2460678.0) - 150.0).abs() < 1e-9);
+    // 範囲外は端の値
+    assert!((table.ap(2460600.0) - 10.0).abs() < 1e-9);
+    assert!((table.ap(2460700.0) - 20.0).abs() < 1e-9);
+}
@@ -0,0 +1,153 @@
+use std::marker::PhantomData;
+
+use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, StandardNormal};
+
+use super::disturbance_trait::DisturbanceCalculator;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::state_trait::StateVector;
+
+/// **標準正規乱数のベクトル**
+fn sample_standard_normal(dim: usize, rng: &mut StdRng) -> Array1<f64> {
+    Array1::from_iter((0..dim).map(|_| StandardNormal.sample(rng)))
+}
+
+/// **白色雑音加速度**
+/// スペクトル密度 q (m^2/s^3) の白色雑音を 1 ステップ保持した値 (標準偏差 sqrt(q / dt))
+#[derive(Debug, Clone)]
+pub struct WhiteNoiseAcceleration<U: Force> {
+    spectral_density: Array1<f64>,
+    current: Array1<f64>,
+    _marker: PhantomData<U>,
+}
+
+impl<U: Force> WhiteNoiseAcceleration<U> {
+    pub fn new(spectral_density: Array1<f64>) -> Self {
+        let current = Array1::zeros(spectral_density.len());
+        Self { spectral_density, current, _marker: PhantomData }
+    }
+}
+
+impl<T: StateVector, U: Force> DisturbanceCalculator<T, U> for WhiteNoiseAcceleration<U> {
+    fn calc_force(&self, _state: &T, _t: f64) -> U {
+        U::form_from_array(self.current.clone())
+    }
+
+    fn update_stochastic(&mut self, _t: f64, dt: f64, rng: &mut StdRng) {
+        let sigma = (&self.spectral_density / dt).mapv(f64::sqrt);
+        self.current = sigma * sample_standard_normal(self.current.len(), rng);
+    }
+}
+
+/// **一次ガウス・マルコフ過程の加速度**
+/// 定常標準偏差 sigma (m/s^2), 相関時間 tau (s). 初期値は 0
+/// tau が正でなければエラーを返す
+#[derive(Debug, Clone)]
+pub struct GaussMarkovAcceleration<U: Force> {
+    sigma: Array1<f64>,
+    tau: f64,
+    current: Array1<f64>,
+    _marker: PhantomData<U>,
+}
+
+impl<U: Force> GaussMarkovAcceleration<U> {
+    pub fn new(sigma: Array1<f64>, tau: f64) -> Result<Self, &'static str> {
+        if tau <= 0.0 || !tau.is_finite() {
+            return Err("相関時間 tau は正である必要があります。");
+        }
+        let current = Array1::zeros(sigma.len());
+        Ok(Self { sigma, tau, current, _marker: PhantomData })
+    }
+}
+
+impl<T: StateVector, U: Force> DisturbanceCalculator<T, U> for GaussMarkovAcceleration<U> {
+    fn calc_force(&self, _state: &T, _t: f64) -> U {
+        U::form_from_array(self.current.clone())
+    }
+
+    fn update_stochastic(&mut self, _t: f64, dt: f64, rng: &mut StdRng) {
+        let phi = (-dt / self.tau).exp();
+        let noise = &self.sigma * (1.0 - phi * phi).sqrt() * sample_standard_normal(self.current.len(), rng);
+        self.current = phi * &self.current + noise;
+    }
+}
+
+/// **ランダムウォークのバイアス加速度**
+/// sigma (m/s^2/sqrt(s)) で拡散する. 初期値は 0
+#[derive(Debug, Clone)]
+pub struct RandomWalkAcceleration<U: Force> {
+    sigma: Array1<f64>,
+    current: Array1<f64>,
+    _marker: PhantomData<U>,
+}
+
+impl<U: Force> RandomWalkAcceleration<U> {
+    pub fn new(sigma: Array1<f64>) -> Self {
+        let current = Array1::zeros(sigma.len());
+        Self { sigma, current, _marker: PhantomData }
+    }
+}
+
+impl<T: StateVector, U: Force> DisturbanceCalculator<T, U> for RandomWalkAcceleration<U> {
+    fn calc_force(&self, _state: &T, _t: f64) -> U {
+        U::form_from_array(self.current.clone())
+    }
+
+    fn update_stochastic(&mut self, _t: f64, dt: f64, rng: &mut StdRng) {
+        let noise = &self.sigma * dt.sqrt() * sample_standard_normal(self.current.len(), rng);
+        self.current = &self.current + noise;
+    }
+}
+
+#[cfg(test)]
+use rand::SeedableRng;
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+
+#[test]
+fn test_stochastic_disturbance_reproducible() {
+    let state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let run = |seed: u64| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut disturbance = WhiteNoiseAcceleration::<Force3dLvlh>::new(arr1(&[1e-6, 1e-6, 1e-6]));
+        (0..10)
+            .map(|k| {
+                DisturbanceCalculator::<PositionVelocityStateLvlh, Force3dLvlh>::update_stochastic(&mut disturbance, k as f64, 1.0, &mut rng);
+                disturbance.calc_force(&state, k as f64).get_vector().clone()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(run(1), run(1));
+    assert_ne!(run(1), run(2));
+}
+
+#[test]
+fn test_gauss_markov_steady_state_variance() {
+    let state = PositionVelocityStateLvlh::form_from_list([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut disturbance = GaussMarkovAcceleration::<Force3dLvlh>::new(arr1(&[2.0, 2.0, 2.0]), 10.0).unwrap();
+
+    let n = 20000;
+    let mut sum_sq = 0.0;
+    for k in 0..n {
+        DisturbanceCalculator::<PositionVelocityStateLvlh, Force3dLvlh>::update_stochastic(&mut disturbance, k as f64, 1.0, &mut rng);
+        let f = disturbance.calc_force(&state, k as f64);
+        sum_sq += f.get_vector()[0].powi(2);
+    }
+
+    // 定常分散 sigma^2 = 4
+    assert!((sum_sq / n as f64 - 4.0).abs() < 0.5);
+}
+
+#[test]
+fn test_gauss_markov_rejects_non_positive_tau() {
+    assert!(GaussMarkovAcceleration::<Force3dLvlh>::new(arr1(&[2.0, 2.0, 2.0]), 0.0).is_err());
+    assert!(GaussMarkovAcceleration::<Force3dLvlh>::new(arr1(&[2.0, 2.0, 2.0]), -1.0).is_err());
+    assert!(GaussMarkovAcceleration::<Force3dLvlh>::new(arr1(&[2.0, 2.0, 2.0]), f64::NAN).is_err());
+}
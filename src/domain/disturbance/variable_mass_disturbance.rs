@@ -0,0 +1,102 @@
+use rand::rngs::StdRng;
+
+use super::disturbance_trait::DisturbanceCalculator;
+use crate::domain::force::force_6d_eci::Force6dEci;
+use crate::domain::force::thrust_acceleration_pair_eci::ThrustAccelerationPairEci;
+use crate::domain::state::position_velocity_mass_pair_state_eci::PositionVelocityMassPairStateEci;
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::state_converter::StateConverter;
+
+/// **質量一定の 2 衛星用の外乱を推進剤質量付きの状態で使うアダプタ**
+/// 空気抵抗や放射圧のように加速度が質量に反比例する外乱は, 基準質量 (外乱生成時の質量) と
+/// 現在の質量 (乾燥質量 + 推進剤質量) の比で加速度を補正する
+pub struct VariableMassPairDisturbance {
+    disturbance: Box<dyn DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci>>,
+    mass_scaling: Option<MassScaling>,
+}
+
+/// **質量補正の基準値 (kg)**
+struct MassScaling {
+    reference_mass_chief: f64,
+    reference_mass_deputy: f64,
+    dry_mass_chief: f64,
+    dry_mass_deputy: f64,
+}
+
+impl VariableMassPairDisturbance {
+    /// **質量によらない外乱 (J2 など)**
+    pub fn new(disturbance: Box<dyn DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci>>) -> Self {
+        Self { disturbance, mass_scaling: None }
+    }
+
+    /// **加速度が質量に反比例する外乱 (空気抵抗, 放射圧など)**
+    pub fn inversely_proportional_to_mass(
+        disturbance: Box<dyn DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci>>,
+        reference_mass_chief: f64,
+        reference_mass_deputy: f64,
+        dry_mass_chief: f64,
+        dry_mass_deputy: f64,
+    ) -> Self {
+        Self {
+            disturbance,
+            mass_scaling: Some(MassScaling {
+                reference_mass_chief,
+                reference_mass_deputy,
+                dry_mass_chief,
+                dry_mass_deputy,
+            }),
+        }
+    }
+}
+
+impl DisturbanceCalculator<PositionVelocityMassPairStateEci, ThrustAccelerationPairEci> for VariableMassPairDisturbance {
+    fn calc_force(&self, state: &PositionVelocityMassPairStateEci, t: f64) -> ThrustAccelerationPairEci {
+        let pair: PositionVelocityPairStateEci = state.convert();
+        let acceleration = self.disturbance.calc_force(&pair, t);
+
+        let acceleration = match &self.mass_scaling {
+            None => acceleration,
+            Some(scaling) => {
+                let scale_chief = scaling.reference_mass_chief / (scaling.dry_mass_chief + state.propellant_chief().max(0.0));
+                let scale_deputy = scaling.reference_mass_deputy / (scaling.dry_mass_deputy + state.propellant_deputy().max(0.0));
+                let (chief, deputy) = (acceleration.chief() * scale_chief, acceleration.deputy() * scale_deputy);
+                Force6dEci::form_from_list([chief[0], chief[1], chief[2], deputy[0], deputy[1], deputy[2]])
+            }
+        };
+        ThrustAccelerationPairEci::form_from_acceleration(&acceleration)
+    }
+
+    fn update_stochastic(&mut self, t: f64, dt: f64, rng: &mut StdRng) {
+        self.disturbance.update_stochastic(t, dt, rng);
+    }
+}
+
+#[cfg(test)]
+struct ConstantPairDisturbance;
+
+#[cfg(test)]
+impl DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci> for ConstantPairDisturbance {
+    fn calc_force(&self, _state: &PositionVelocityPairStateEci, _t: f64) -> Force6dEci {
+        Force6dEci::form_from_list([1.0, 0.0, 0.0, 0.0, 2.0, 0.0])
+    }
+}
+
+#[test]
+fn test_variable_mass_disturbance_scaling() {
+    let state = PositionVelocityMassPairStateEci::form_from_list(
+        [7.0e6, 0.0, 0.0, 0.0, 7.5e3, 0.0, 5.0, 0.0],
+        [7.0e6, 0.0, 0.0, 0.0, 7.5e3, 0.0, 0.0, 0.0],
+    );
+
+    // 質量によらない外乱はそのまま加速度として加わる
+    let fixed = VariableMassPairDisturbance::new(Box::new(ConstantPairDisturbance));
+    let force = fixed.calc_force(&state, 0.0);
+    assert_eq!(force.acceleration_chief()[0], 1.0);
+    assert_eq!(force.thrust_chief()[0], 0.0);
+
+    // 基準 50 kg, 現在 chief 45 + 5 = 50 kg, deputy 40 + 0 = 40 kg
+    let scaled = VariableMassPairDisturbance::inversely_proportional_to_mass(Box::new(ConstantPairDisturbance), 50.0, 50.0, 45.0, 40.0);
+    let force = scaled.calc_force(&state, 0.0);
+    assert!((force.acceleration_chief()[0] - 1.0).abs() < 1e-12);
+    assert!((force.acceleration_deputy()[1] - 2.5).abs() < 1e-12);
+}
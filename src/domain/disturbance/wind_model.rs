@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+
+use ndarray::{Array1, arr1};
+
+use crate::domain::math::formulations::Math;
+
+/// **大気の水平風モデルのトレイト**
+/// 地球と共回転する大気に対する風速を返す (共回転成分は含めない)
+pub trait WindModel: Debug {
+    /// 位置 (ECI, m) と時刻 (エポックからの経過秒) から風速 (ECI, m/s) を返す
+    fn calc_wind_velocity(&self, position_eci: &Array1<f64>, t: f64) -> Array1<f64>;
+}
+
+/// **一定の水平風 (東向き, 北向き成分)**
+#[derive(Debug, Clone)]
+pub struct ConstantHorizontalWind {
+    east_mps: f64,
+    north_mps: f64,
+}
+
+impl ConstantHorizontalWind {
+    pub fn new(east_mps: f64, north_mps: f64) -> Self {
+        Self { east_mps, north_mps }
+    }
+}
+
+impl WindModel for ConstantHorizontalWind {
+    fn calc_wind_velocity(&self, position_eci: &Array1<f64>, _t: f64) -> Array1<f64> {
+        let up = Math::normalize(position_eci);
+        let east = Math::cross_product(&arr1(&[0.0, 0.0, 1.0]), &up);
+        // 極では東・北が定義できないので無風とする
+        if east.dot(&east) < 1e-12 {
+            return arr1(&[0.0, 0.0, 0.0]);
+        }
+        let east = Math::normalize(&east);
+        let north = Math::cross_product(&up, &east);
+        self.east_mps * east + self.north_mps * north
+    }
+}
+
+#[test]
+fn test_constant_horizontal_wind_direction() {
+    let wind = ConstantHorizontalWind::new(100.0, 50.0);
+
+    // 赤道上 (x 軸上) では東が +y, 北が +z
+    let v = wind.calc_wind_velocity(&arr1(&[7.0e6, 0.0, 0.0]), 0.0);
+    assert!((v[0]).abs() < 1e-9);
+    assert!((v[1] - 100.0).abs() < 1e-9);
+    assert!((v[2] - 50.0).abs() < 1e-9);
+
+    // 極上では無風
+    let v = wind.calc_wind_velocity(&arr1(&[0.0, 0.0, 7.0e6]), 0.0);
+    assert!(v.dot(&v) < 1e-12);
+}
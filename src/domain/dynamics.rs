@@ -3,4 +3,5 @@ pub mod dynamics_trait;
 pub mod dynamics_2body;
 pub mod dynamics_hcw;
 pub mod dynamics_2sat_2body;
-pub mod dynamics_linear;
\ No newline at end of file
+pub mod dynamics_linear;
+pub mod dynamics_2sat_2body_variable_mass;
\ No newline at end of file
@@ -1,3 +1,6 @@
+use ndarray::{Array2, Axis, s};
+
+use crate::domain::differentiable::differentiable_trait::{Differentiable2d, InputDifferentiable2d};
 use crate::domain::force::force_trait::Force;
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
@@ -16,7 +19,7 @@ impl TwoBodyDynamics {
 }
 
 impl ContinuousDynamics<PositionVelocityStateEci, Force3dEci> for TwoBodyDynamics {
-    fn compute_derivative(&self, state: &PositionVelocityStateEci, input: &Force3dEci) -> PositionVelocityStateEci {
+    fn compute_derivative(&self, state: &PositionVelocityStateEci, input: &Force3dEci, _t: f64) -> PositionVelocityStateEci {
         let mu = CONSTANTS.mu;
         let r_vec = state.position();
         let v_vec = state.velocity();
@@ -25,4 +28,50 @@ impl ContinuousDynamics<PositionVelocityStateEci, Force3dEci> for TwoBodyDynamic
 
         PositionVelocityStateEci::form_from_array(ndarray::concatenate![ndarray::Axis(0), v_vec, a_vec])
     }
-}
\ No newline at end of file
+}
+
+impl Differentiable2d<PositionVelocityStateEci, Force3dEci> for TwoBodyDynamics {
+    /// ∂f/∂x = [[0, I], [G, 0]], G = -μ/r^3 (I - 3 r̂ r̂^T)
+    fn differentiate(&self, state: &PositionVelocityStateEci, _input: &Force3dEci, _t: f64) -> Array2<f64> {
+        let r_norm = state.position_norm();
+        let r_hat = state.position() / r_norm;
+        let outer = r_hat.view().insert_axis(Axis(1)).dot(&r_hat.view().insert_axis(Axis(0)));
+        let gravity_gradient = (Array2::<f64>::eye(3) - outer * 3.0) * (-CONSTANTS.mu / r_norm.powi(3));
+
+        let mut jacobian = Array2::<f64>::zeros((6, 6));
+        jacobian.slice_mut(s![0..3, 3..6]).assign(&Array2::eye(3));
+        jacobian.slice_mut(s![3..6, 0..3]).assign(&gravity_gradient);
+        jacobian
+    }
+}
+
+impl InputDifferentiable2d<PositionVelocityStateEci, Force3dEci> for TwoBodyDynamics {
+    /// ∂f/∂u = [0; I]
+    fn differentiate_input(&self, _state: &PositionVelocityStateEci, _input: &Force3dEci, _t: f64) -> Array2<f64> {
+        let mut jacobian = Array2::<f64>::zeros((6, 3));
+        jacobian.slice_mut(s![3..6, ..]).assign(&Array2::eye(3));
+        jacobian
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::domain::differentiable::differentiable_trait::NumericalDefferential;
+
+#[test]
+fn test_two_body_jacobians_match_numerical() {
+    let dynamics = TwoBodyDynamics::new();
+    let state = PositionVelocityStateEci::form_from_array(arr1(&[6.0e6, 2.0e6, 1.0e6, -1.0e3, 7.0e3, 1.0e3]));
+    let input = Force3dEci::form_from_array(arr1(&[1e-3, -2e-3, 5e-4]));
+    let f = |x: &PositionVelocityStateEci, u: &Force3dEci| dynamics.compute_derivative(x, u, 0.0);
+
+    let a = dynamics.differentiate(&state, &input, 0.0);
+    let a_numerical = NumericalDefferential::differentiate_numeric(&f, &state, &input);
+    let scale = a.iter().fold(0.0_f64, |m, v| m.max(v.abs()));
+    assert!((&a - &a_numerical).iter().all(|v| v.abs() < 1e-4 * scale));
+
+    let b = dynamics.differentiate_input(&state, &input, 0.0);
+    let b_numerical = NumericalDefferential::differentiate_input_numeric(&f, &state, &input);
+    assert!((&b - &b_numerical).iter().all(|v| v.abs() < 1e-6));
+}
@@ -20,7 +20,7 @@ impl PairTwoBodyDynamics {
 }
 
 impl ContinuousDynamics<PositionVelocityPairStateEci, Force6dEci> for PairTwoBodyDynamics {
-    fn compute_derivative(&self, state: &PositionVelocityPairStateEci, input: &Force6dEci) -> PositionVelocityPairStateEci {
+    fn compute_derivative(&self, state: &PositionVelocityPairStateEci, input: &Force6dEci, _t: f64) -> PositionVelocityPairStateEci {
         let mu = CONSTANTS.mu;
         let state_vec: Vec<PositionVelocityStateEci> = state.convert();
         let state_chief = &state_vec[0];
@@ -0,0 +1,123 @@
+use ndarray::{Array1, arr1, concatenate, Axis};
+
+use crate::domain::state::position_velocity_mass_pair_state_eci::PositionVelocityMassPairStateEci;
+use crate::domain::state::state_trait::StateVector;
+use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
+use crate::domain::force::thrust_acceleration_pair_eci::ThrustAccelerationPairEci;
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+/// **推進剤の消費を考慮した二体問題の連続ダイナミクス**
+/// 推力 F (N) から加速度 F / m, 質量流量 |F| / (Isp g0), 累積 Δv の変化率 |F| / m を計算する.
+/// m は乾燥質量 + 現在の推進剤質量なので, 同じ推力でも推進剤を使うほど加速度は大きくなる.
+/// 推進剤がなくなった衛星には推力が出ない
+#[derive(Debug, Clone)]
+pub struct VariableMassPairTwoBodyDynamics {
+    dry_mass_chief: f64,   // 乾燥質量 (kg)
+    dry_mass_deputy: f64,
+    isp_chief: f64,        // 比推力 (s)
+    isp_deputy: f64,
+}
+
+impl VariableMassPairTwoBodyDynamics {
+    pub fn new(dry_mass_chief: f64, dry_mass_deputy: f64, isp_chief: f64, isp_deputy: f64) -> Self {
+        Self {
+            dry_mass_chief,
+            dry_mass_deputy,
+            isp_chief,
+            isp_deputy,
+        }
+    }
+
+    /// **1 衛星分の [v, a, 推進剤質量の変化率, Δv の変化率]**
+    fn compute_single(
+        state: &Array1<f64>,
+        propellant: f64,
+        dry_mass: f64,
+        isp: f64,
+        thrust: Array1<f64>,
+        acceleration: Array1<f64>,
+    ) -> Array1<f64> {
+        let mu = CONSTANTS.mu;
+        let r_vec = arr1(&[state[0], state[1], state[2]]);
+        let v_vec = arr1(&[state[3], state[4], state[5]]);
+        let r_norm = r_vec.dot(&r_vec).sqrt();
+
+        let mass = dry_mass + propellant.max(0.0);
+        let thrust = if propellant > 0.0 { thrust } else { Array1::zeros(3) };
+        let thrust_norm = thrust.dot(&thrust).sqrt();
+
+        let a_vec = -mu / r_norm.powi(3) * &r_vec + &thrust / mass + acceleration;
+        let mass_flow = -thrust_norm / (isp * CONSTANTS.standard_gravity);
+        let delta_v_rate = thrust_norm / mass;
+
+        concatenate![Axis(0), v_vec, a_vec, arr1(&[mass_flow, delta_v_rate])]
+    }
+}
+
+impl ContinuousDynamics<PositionVelocityMassPairStateEci, ThrustAccelerationPairEci> for VariableMassPairTwoBodyDynamics {
+    fn compute_derivative(&self, state: &PositionVelocityMassPairStateEci, input: &ThrustAccelerationPairEci, _t: f64) -> PositionVelocityMassPairStateEci {
+        let chief = Self::compute_single(
+            &state.chief(),
+            state.propellant_chief(),
+            self.dry_mass_chief,
+            self.isp_chief,
+            input.thrust_chief(),
+            input.acceleration_chief(),
+        );
+        let deputy = Self::compute_single(
+            &state.deputy(),
+            state.propellant_deputy(),
+            self.dry_mass_deputy,
+            self.isp_deputy,
+            input.thrust_deputy(),
+            input.acceleration_deputy(),
+        );
+
+        PositionVelocityMassPairStateEci::form_from_array(concatenate![Axis(0), chief, deputy])
+    }
+}
+
+#[cfg(test)]
+use crate::domain::dynamics::propagator::{Propagator, RungeKutta4Propagator};
+#[cfg(test)]
+use crate::domain::force::force_6d_eci::Force6dEci;
+#[cfg(test)]
+use crate::domain::force::force_trait::Force;
+
+#[test]
+fn test_variable_mass_rocket_equation() {
+    let r = 7.0e6;
+    let v = (CONSTANTS.mu / r).sqrt();
+    let mut state = PositionVelocityMassPairStateEci::form_from_list(
+        [r, 0.0, 0.0, 0.0, v, 0.0, 5.0, 0.0],
+        [r, 0.0, 0.0, 0.0, v, 0.0, 5.0, 0.0],
+    );
+    let (dry_mass, isp, thrust) = (45.0, 60.0, 1.0);
+    let dynamics = VariableMassPairTwoBodyDynamics::new(dry_mass, dry_mass, isp, isp);
+    let propagator = RungeKutta4Propagator;
+    let input = ThrustAccelerationPairEci::form_from_thrust(&Force6dEci::form_from_list([0.0, 0.0, 0.0, 0.0, thrust, 0.0]));
+
+    // 推力一定なら推進剤は |F| / (Isp g0) で減り, 質量が減るほど加速度は大きくなる
+    let zero = ThrustAccelerationPairEci::zeros();
+    let thrust_acceleration = |state: &PositionVelocityMassPairStateEci, t: f64| {
+        dynamics.compute_derivative(state, &input, t).get_vector()[12] - dynamics.compute_derivative(state, &zero, t).get_vector()[12]
+    };
+    let initial_acceleration = thrust_acceleration(&state, 0.0);
+    assert!((initial_acceleration - thrust / (dry_mass + 5.0)).abs() < 1e-12);
+
+    let dt = 1.0;
+    for k in 0..100 {
+        state = propagator.propagate_continuous(&state, &input, &dynamics, k as f64 * dt, dt);
+    }
+    let consumed = 5.0 - state.propellant_deputy();
+    assert!((consumed - 100.0 * thrust / (isp * CONSTANTS.standard_gravity)).abs() < 1e-9);
+    let final_acceleration = thrust_acceleration(&state, 100.0);
+    assert!(final_acceleration > initial_acceleration);
+    assert!((final_acceleration - thrust / (dry_mass + state.propellant_deputy())).abs() < 1e-12);
+
+    // 累積 Δv はロケット方程式 Isp g0 ln(m0 / m1) に一致する
+    let expected = isp * CONSTANTS.standard_gravity * ((dry_mass + 5.0) / (dry_mass + state.propellant_deputy())).ln();
+    assert!((state.delta_v_deputy() - expected).abs() < 1e-6);
+    assert_eq!(state.propellant_chief(), 5.0);
+    assert_eq!(state.delta_v_chief(), 0.0);
+}
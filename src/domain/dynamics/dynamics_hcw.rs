@@ -1,10 +1,11 @@
-use ndarray::arr2;
+use ndarray::{Array2, arr2};
 
 use crate::domain::force::force_3d_lvlh::Force3dLvlh;
 use crate::domain::force::force_trait::Force;
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
-use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
+use crate::domain::dynamics::dynamics_trait::{ContinuousDynamics, LinearSystem};
+use crate::domain::differentiable::differentiable_trait::{Differentiable2d, InputDifferentiable2d};
 use crate::infrastructure::settings::constants::CONSTANTS;
 
 /// **二体問題の連続ダイナミクス**
@@ -21,24 +22,44 @@ impl HcwDynamics {
     }
 }
 
-impl ContinuousDynamics<PositionVelocityStateLvlh, Force3dLvlh> for HcwDynamics {
-    fn compute_derivative(&self, state: &PositionVelocityStateLvlh, input: &Force3dLvlh) -> PositionVelocityStateLvlh {
-        let system_matrix = arr2(&[
+impl LinearSystem for HcwDynamics {
+    fn system_matrix(&self) -> Array2<f64> {
+        arr2(&[
             [0.0, 0.0, 0.0, 1.0, 0.0, 0.0],
             [0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
             [0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
             [3.0 * self.n.powf(2.0), 0.0, 0.0, 0.0, 2.0 * self.n, 0.0],
             [0.0, 0.0, 0.0, -2.0 * self.n, 0.0, 0.0],
-            [0.0, 0.0, -self.n.powf(2.0), 0.0, 0.0, 0.0]]);
-        let input_matrix = arr2(&[
+            [0.0, 0.0, -self.n.powf(2.0), 0.0, 0.0, 0.0]])
+    }
+
+    fn input_matrix(&self) -> Array2<f64> {
+        arr2(&[
             [0.0, 0.0, 0.0],
             [0.0, 0.0, 0.0],
             [0.0, 0.0, 0.0],
             [1.0, 0.0, 0.0],
             [0.0, 1.0, 0.0],
             [0.0, 0.0, 1.0]
-        ]);
-        let vec = (system_matrix * state.clone()).get_vector() + (input_matrix * input.clone()).get_vector();
+        ])
+    }
+}
+
+impl ContinuousDynamics<PositionVelocityStateLvlh, Force3dLvlh> for HcwDynamics {
+    fn compute_derivative(&self, state: &PositionVelocityStateLvlh, input: &Force3dLvlh, _t: f64) -> PositionVelocityStateLvlh {
+        let vec = (self.system_matrix() * state.clone()).get_vector() + (self.input_matrix() * input.clone()).get_vector();
         PositionVelocityStateLvlh::form_from_array(vec)
     }
-}
\ No newline at end of file
+}
+
+impl Differentiable2d<PositionVelocityStateLvlh, Force3dLvlh> for HcwDynamics {
+    fn differentiate(&self, _x: &PositionVelocityStateLvlh, _u: &Force3dLvlh, _t: f64) -> Array2<f64> {
+        self.system_matrix()
+    }
+}
+
+impl InputDifferentiable2d<PositionVelocityStateLvlh, Force3dLvlh> for HcwDynamics {
+    fn differentiate_input(&self, _x: &PositionVelocityStateLvlh, _u: &Force3dLvlh, _t: f64) -> Array2<f64> {
+        self.input_matrix()
+    }
+}
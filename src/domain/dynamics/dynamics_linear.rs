@@ -3,8 +3,8 @@ use crate::domain::state::state_trait::StateVector;
 use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
 use crate::domain::force::force_trait::Force;
 use crate::domain::force::force_3d_eci::Force3dEci;
-use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
-use crate::domain::differentiable::differentiable_trait::Differentiable2d;
+use crate::domain::dynamics::dynamics_trait::{ContinuousDynamics, LinearSystem};
+use crate::domain::differentiable::differentiable_trait::{Differentiable2d, InputDifferentiable2d};
 
 /// **線形ダイナミクス: \(\dot{x} = A x + B u\)**
 pub struct LinearDynamics {
@@ -20,7 +20,7 @@ impl LinearDynamics {
 
 impl ContinuousDynamics<PositionVelocityStateEci, Force3dEci> for LinearDynamics
 {
-    fn compute_derivative(&self, state: &PositionVelocityStateEci, input: &Force3dEci) -> PositionVelocityStateEci {
+    fn compute_derivative(&self, state: &PositionVelocityStateEci, input: &Force3dEci, _t: f64) -> PositionVelocityStateEci {
         let x_vec = state.get_vector();
         let u_vec = input.get_vector();
         let dx = self.a_matrix.dot(x_vec) + self.b_matrix.dot(u_vec);
@@ -28,6 +28,16 @@ impl ContinuousDynamics<PositionVelocityStateEci, Force3dEci> for LinearDynamics
     }
 }
 
+impl LinearSystem for LinearDynamics {
+    fn system_matrix(&self) -> Array2<f64> {
+        self.a_matrix.clone()
+    }
+
+    fn input_matrix(&self) -> Array2<f64> {
+        self.b_matrix.clone()
+    }
+}
+
 impl<T, U> Differentiable2d<T, U> for LinearDynamics
 where
     T: StateVector,
@@ -37,3 +47,13 @@ where
         self.a_matrix.clone() // 状態方程式の微分 (\(\nabla_x \dot{x} = A\))
     }
 }
+
+impl<T, U> InputDifferentiable2d<T, U> for LinearDynamics
+where
+    T: StateVector,
+    U: Force,
+{
+    fn differentiate_input(&self, _x: &T, _u: &U, _t: f64) -> Array2<f64> {
+        self.b_matrix.clone() // \(\nabla_u \dot{x} = B\)
+    }
+}
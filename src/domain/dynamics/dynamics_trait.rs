@@ -1,12 +1,21 @@
+use ndarray::Array2;
+
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::force::force_trait::Force;
 
 /// **連続ダイナミクスのトレイト**
+/// t はシミュレーション開始からの経過時間 (s)
 pub trait ContinuousDynamics<T: StateVector, U: Force> {
-    fn compute_derivative(&self, state: &T, input: &U) -> T;
+    fn compute_derivative(&self, state: &T, input: &U, t: f64) -> T;
 }
 
 /// **離散ダイナミクスのトレイト**
 pub trait DiscreteDynamics<T: StateVector, U: Force> {
     fn step(&self, state: &T, dt: f64) -> T;
-}
\ No newline at end of file
+}
+
+/// **線形ダイナミクス \dot{x} = A x + B u の係数行列**
+pub trait LinearSystem {
+    fn system_matrix(&self) -> Array2<f64>;
+    fn input_matrix(&self) -> Array2<f64>;
+}
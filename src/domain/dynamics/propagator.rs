@@ -5,7 +5,7 @@ use std::ops::{Add, Sub, Mul, Div};
 
 /// **伝搬トレイト**
 pub trait Propagator<T: StateVector, U: Force> {
-    fn propagate_continuous(&self, state: &T, input: &U, dynamics: &dyn ContinuousDynamics<T, U>, dt: f64) -> T;
+    fn propagate_continuous(&self, state: &T, input: &U, dynamics: &dyn ContinuousDynamics<T, U>, t: f64, dt: f64) -> T;
     fn propagate_discrete(&self, state: &T, dynamics: &dyn DiscreteDynamics<T, U>, dt: f64) -> T {
         dynamics.step(state, dt)
     }
@@ -21,8 +21,8 @@ where
     T: StateVector + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> + Clone,
     U: Force + Add<Output = U> + Sub<Output = U> + Mul<f64, Output = U> + Div<f64, Output = U> + Clone,
 {
-    fn propagate_continuous(&self, state: &T, input: &U, dynamics: &dyn ContinuousDynamics<T, U>, dt: f64) -> T {
-        let derivative = dynamics.compute_derivative(state, input);
+    fn propagate_continuous(&self, state: &T, input: &U, dynamics: &dyn ContinuousDynamics<T, U>, t: f64, dt: f64) -> T {
+        let derivative = dynamics.compute_derivative(state, input, t);
         state.clone() + derivative * dt
     }
 
@@ -40,11 +40,11 @@ where
     T: StateVector + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> + Clone,
     U: Force + Add<Output = U> + Sub<Output = U> + Mul<f64, Output = U> + Div<f64, Output = U> + Clone,
 {
-    fn propagate_continuous(&self, state: &T, input: &U, dynamics: &dyn ContinuousDynamics<T, U>, dt: f64) -> T {
-        let k1 = dynamics.compute_derivative(state, input);
-        let k2 = dynamics.compute_derivative(&(state.clone() + k1.clone() * (0.5 * dt)), input);
-        let k3 = dynamics.compute_derivative(&(state.clone() + k2.clone() * (0.5 * dt)), input);
-        let k4 = dynamics.compute_derivative(&(state.clone() + k3.clone() * dt), input);
+    fn propagate_continuous(&self, state: &T, input: &U, dynamics: &dyn ContinuousDynamics<T, U>, t: f64, dt: f64) -> T {
+        let k1 = dynamics.compute_derivative(state, input, t);
+        let k2 = dynamics.compute_derivative(&(state.clone() + k1.clone() * (0.5 * dt)), input, t + 0.5 * dt);
+        let k3 = dynamics.compute_derivative(&(state.clone() + k2.clone() * (0.5 * dt)), input, t + 0.5 * dt);
+        let k4 = dynamics.compute_derivative(&(state.clone() + k3.clone() * dt), input, t + dt);
 
         state.clone() + (k1 + k2 * 2.0 + k3 * 2.0 + k4) * (dt / 6.0)
     }
@@ -3,4 +3,5 @@ pub mod force_3d_lvlh;
 pub mod force_3d_eci;
 pub mod force_6d_lvlh;
 pub mod force_6d_eci;
+pub mod thrust_acceleration_pair_eci;
 pub mod force_converter;
\ No newline at end of file
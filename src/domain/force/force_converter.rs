@@ -150,4 +150,4 @@ impl ForceConverter<Force6dLvlh> for Force6dLvlh {
     fn convert(&self, state_eci: &PositionVelocityStateEci) -> Force6dLvlh {
         self.clone()
     }
-}
\ No newline at end of file
+}
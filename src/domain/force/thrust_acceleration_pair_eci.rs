@@ -0,0 +1,121 @@
+use ndarray::{Array1, concatenate, s, Axis};
+use std::ops::{Add, Sub, Mul, Div};
+
+use super::force_trait::Force;
+use super::force_6d_eci::Force6dEci;
+use crate::infrastructure::logger::loggable_trait::Loggable;
+
+/// **推力と加速度を分けた 2 衛星の入力 (ECI)**
+/// 推力 (N) は現在の質量で割り, 推進剤を消費する. 加速度 (m/s^2) は外乱などでそのまま加える
+#[derive(Debug, Clone)]
+pub struct ThrustAccelerationPairEci {
+    force: Array1<f64>, // [chief_thrust(3), deputy_thrust(3), chief_acceleration(3), deputy_acceleration(3)]
+}
+
+impl ThrustAccelerationPairEci {
+    pub fn form_from_thrust(thrust: &Force6dEci) -> Self {
+        Self { force: concatenate![Axis(0), thrust.get_vector().view(), Array1::zeros(6)] }
+    }
+
+    pub fn form_from_acceleration(acceleration: &Force6dEci) -> Self {
+        Self { force: concatenate![Axis(0), Array1::zeros(6), acceleration.get_vector().view()] }
+    }
+
+    pub fn thrust_chief(&self) -> Array1<f64> {
+        self.force.slice(s![0..3]).to_owned()
+    }
+
+    pub fn thrust_deputy(&self) -> Array1<f64> {
+        self.force.slice(s![3..6]).to_owned()
+    }
+
+    pub fn acceleration_chief(&self) -> Array1<f64> {
+        self.force.slice(s![6..9]).to_owned()
+    }
+
+    pub fn acceleration_deputy(&self) -> Array1<f64> {
+        self.force.slice(s![9..12]).to_owned()
+    }
+}
+
+impl Force for ThrustAccelerationPairEci {
+    fn get_vector(&self) -> &Array1<f64> {
+        &self.force
+    }
+
+    fn form_from_array(vec: Array1<f64>) -> Self {
+        Self { force: vec }
+    }
+
+    fn zeros() -> Self {
+        Self { force: Array1::zeros(12) }
+    }
+}
+
+impl Loggable for ThrustAccelerationPairEci {
+    fn header(&self) -> String {
+        "thrust0,thrust1,thrust2,thrust3,thrust4,thrust5,a0,a1,a2,a3,a4,a5".to_string()
+    }
+
+    fn output_log(&self) -> String {
+        let force_str: Vec<String> = self.get_vector().iter().map(|v| v.to_string()).collect();
+        force_str.join(",")
+    }
+}
+
+/// **演算子のオーバーロード**
+impl Add for ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn add(self, rhs: ThrustAccelerationPairEci) -> ThrustAccelerationPairEci {
+        self.add_vec(&rhs)
+    }
+}
+
+impl Add for &ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn add(self, rhs: &ThrustAccelerationPairEci) -> ThrustAccelerationPairEci {
+        self.add_vec(rhs)
+    }
+}
+
+impl Sub for ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn sub(self, rhs: ThrustAccelerationPairEci) -> ThrustAccelerationPairEci {
+        self.sub_vec(&rhs)
+    }
+}
+
+impl Sub for &ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn sub(self, rhs: &ThrustAccelerationPairEci) -> ThrustAccelerationPairEci {
+        self.sub_vec(rhs)
+    }
+}
+
+impl Mul<f64> for ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn mul(self, scalar: f64) -> ThrustAccelerationPairEci {
+        self.mul_scalar(scalar)
+    }
+}
+
+impl Mul<f64> for &ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn mul(self, scalar: f64) -> ThrustAccelerationPairEci {
+        self.mul_scalar(scalar)
+    }
+}
+
+impl Div<f64> for ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn div(self, scalar: f64) -> ThrustAccelerationPairEci {
+        self.div_scalar(scalar)
+    }
+}
+
+impl Div<f64> for &ThrustAccelerationPairEci {
+    type Output = ThrustAccelerationPairEci;
+    fn div(self, scalar: f64) -> ThrustAccelerationPairEci {
+        self.div_scalar(scalar)
+    }
+}
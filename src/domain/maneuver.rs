@@ -0,0 +1,4 @@
+pub mod impulsive_maneuver;
+pub mod cw_maneuver_planner;
+pub mod lambert;
+pub mod lambert_targeting;
\ No newline at end of file
@@ -0,0 +1,158 @@
+use ndarray::{Array1, Array2, concatenate, s, Axis};
+use ndarray_linalg::Inverse;
+
+use super::impulsive_maneuver::{ImpulsiveManeuver, ManeuverFrame};
+use crate::domain::dynamics::dynamics_trait::LinearSystem;
+use crate::domain::math::formulations::Math;
+use crate::domain::math::linear_program::LinearProgram;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+
+/// **CW (HCW) モデルによるインパルスマヌーバ計画**
+/// 状態遷移行列 Φ(t) = exp(A t) を使い, 速度の瞬時変化で初期状態から目標状態へ移る
+#[derive(Debug, Clone)]
+pub struct CwManeuverPlanner {
+    a_matrix: Array2<f64>,
+}
+
+impl CwManeuverPlanner {
+    pub fn new(dynamics: &dyn LinearSystem) -> Self {
+        Self {
+            a_matrix: dynamics.system_matrix(),
+        }
+    }
+
+    pub fn state_transition(&self, t: f64) -> Array2<f64> {
+        Math::expm(&(&self.a_matrix * t))
+    }
+
+    /// **2 インパルス解 (時刻 t0 と t0 + transfer_time に噴射)**
+    /// Φ_rv が特異になる移行時間 (軌道周期の整数倍など) ではエラー
+    pub fn two_impulse(
+        &self,
+        initial: &PositionVelocityStateLvlh,
+        target: &PositionVelocityStateLvlh,
+        t0: f64,
+        transfer_time: f64,
+    ) -> Result<Vec<ImpulsiveManeuver>, &'static str> {
+        if transfer_time <= 0.0 {
+            return Err("移行時間が正ではありません。");
+        }
+        let phi = self.state_transition(transfer_time);
+        let phi_rr = phi.slice(s![0..3, 0..3]);
+        let phi_rv = phi.slice(s![0..3, 3..6]).to_owned();
+        let phi_vr = phi.slice(s![3..6, 0..3]);
+        let phi_vv = phi.slice(s![3..6, 3..6]);
+
+        let phi_rv_inv = phi_rv.inv().map_err(|_| "Φ_rv が正則ではありません。")?;
+        let norm = |m: &Array2<f64>| m.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        if norm(&phi_rv) * norm(&phi_rv_inv) > 1e12 {
+            return Err("Φ_rv が正則ではありません。");
+        }
+
+        let (r0, v0) = (initial.position(), initial.velocity());
+        let v0_plus = phi_rv_inv.dot(&(target.position() - phi_rr.dot(&r0)));
+        let vf_minus = phi_vr.dot(&r0) + phi_vv.dot(&v0_plus);
+
+        Ok(vec![
+            ImpulsiveManeuver::new(t0, &v0_plus - &v0, ManeuverFrame::Lvlh),
+            ImpulsiveManeuver::new(t0 + transfer_time, target.velocity() - vf_minus, ManeuverFrame::Lvlh),
+        ])
+    }
+
+    /// **N インパルスの燃料最適解**
+    /// [t0, t0 + transfer_time] を等間隔に分けた candidates 個の噴射候補から,
+    /// 終端状態の拘束を満たして各軸の |Δv| の和を最小にする噴射を線形計画で選ぶ
+    pub fn multi_impulse(
+        &self,
+        initial: &PositionVelocityStateLvlh,
+        target: &PositionVelocityStateLvlh,
+        t0: f64,
+        transfer_time: f64,
+        candidates: usize,
+    ) -> Result<Vec<ImpulsiveManeuver>, &'static str> {
+        if candidates < 2 || transfer_time <= 0.0 {
+            return Err("噴射候補は 2 個以上, 移行時間は正である必要があります。");
+        }
+        let interval = transfer_time / (candidates - 1) as f64;
+
+        // 終端状態 = Φ(T) x0 + Σ Φ(T - τ_k) [0; I] Δv_k
+        let columns: Vec<Array2<f64>> = (0..candidates)
+            .map(|k| self.state_transition(transfer_time - k as f64 * interval).slice(s![.., 3..6]).to_owned())
+            .collect();
+        let views: Vec<_> = columns.iter().map(|c| c.view()).collect();
+        let m_matrix = concatenate(Axis(1), &views).map_err(|_| "行列の結合に失敗しました。")?;
+        let b = target.get_vector() - &self.state_transition(transfer_time).dot(initial.get_vector());
+
+        // Δv = p - q (p, q ≥ 0) として Σ (p + q) を最小化
+        let a_eq = concatenate![Axis(1), m_matrix, -&m_matrix];
+        let c = Array1::<f64>::ones(6 * candidates);
+        let solution = LinearProgram::minimize(&c, &a_eq, &b)?;
+
+        let threshold = 1e-9 * solution.iter().fold(1e-12_f64, |acc, v| acc.max(v.abs()));
+        Ok((0..candidates)
+            .map(|k| {
+                let delta_v = solution.slice(s![3 * k..3 * k + 3]).to_owned()
+                    - solution.slice(s![3 * (candidates + k)..3 * (candidates + k) + 3]);
+                ImpulsiveManeuver::new(t0 + k as f64 * interval, delta_v, ManeuverFrame::Lvlh)
+            })
+            .filter(|m| m.delta_v.iter().any(|v| v.abs() > threshold))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+use crate::application::simulator::simulator::Simulator;
+#[cfg(test)]
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+#[cfg(test)]
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+#[cfg(test)]
+use crate::domain::force::force_trait::Force;
+#[cfg(test)]
+use crate::domain::maneuver::impulsive_maneuver::ImpulsiveState;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_cw_two_impulse_and_multi_impulse() {
+    let dynamics = HcwDynamics::new(CONSTANTS.radius + 500.0e3);
+    let planner = CwManeuverPlanner::new(&dynamics);
+    let initial = PositionVelocityStateLvlh::form_from_list([20.0, -500.0, 10.0], [0.0, 0.01, 0.0]);
+    let target = PositionVelocityStateLvlh::form_from_list([0.0, -50.0, 0.0], [0.0, 0.0, 0.0]);
+    let (dt, steps) = (10.0, 200);
+    let transfer_time = dt * steps as f64;
+
+    // 2 インパルス解を Simulator に予約して瞬時の速度変化として実行する
+    let maneuvers = planner.two_impulse(&initial, &target, 0.0, transfer_time).unwrap();
+    let mut simulator = Simulator::new(RungeKutta4Propagator, dynamics.clone(), initial.clone(), dt, steps, 0.0);
+    for maneuver in maneuvers.iter() {
+        simulator.schedule_maneuver(maneuver.clone()).unwrap();
+    }
+    for _ in 0..steps {
+        simulator.update(&Force3dLvlh::zeros());
+    }
+    assert_eq!(simulator.executed_maneuvers().len(), 2);
+    let error = simulator.get_state().get_vector() - target.get_vector();
+    assert!(error.slice(s![0..3]).iter().all(|v| v.abs() < 1e-3));
+    assert!(error.slice(s![3..6]).iter().all(|v| v.abs() < 1e-6));
+
+    // N インパルス解も目標に到達し, 各軸の |Δv| の和は 2 インパルス解以下
+    let l1 = |maneuvers: &[ImpulsiveManeuver]| maneuvers.iter().map(|m| m.delta_v.iter().map(|v| v.abs()).sum::<f64>()).sum::<f64>();
+    let multi = planner.multi_impulse(&initial, &target, 0.0, transfer_time, 21).unwrap();
+    assert!(l1(&multi) <= l1(&maneuvers) + 1e-9);
+
+    let mut state = initial.clone();
+    let mut t = 0.0;
+    for maneuver in multi.iter() {
+        state = PositionVelocityStateLvlh::form_from_array(planner.state_transition(maneuver.t - t).dot(state.get_vector()));
+        state = state.apply_delta_v(&maneuver.delta_v);
+        t = maneuver.t;
+    }
+    let state = planner.state_transition(transfer_time - t).dot(state.get_vector());
+    let error = &state - target.get_vector();
+    assert!(error.slice(s![0..3]).iter().all(|v| v.abs() < 1e-6));
+    assert!(error.slice(s![3..6]).iter().all(|v| v.abs() < 1e-9));
+}
@@ -0,0 +1,224 @@
+use ndarray::{Array1, s};
+
+use crate::domain::math::formulations::Math;
+use crate::domain::state::position_velocity_mass_pair_state_eci::PositionVelocityMassPairStateEci;
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::logger::loggable_trait::Loggable;
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+/// **Δv を表す座標系**
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManeuverFrame {
+    Eci,
+    /// 噴射時刻の chief (単体の ECI 状態ではその衛星自身) の LVLH
+    Lvlh,
+}
+
+impl ManeuverFrame {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ManeuverFrame::Eci => "eci",
+            ManeuverFrame::Lvlh => "lvlh",
+        }
+    }
+}
+
+/// **インパルス的なマヌーバ (時刻 t に速度を Δv だけ瞬時に変える)**
+#[derive(Debug, Clone)]
+pub struct ImpulsiveManeuver {
+    pub t: f64,
+    pub delta_v: Array1<f64>,
+    pub frame: ManeuverFrame,
+}
+
+impl ImpulsiveManeuver {
+    pub fn new(t: f64, delta_v: Array1<f64>, frame: ManeuverFrame) -> Self {
+        Self { t, delta_v, frame }
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.delta_v.dot(&self.delta_v).sqrt()
+    }
+
+    /// **マヌーバ列の Δv の合計 (2 ノルムの和)**
+    pub fn total_delta_v(maneuvers: &[ImpulsiveManeuver]) -> f64 {
+        maneuvers.iter().map(|m| m.magnitude()).sum()
+    }
+}
+
+impl Loggable for ImpulsiveManeuver {
+    fn header(&self) -> String {
+        "burn_frame,burn_dv0,burn_dv1,burn_dv2".to_string()
+    }
+
+    fn output_log(&self) -> String {
+        let dv_str: Vec<String> = self.delta_v.iter().map(|v| v.to_string()).collect();
+        format!("{},{}", self.frame.name(), dv_str.join(","))
+    }
+}
+
+/// **速度を瞬時に変えられる状態量**
+pub trait ImpulsiveState: StateVector {
+    /// 噴射で消費する推進剤を計算するための推進系の諸元 (推進剤を持たない状態量は `()`)
+    type Propulsion;
+
+    /// **状態量と同じ座標系の Δv を加える**
+    fn apply_delta_v(&self, delta_v: &Array1<f64>) -> Self;
+
+    /// **frame の Δv を扱えるか**
+    fn supports_frame(frame: ManeuverFrame) -> bool;
+
+    /// **マヌーバの座標系を考慮して Δv を加える**
+    fn apply_maneuver(&self, maneuver: &ImpulsiveManeuver, propulsion: Option<&Self::Propulsion>) -> Result<Self, &'static str>;
+}
+
+fn add_velocity(state: &Array1<f64>, offset: usize, delta_v: &Array1<f64>) -> Array1<f64> {
+    let mut state = state.clone();
+    let mut velocity = state.slice_mut(s![offset + 3..offset + 6]);
+    velocity += delta_v;
+    state
+}
+
+impl ImpulsiveState for PositionVelocityStateLvlh {
+    type Propulsion = ();
+
+    fn apply_delta_v(&self, delta_v: &Array1<f64>) -> Self {
+        Self::form_from_array(add_velocity(self.get_vector(), 0, delta_v))
+    }
+
+    fn supports_frame(frame: ManeuverFrame) -> bool {
+        frame == ManeuverFrame::Lvlh
+    }
+
+    fn apply_maneuver(&self, maneuver: &ImpulsiveManeuver, _: Option<&()>) -> Result<Self, &'static str> {
+        match maneuver.frame {
+            ManeuverFrame::Lvlh => Ok(self.apply_delta_v(&maneuver.delta_v)),
+            ManeuverFrame::Eci => Err("LVLH の状態量には ECI の Δv を加えられません。"),
+        }
+    }
+}
+
+impl ImpulsiveState for PositionVelocityStateEci {
+    type Propulsion = ();
+
+    fn apply_delta_v(&self, delta_v: &Array1<f64>) -> Self {
+        Self::form_from_array(add_velocity(self.get_vector(), 0, delta_v))
+    }
+
+    fn supports_frame(_: ManeuverFrame) -> bool {
+        true
+    }
+
+    fn apply_maneuver(&self, maneuver: &ImpulsiveManeuver, _: Option<&()>) -> Result<Self, &'static str> {
+        match maneuver.frame {
+            ManeuverFrame::Eci => Ok(self.apply_delta_v(&maneuver.delta_v)),
+            ManeuverFrame::Lvlh => {
+                let rotation = Math::mat_lvlh2eci(&self.position(), &self.velocity());
+                Ok(self.apply_delta_v(&rotation.dot(&maneuver.delta_v)))
+            }
+        }
+    }
+}
+
+/// **ペアの状態量では deputy の速度を変える (LVLH は chief 基準)**
+impl ImpulsiveState for PositionVelocityPairStateEci {
+    type Propulsion = ();
+
+    fn apply_delta_v(&self, delta_v: &Array1<f64>) -> Self {
+        Self::form_from_array(add_velocity(self.get_vector(), 6, delta_v))
+    }
+
+    fn supports_frame(_: ManeuverFrame) -> bool {
+        true
+    }
+
+    fn apply_maneuver(&self, maneuver: &ImpulsiveManeuver, _: Option<&()>) -> Result<Self, &'static str> {
+        Ok(self.apply_delta_v(&pair_delta_v_eci(&self.chief(), maneuver)))
+    }
+}
+
+/// **ペアの状態量に加える deputy の ECI の Δv (LVLH は chief 基準)**
+fn pair_delta_v_eci(chief: &Array1<f64>, maneuver: &ImpulsiveManeuver) -> Array1<f64> {
+    match maneuver.frame {
+        ManeuverFrame::Eci => maneuver.delta_v.clone(),
+        ManeuverFrame::Lvlh => {
+            let rotation = Math::mat_lvlh2eci(&chief.slice(s![0..3]).to_owned(), &chief.slice(s![3..6]).to_owned());
+            rotation.dot(&maneuver.delta_v)
+        }
+    }
+}
+
+/// **deputy の推進系の諸元**
+#[derive(Debug, Clone)]
+pub struct ImpulsivePropulsion {
+    pub dry_mass: f64,  // 乾燥質量 (kg)
+    pub isp: f64,       // 比推力 (s)
+}
+
+/// **推進剤付きのペアの状態量では deputy の速度を変え, 累積 Δv を増やす**
+/// 推進系の諸元があればロケット方程式 Δm = m (1 - exp(-|Δv| / (Isp g0))) で推進剤を減らす.
+/// 推進剤が足りない噴射はエラーにする
+impl ImpulsiveState for PositionVelocityMassPairStateEci {
+    type Propulsion = ImpulsivePropulsion;
+
+    fn apply_delta_v(&self, delta_v: &Array1<f64>) -> Self {
+        let mut state = add_velocity(self.get_vector(), 8, delta_v);
+        state[15] += delta_v.dot(delta_v).sqrt();
+        Self::form_from_array(state)
+    }
+
+    fn supports_frame(_: ManeuverFrame) -> bool {
+        true
+    }
+
+    fn apply_maneuver(&self, maneuver: &ImpulsiveManeuver, propulsion: Option<&ImpulsivePropulsion>) -> Result<Self, &'static str> {
+        let state = self.apply_delta_v(&pair_delta_v_eci(&self.chief(), maneuver));
+        let Some(propulsion) = propulsion else {
+            return Ok(state);
+        };
+
+        let mass = propulsion.dry_mass + self.propellant_deputy();
+        let consumed = mass * (1.0 - (-maneuver.magnitude() / (propulsion.isp * CONSTANTS.standard_gravity)).exp());
+        if consumed > self.propellant_deputy() {
+            return Err("推進剤が足りないため噴射できません。");
+        }
+        let mut vector = state.get_vector().clone();
+        vector[14] -= consumed;
+        Ok(Self::form_from_array(vector))
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+
+#[test]
+fn test_impulsive_maneuver_consumes_propellant() {
+    let r = CONSTANTS.radius + 500.0e3;
+    let v = (CONSTANTS.mu / r).sqrt();
+    let state = PositionVelocityMassPairStateEci::form_from_list(
+        [r, 0.0, 0.0, 0.0, v, 0.0, 5.0, 0.0],
+        [r, -100.0, 0.0, 0.0, v, 0.0, 5.0, 0.0],
+    );
+    let propulsion = ImpulsivePropulsion { dry_mass: 45.0, isp: 60.0 };
+    let maneuver = ImpulsiveManeuver::new(0.0, arr1(&[0.0, 3.0, 4.0]), ManeuverFrame::Eci);
+
+    // 速度と累積 Δv が変わり, 推進剤はロケット方程式の分だけ減る (chief は変わらない)
+    let burned = state.apply_maneuver(&maneuver, Some(&propulsion)).unwrap();
+    assert!((burned.deputy()[4] - (v + 3.0)).abs() < 1e-12 && (burned.deputy()[5] - 4.0).abs() < 1e-12);
+    assert!((burned.delta_v_deputy() - 5.0).abs() < 1e-12);
+    let expected = 50.0 * (1.0 - (-5.0 / (60.0 * CONSTANTS.standard_gravity)).exp());
+    assert!((5.0 - burned.propellant_deputy() - expected).abs() < 1e-12);
+    assert_eq!(burned.chief(), state.chief());
+    assert_eq!(burned.propellant_chief(), 5.0);
+
+    // 消費した推進剤から逆算した Δv は指令と一致する
+    let delta_v = 60.0 * CONSTANTS.standard_gravity * (50.0 / (45.0 + burned.propellant_deputy())).ln();
+    assert!((delta_v - 5.0).abs() < 1e-9);
+
+    // 推進剤が足りない噴射はできない
+    let large = ImpulsiveManeuver::new(0.0, arr1(&[100.0, 0.0, 0.0]), ManeuverFrame::Eci);
+    assert!(state.apply_maneuver(&large, Some(&propulsion)).is_err());
+}
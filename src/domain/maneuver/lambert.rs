@@ -0,0 +1,360 @@
+use std::f64::consts::PI;
+
+use ndarray::{Array1, concatenate, Axis};
+
+use crate::domain::math::formulations::Math;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::state_trait::StateVector;
+
+/// Householder / Halley 反復の上限と収束判定
+const MAX_ITERATIONS: usize = 50;
+const ABSOLUTE_TOLERANCE: f64 = 1e-13;
+const RELATIVE_TOLERANCE: f64 = 1e-13;
+
+/// **ランベルト問題の解 (出発・到着時の速度)**
+#[derive(Debug, Clone)]
+pub struct LambertSolution {
+    pub v1: Array1<f64>,
+    pub v2: Array1<f64>,
+    pub revolutions: usize,
+    pub low_path: bool,  // 多周回解の枝 (0 周回解では true)
+}
+
+/// **Izzo (2015) のランベルト問題ソルバ**
+/// 無次元化した移行時間方程式を Householder 法で解く. 多周回・逆行にも対応する
+#[derive(Debug, Clone)]
+pub struct LambertSolver {
+    mu: f64,
+    prograde: bool,
+    max_revolutions: usize,
+}
+
+impl LambertSolver {
+    pub fn new(mu: f64, prograde: bool, max_revolutions: usize) -> Self {
+        Self {
+            mu,
+            prograde,
+            max_revolutions,
+        }
+    }
+
+    /// **位置 r1 から r2 へ時間 tof で移る全ての解 (0 周回解 + 各周回数の 2 つの枝)**
+    pub fn solve(&self, r1: &Array1<f64>, r2: &Array1<f64>, tof: f64) -> Result<Vec<LambertSolution>, &'static str> {
+        let geometry = Geometry::new(r1, r2, self.mu, self.prograde)?;
+        if tof <= 0.0 {
+            return Err("移行時間が正ではありません。");
+        }
+        let t = (2.0 * self.mu / geometry.s.powi(3)).sqrt() * tof;
+        let revolutions = self.max_revolutions.min(geometry.max_revolutions(t));
+
+        let mut solutions = Vec::new();
+        for m in 0..=revolutions {
+            let branches: &[bool] = if m == 0 { &[true] } else { &[true, false] };
+            for &low_path in branches {
+                let x0 = initial_guess(t, geometry.lambda, m, low_path);
+                let x = householder(x0, t, geometry.lambda, m)?;
+                let (v1, v2) = geometry.reconstruct(x);
+                solutions.push(LambertSolution { v1, v2, revolutions: m, low_path });
+            }
+        }
+        Ok(solutions)
+    }
+}
+
+/// **問題の幾何 (無次元化の係数と接線方向)**
+struct Geometry {
+    r1_norm: f64,
+    r2_norm: f64,
+    i_r1: Array1<f64>,
+    i_r2: Array1<f64>,
+    i_t1: Array1<f64>,
+    i_t2: Array1<f64>,
+    s: f64,
+    c: f64,
+    lambda: f64,
+    mu: f64,
+}
+
+impl Geometry {
+    fn new(r1: &Array1<f64>, r2: &Array1<f64>, mu: f64, prograde: bool) -> Result<Self, &'static str> {
+        let chord = r2 - r1;
+        let c = chord.dot(&chord).sqrt();
+        let r1_norm = r1.dot(r1).sqrt();
+        let r2_norm = r2.dot(r2).sqrt();
+        let s = (r1_norm + r2_norm + c) * 0.5;
+        let i_r1 = r1 / r1_norm;
+        let i_r2 = r2 / r2_norm;
+        let normal = Math::cross_product(&i_r1, &i_r2);
+        let normal_norm = normal.dot(&normal).sqrt();
+        if normal_norm < 1e-12 {
+            return Err("出発点と到着点が同一直線上にあり軌道面が定まりません。");
+        }
+        let i_h = normal / normal_norm;
+
+        let mut lambda = (1.0 - (c / s).min(1.0)).sqrt();
+        let (mut i_t1, mut i_t2) = if i_h[2] < 0.0 {
+            lambda = -lambda;
+            (Math::cross_product(&i_r1, &i_h), Math::cross_product(&i_r2, &i_h))
+        } else {
+            (Math::cross_product(&i_h, &i_r1), Math::cross_product(&i_h, &i_r2))
+        };
+        if !prograde {
+            lambda = -lambda;
+            i_t1 = -i_t1;
+            i_t2 = -i_t2;
+        }
+
+        Ok(Self { r1_norm, r2_norm, i_r1, i_r2, i_t1, i_t2, s, c, lambda, mu })
+    }
+
+    /// **無次元移行時間 t で可能な最大周回数**
+    fn max_revolutions(&self, t: f64) -> usize {
+        let mut m_max = (t / PI).floor();
+        let t_00 = self.lambda.acos() + self.lambda * (1.0 - self.lambda.powi(2)).sqrt();
+        if m_max > 0.0 && t < t_00 + m_max * PI {
+            // 周回数 m_max の最小移行時間より短ければ 1 周減らす
+            if let Ok(x_min) = halley(0.1, self.lambda, m_max as usize) {
+                if t < tof_equation(x_min, 0.0, self.lambda, m_max as usize) {
+                    m_max -= 1.0;
+                }
+            }
+        }
+        m_max as usize
+    }
+
+    fn reconstruct(&self, x: f64) -> (Array1<f64>, Array1<f64>) {
+        let lambda = self.lambda;
+        let y = compute_y(x, lambda);
+        let gamma = (self.mu * self.s / 2.0).sqrt();
+        let rho = (self.r1_norm - self.r2_norm) / self.c;
+        let sigma = (1.0 - rho * rho).sqrt();
+
+        let v_r1 = gamma * ((lambda * y - x) - rho * (lambda * y + x)) / self.r1_norm;
+        let v_r2 = -gamma * ((lambda * y - x) + rho * (lambda * y + x)) / self.r2_norm;
+        let v_t1 = gamma * sigma * (y + lambda * x) / self.r1_norm;
+        let v_t2 = gamma * sigma * (y + lambda * x) / self.r2_norm;
+
+        (
+            &self.i_r1 * v_r1 + &self.i_t1 * v_t1,
+            &self.i_r2 * v_r2 + &self.i_t2 * v_t2,
+        )
+    }
+}
+
+fn compute_y(x: f64, lambda: f64) -> f64 {
+    (1.0 - lambda * lambda * (1.0 - x * x)).sqrt()
+}
+
+fn compute_psi(x: f64, y: f64, lambda: f64) -> f64 {
+    if (-1.0..1.0).contains(&x) {
+        (x * y + lambda * (1.0 - x * x)).acos()
+    } else if x > 1.0 {
+        ((y - x * lambda) * (x * x - 1.0).sqrt()).asinh()
+    } else {
+        0.0
+    }
+}
+
+/// **超幾何関数 2F1(3, 1, 5/2, z)**
+fn hypergeometric(z: f64) -> f64 {
+    if z >= 1.0 {
+        return f64::INFINITY;
+    }
+    let (mut result, mut term) = (1.0, 1.0);
+    for i in 0..1000 {
+        let i = i as f64;
+        term = term * (3.0 + i) * (1.0 + i) / (2.5 + i) * z / (i + 1.0);
+        let previous = result;
+        result += term;
+        if result == previous {
+            break;
+        }
+    }
+    result
+}
+
+/// **無次元移行時間方程式 T(x) - t0**
+fn tof_equation(x: f64, t0: f64, lambda: f64, m: usize) -> f64 {
+    tof_equation_y(x, compute_y(x, lambda), t0, lambda, m)
+}
+
+fn tof_equation_y(x: f64, y: f64, t0: f64, lambda: f64, m: usize) -> f64 {
+    let t = if m == 0 && 0.6_f64.sqrt() < x && x < 1.4_f64.sqrt() {
+        // x = 1 付近は級数展開で評価する
+        let eta = y - lambda * x;
+        let s1 = (1.0 - lambda - x * eta) * 0.5;
+        let q = 4.0 / 3.0 * hypergeometric(s1);
+        (eta.powi(3) * q + 4.0 * lambda * eta) * 0.5
+    } else {
+        let psi = compute_psi(x, y, lambda);
+        ((psi + m as f64 * PI) / (1.0 - x * x).abs().sqrt() - x + lambda * y) / (1.0 - x * x)
+    };
+    t - t0
+}
+
+/// **移行時間方程式の 1 〜 3 階微分**
+fn tof_derivatives(x: f64, y: f64, t: f64, lambda: f64) -> (f64, f64, f64) {
+    let d1 = (3.0 * t * x - 2.0 + 2.0 * lambda.powi(3) * x / y) / (1.0 - x * x);
+    let d2 = (3.0 * t + 5.0 * x * d1 + 2.0 * (1.0 - lambda * lambda) * lambda.powi(3) / y.powi(3)) / (1.0 - x * x);
+    let d3 = (7.0 * x * d2 + 8.0 * d1 - 6.0 * (1.0 - lambda * lambda) * lambda.powi(5) * x / y.powi(5)) / (1.0 - x * x);
+    (d1, d2, d3)
+}
+
+fn initial_guess(t: f64, lambda: f64, m: usize, low_path: bool) -> f64 {
+    if m == 0 {
+        let t_0 = lambda.acos() + lambda * (1.0 - lambda * lambda).sqrt();
+        let t_1 = 2.0 * (1.0 - lambda.powi(3)) / 3.0;
+        if t >= t_0 {
+            (t_0 / t).powf(2.0 / 3.0) - 1.0
+        } else if t < t_1 {
+            2.5 * t_1 / t * (t_1 - t) / (1.0 - lambda.powi(5)) + 1.0
+        } else {
+            ((2.0_f64).ln() * (t / t_0).ln() / (t_1 / t_0).ln()).exp() - 1.0
+        }
+    } else {
+        let m_pi = m as f64 * PI;
+        let left = ((m_pi + PI) / (8.0 * t)).powf(2.0 / 3.0);
+        let right = ((8.0 * t) / m_pi).powf(2.0 / 3.0);
+        let x_left = (left - 1.0) / (left + 1.0);
+        let x_right = (right - 1.0) / (right + 1.0);
+        if low_path { x_left.max(x_right) } else { x_left.min(x_right) }
+    }
+}
+
+/// **移行時間方程式の根 (Householder 法)**
+fn householder(mut x: f64, t0: f64, lambda: f64, m: usize) -> Result<f64, &'static str> {
+    for _ in 0..MAX_ITERATIONS {
+        let y = compute_y(x, lambda);
+        let f = tof_equation_y(x, y, t0, lambda, m);
+        let (d1, d2, d3) = tof_derivatives(x, y, f + t0, lambda);
+        let next = x - f * ((d1 * d1 - f * d2 / 2.0) / (d1 * (d1 * d1 - f * d2) + d3 * f * f / 6.0));
+        if !next.is_finite() {
+            return Err("ランベルト問題の反復が発散しました。");
+        }
+        if (next - x).abs() < RELATIVE_TOLERANCE * x.abs() + ABSOLUTE_TOLERANCE {
+            return Ok(next);
+        }
+        x = next;
+    }
+    Err("ランベルト問題の反復が収束しませんでした。")
+}
+
+/// **周回数 m の移行時間が最小になる x (Halley 法で dT/dx = 0 を解く)**
+fn halley(mut x: f64, lambda: f64, m: usize) -> Result<f64, &'static str> {
+    for _ in 0..MAX_ITERATIONS {
+        let y = compute_y(x, lambda);
+        let t = tof_equation_y(x, y, 0.0, lambda, m);
+        let (d1, d2, d3) = tof_derivatives(x, y, t, lambda);
+        let next = x - 2.0 * d1 * d2 / (2.0 * d2 * d2 - d1 * d3);
+        if !next.is_finite() {
+            return Err("最小移行時間の反復が発散しました。");
+        }
+        if (next - x).abs() < RELATIVE_TOLERANCE * x.abs() + ABSOLUTE_TOLERANCE {
+            return Ok(next);
+        }
+        x = next;
+    }
+    Err("最小移行時間の反復が収束しませんでした。")
+}
+
+/// **スタンフ関数 C(z), S(z)**
+fn stumpff(z: f64) -> (f64, f64) {
+    if z > 1e-6 {
+        let sz = z.sqrt();
+        ((1.0 - sz.cos()) / z, (sz - sz.sin()) / sz.powi(3))
+    } else if z < -1e-6 {
+        let sz = (-z).sqrt();
+        ((sz.cosh() - 1.0) / -z, (sz.sinh() - sz) / sz.powi(3))
+    } else {
+        (0.5 - z / 24.0 + z * z / 720.0, 1.0 / 6.0 - z / 120.0 + z * z / 5040.0)
+    }
+}
+
+/// **ケプラー軌道に沿った伝搬 (普遍変数法)**
+pub fn propagate_kepler(state: &PositionVelocityStateEci, dt: f64, mu: f64) -> Result<PositionVelocityStateEci, &'static str> {
+    let (r0, v0) = (state.position(), state.velocity());
+    let r0_norm = state.position_norm();
+    let sqrt_mu = mu.sqrt();
+    let vr0 = r0.dot(&v0) / r0_norm;
+    let alpha = 2.0 / r0_norm - v0.dot(&v0) / mu;
+
+    // 楕円軌道では周期の整数倍を除いてから解く
+    let dt = if alpha > 0.0 {
+        let period = 2.0 * PI / (mu * alpha.powi(3)).sqrt();
+        dt - (dt / period).round() * period
+    } else {
+        dt
+    };
+
+    let mut chi = sqrt_mu * alpha.abs() * dt;
+    let mut converged = false;
+    for _ in 0..MAX_ITERATIONS * 2 {
+        let z = alpha * chi * chi;
+        let (c, s) = stumpff(z);
+        let f = r0_norm * vr0 / sqrt_mu * chi * chi * c + (1.0 - alpha * r0_norm) * chi.powi(3) * s + r0_norm * chi - sqrt_mu * dt;
+        let df = r0_norm * vr0 / sqrt_mu * chi * (1.0 - z * s) + (1.0 - alpha * r0_norm) * chi * chi * c + r0_norm;
+        let step = f / df;
+        chi -= step;
+        if step.abs() < 1e-12 * (1.0 + chi.abs()) {
+            converged = true;
+            break;
+        }
+    }
+    if !converged || !chi.is_finite() {
+        return Err("ケプラー方程式の反復が収束しませんでした。");
+    }
+
+    let z = alpha * chi * chi;
+    let (c, s) = stumpff(z);
+    let f = 1.0 - chi * chi / r0_norm * c;
+    let g = dt - chi.powi(3) * s / sqrt_mu;
+    let r = &r0 * f + &v0 * g;
+    let r_norm = r.dot(&r).sqrt();
+    let f_dot = sqrt_mu / (r_norm * r0_norm) * (z * chi * s - chi);
+    let g_dot = 1.0 - chi * chi / r_norm * c;
+    let v = &r0 * f_dot + &v0 * g_dot;
+
+    Ok(PositionVelocityStateEci::form_from_array(concatenate![Axis(0), r, v]))
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_lambert_recovers_kepler_orbit() {
+    let mu = CONSTANTS.mu;
+    let initial = PositionVelocityStateEci::form_from_array(arr1(&[7.0e6, 1.0e6, 5.0e5, -1.0e3, 7.2e3, 1.5e3]));
+    let r = initial.position_norm();
+    let alpha = 2.0 / r - initial.velocity_norm().powi(2) / mu;
+    let period = 2.0 * PI / (mu * alpha.powi(3)).sqrt();
+
+    // ケプラー伝搬の往復で元に戻る
+    let back = propagate_kepler(&propagate_kepler(&initial, 2000.0, mu).unwrap(), -2000.0, mu).unwrap();
+    assert!((back.get_vector() - initial.get_vector()).iter().all(|v| v.abs() < 1e-4));
+
+    // 0 周回解
+    let tof = 0.3 * period;
+    let arrival = propagate_kepler(&initial, tof, mu).unwrap();
+    let solutions = LambertSolver::new(mu, true, 0).solve(&initial.position(), &arrival.position(), tof).unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert!((&solutions[0].v1 - &initial.velocity()).iter().all(|v| v.abs() < 1e-6));
+    assert!((&solutions[0].v2 - &arrival.velocity()).iter().all(|v| v.abs() < 1e-6));
+
+    // 1 周回解のどちらかの枝が元の軌道と一致する
+    let tof = 1.3 * period;
+    let arrival = propagate_kepler(&initial, tof, mu).unwrap();
+    let solutions = LambertSolver::new(mu, true, 1).solve(&initial.position(), &arrival.position(), tof).unwrap();
+    assert_eq!(solutions.len(), 3);
+    assert!(solutions
+        .iter()
+        .filter(|s| s.revolutions == 1)
+        .any(|s| (&s.v1 - &initial.velocity()).iter().all(|v| v.abs() < 1e-6)));
+
+    // 逆行解は角運動量の向きが逆
+    let retrograde = LambertSolver::new(mu, false, 0).solve(&initial.position(), &arrival.position(), 0.3 * period).unwrap();
+    let h_prograde = Math::cross_product(&initial.position(), &initial.velocity());
+    let h_retrograde = Math::cross_product(&initial.position(), &retrograde[0].v1);
+    assert!(h_prograde.dot(&h_retrograde) < 0.0);
+}
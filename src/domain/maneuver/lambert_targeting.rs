@@ -0,0 +1,125 @@
+use ndarray::{Array1, Array2};
+
+use super::impulsive_maneuver::{ImpulsiveManeuver, ManeuverFrame};
+use super::lambert::{propagate_kepler, LambertSolver};
+use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::state_trait::StateVector;
+
+/// **ランベルト移行の結果 (ECI)**
+#[derive(Debug, Clone)]
+pub struct LambertTransfer {
+    pub departure: ImpulsiveManeuver,
+    pub arrival: ImpulsiveManeuver,
+    pub total_delta_v: f64,
+}
+
+/// **deputy を chief に会合させるランベルト移行の計算**
+/// 出発時の deputy の位置から到着時の chief の位置へ移り, 到着時に chief の速度に合わせる.
+/// 複数解 (多周回・枝) があるときは合計 Δv が最小のものを選ぶ
+#[derive(Debug, Clone)]
+pub struct LambertTargeting {
+    solver: LambertSolver,
+    mu: f64,
+}
+
+impl LambertTargeting {
+    pub fn new(mu: f64, prograde: bool, max_revolutions: usize) -> Self {
+        Self {
+            solver: LambertSolver::new(mu, prograde, max_revolutions),
+            mu,
+        }
+    }
+
+    /// **出発時刻 t_departure の deputy から, 移行時間 tof 後の chief へ**
+    pub fn transfer(
+        &self,
+        deputy_at_departure: &PositionVelocityStateEci,
+        chief_at_arrival: &PositionVelocityStateEci,
+        t_departure: f64,
+        tof: f64,
+    ) -> Result<LambertTransfer, &'static str> {
+        let solutions = self.solver.solve(&deputy_at_departure.position(), &chief_at_arrival.position(), tof)?;
+        solutions
+            .into_iter()
+            .map(|solution| {
+                let departure = ImpulsiveManeuver::new(t_departure, &solution.v1 - &deputy_at_departure.velocity(), ManeuverFrame::Eci);
+                let arrival = ImpulsiveManeuver::new(t_departure + tof, chief_at_arrival.velocity() - &solution.v2, ManeuverFrame::Eci);
+                let total_delta_v = departure.magnitude() + arrival.magnitude();
+                LambertTransfer { departure, arrival, total_delta_v }
+            })
+            .min_by(|a, b| a.total_delta_v.total_cmp(&b.total_delta_v))
+            .ok_or("ランベルト問題の解がありません。")
+    }
+
+    /// **ペア状態 (時刻 t0) から departure_delay 後に出発して tof 後に会合する移行**
+    /// 出発・到着までの chief と deputy はケプラー軌道で伝搬する
+    pub fn transfer_from_pair(
+        &self,
+        pair: &PositionVelocityPairStateEci,
+        t0: f64,
+        departure_delay: f64,
+        tof: f64,
+    ) -> Result<LambertTransfer, &'static str> {
+        let chief = PositionVelocityStateEci::form_from_array(pair.chief());
+        let deputy = PositionVelocityStateEci::form_from_array(pair.deputy());
+        let deputy_at_departure = propagate_kepler(&deputy, departure_delay, self.mu)?;
+        let chief_at_arrival = propagate_kepler(&chief, departure_delay + tof, self.mu)?;
+        self.transfer(&deputy_at_departure, &chief_at_arrival, t0 + departure_delay, tof)
+    }
+
+    /// **ポークチョップ図: 出発遅れ × 移行時間ごとの合計 Δv (解がなければ NaN)**
+    pub fn porkchop(
+        &self,
+        pair: &PositionVelocityPairStateEci,
+        t0: f64,
+        departure_delays: &Array1<f64>,
+        times_of_flight: &Array1<f64>,
+    ) -> Array2<f64> {
+        Array2::from_shape_fn((departure_delays.len(), times_of_flight.len()), |(i, j)| {
+            self.transfer_from_pair(pair, t0, departure_delays[i], times_of_flight[j])
+                .map_or(f64::NAN, |transfer| transfer.total_delta_v)
+        })
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use super::impulsive_maneuver::ImpulsiveState;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_lambert_rendezvous_and_porkchop() {
+    let mu = CONSTANTS.mu;
+    let a = CONSTANTS.radius + 500.0e3;
+    let v = (mu / a).sqrt();
+    // deputy は chief より 10 度後方の少し低い円軌道
+    let (angle, a_deputy): (f64, f64) = (-10.0_f64.to_radians(), a - 5.0e3);
+    let v_deputy = (mu / a_deputy).sqrt();
+    let pair = PositionVelocityPairStateEci::form_from_list(
+        [a, 0.0, 0.0, 0.0, v, 0.0],
+        [a_deputy * angle.cos(), a_deputy * angle.sin(), 0.0, -v_deputy * angle.sin(), v_deputy * angle.cos(), 0.0],
+    );
+    let targeting = LambertTargeting::new(mu, true, 1);
+
+    // 計算した Δv で deputy を伝搬すると到着時に chief と一致する
+    let (delay, tof) = (600.0, 3000.0);
+    let transfer = targeting.transfer_from_pair(&pair, 0.0, delay, tof).unwrap();
+    let deputy = PositionVelocityStateEci::form_from_array(pair.deputy());
+    let departed = propagate_kepler(&deputy, delay, mu).unwrap().apply_delta_v(&transfer.departure.delta_v);
+    let arrived = propagate_kepler(&departed, tof, mu).unwrap().apply_delta_v(&transfer.arrival.delta_v);
+    let chief = propagate_kepler(&PositionVelocityStateEci::form_from_array(pair.chief()), delay + tof, mu).unwrap();
+    let error = arrived.get_vector() - chief.get_vector();
+    assert!(error.iter().take(3).all(|v| v.abs() < 1e-2));
+    assert!(error.iter().skip(3).all(|v| v.abs() < 1e-5));
+
+    // ポークチョップ図の各点は個別に計算した値と一致する
+    let delays = arr1(&[0.0, 600.0, 1200.0]);
+    let tofs = arr1(&[2000.0, 3000.0]);
+    let porkchop = targeting.porkchop(&pair, 0.0, &delays, &tofs);
+    assert_eq!(porkchop.dim(), (3, 2));
+    assert!((porkchop[[1, 1]] - transfer.total_delta_v).abs() < 1e-9);
+    assert!(porkchop.iter().all(|dv| dv.is_finite() && *dv > 0.0));
+}
@@ -1 +1,4 @@
-pub mod formulations;
\ No newline at end of file
+pub mod formulations;
+pub mod riccati;
+pub mod qp_solver;
+pub mod linear_program;
\ No newline at end of file
@@ -1,5 +1,6 @@
-use ndarray::{Array1, Array2, arr1, arr2};
+use ndarray::{Array1, Array2, arr1, arr2, s};
 use ndarray_linalg::Inverse;
+use std::f64::consts::PI;
 
 pub struct Math {}
 
@@ -16,7 +17,7 @@ impl Math {
     }
 
     pub fn normalize(v: &Array1<f64>) -> Array1<f64> {
-        let norm = v[0].powi(2) + v[1].powi(2) + v[2].powi(2);
+        let norm = (v[0].powi(2) + v[1].powi(2) + v[2].powi(2)).sqrt();
         v / norm
     }
 
@@ -41,6 +42,32 @@ impl Math {
         Self::mat_eci2lvlh(position, velocity).into_owned().inv().expect("Matrix inversion failed")
     }
 
+    /// **太陽位置 (ECI, m) の低精度近似**
+    /// Astronomical Almanac の簡易式. 歳差・章動は無視する
+    pub fn sun_position_eci(julian_date: f64) -> Array1<f64> {
+        let t = (julian_date - 2451545.0) / 36525.0;
+        let deg = PI / 180.0;
+        let mean_anomaly = (357.5256 + 35999.049 * t) * deg;
+        let longitude = (282.940 * deg)
+            + mean_anomaly
+            + (6892.0 / 3600.0 * deg) * mean_anomaly.sin()
+            + (72.0 / 3600.0 * deg) * (2.0 * mean_anomaly).sin();
+        let distance = (149.619 - 2.499 * mean_anomaly.cos() - 0.021 * (2.0 * mean_anomaly).cos()) * 1e9;
+        let obliquity = 23.43929111 * deg;
+
+        arr1(&[
+            distance * longitude.cos(),
+            distance * longitude.sin() * obliquity.cos(),
+            distance * longitude.sin() * obliquity.sin(),
+        ])
+    }
+
+    /// **ECI ベクトルの赤経・赤緯 (rad)**
+    pub fn right_ascension_declination(v: &Array1<f64>) -> (f64, f64) {
+        let norm = v.dot(v).sqrt();
+        (v[1].atan2(v[0]), (v[2] / norm).asin())
+    }
+
     #[allow(non_snake_case)]
     pub fn pqw_to_eci_matrix(i_rad: f64, omega_rad: f64, Omega_rad: f64) -> Array2<f64> {
         let cos_Omega = Omega_rad.cos();
@@ -66,4 +93,60 @@ impl Math {
         )
         .unwrap()
     }
+
+    /// **3-2-1 (ヨー・ピッチ・ロール) オイラー角から機体→基準座標系の方向余弦行列**
+    pub fn dcm_from_euler_321(roll_rad: f64, pitch_rad: f64, yaw_rad: f64) -> Array2<f64> {
+        let (sr, cr) = roll_rad.sin_cos();
+        let (sp, cp) = pitch_rad.sin_cos();
+        let (sy, cy) = yaw_rad.sin_cos();
+
+        arr2(&[
+            [cy * cp, cy * sp * sr - sy * cr, cy * sp * cr + sy * sr],
+            [sy * cp, sy * sp * sr + cy * cr, sy * sp * cr - cy * sr],
+            [-sp, cp * sr, cp * cr],
+        ])
+    }
+
+    /// **クォータニオン (スカラー先頭) から機体→基準座標系の方向余弦行列**
+    pub fn dcm_from_quaternion(q: &[f64; 4]) -> Array2<f64> {
+        let norm = q.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let (q0, q1, q2, q3) = (q[0] / norm, q[1] / norm, q[2] / norm, q[3] / norm);
+
+        arr2(&[
+            [1.0 - 2.0 * (q2 * q2 + q3 * q3), 2.0 * (q1 * q2 - q0 * q3), 2.0 * (q1 * q3 + q0 * q2)],
+            [2.0 * (q1 * q2 + q0 * q3), 1.0 - 2.0 * (q1 * q1 + q3 * q3), 2.0 * (q2 * q3 - q0 * q1)],
+            [2.0 * (q1 * q3 - q0 * q2), 2.0 * (q2 * q3 + q0 * q1), 1.0 - 2.0 * (q1 * q1 + q2 * q2)],
+        ])
+    }
+
+    /// **行列指数関数 exp(M) (スケーリング・二乗法 + テイラー展開)**
+    pub fn expm(matrix: &Array2<f64>) -> Array2<f64> {
+        let norm = matrix.iter().map(|v| v.abs()).fold(0.0, f64::max) * matrix.nrows() as f64;
+        let squarings = if norm > 0.5 { (norm / 0.5).log2().ceil() as i32 } else { 0 };
+        let scaled = matrix / 2.0_f64.powi(squarings);
+
+        let mut result = Array2::<f64>::eye(matrix.nrows());
+        let mut term = Array2::<f64>::eye(matrix.nrows());
+        for k in 1..20 {
+            term = term.dot(&scaled) / k as f64;
+            result += &term;
+        }
+        for _ in 0..squarings {
+            result = result.dot(&result);
+        }
+        result
+    }
+
+    /// **連続時間線形系 (A, B) の零次ホールド離散化 (A_d, B_d)**
+    pub fn discretize_zoh(a_matrix: &Array2<f64>, b_matrix: &Array2<f64>, dt: f64) -> (Array2<f64>, Array2<f64>) {
+        let (n, m) = (a_matrix.nrows(), b_matrix.ncols());
+        let mut augmented = Array2::<f64>::zeros((n + m, n + m));
+        augmented.slice_mut(s![0..n, 0..n]).assign(&(a_matrix * dt));
+        augmented.slice_mut(s![0..n, n..n + m]).assign(&(b_matrix * dt));
+        let exponential = Self::expm(&augmented);
+        (
+            exponential.slice(s![0..n, 0..n]).to_owned(),
+            exponential.slice(s![0..n, n..n + m]).to_owned(),
+        )
+    }
 }
@@ -0,0 +1,148 @@
+use ndarray::{Array1, Array2, Axis, s};
+
+/// 0 とみなす閾値
+const TOLERANCE: f64 = 1e-9;
+/// 単体法の反復回数の上限
+const MAX_PIVOTS: usize = 10000;
+
+/// **線形計画問題のソルバ (二段階単体法, ブランドの規則)**
+pub struct LinearProgram;
+
+impl LinearProgram {
+    /// **minimize c^T x  subject to  A x = b, x ≥ 0**
+    pub fn minimize(c: &Array1<f64>, a_eq: &Array2<f64>, b_eq: &Array1<f64>) -> Result<Array1<f64>, &'static str> {
+        let (m, n) = a_eq.dim();
+        if c.len() != n || b_eq.len() != m {
+            return Err("線形計画問題の次元が一致しません。");
+        }
+
+        // 各行を最大要素で正規化し, 右辺を非負にしてから人工変数を加える
+        let rhs = n + m;
+        let mut tableau = Array2::<f64>::zeros((m, n + m + 1));
+        for i in 0..m {
+            let scale = a_eq.row(i).iter().fold(0.0_f64, |acc, v| acc.max(v.abs())).max(f64::MIN_POSITIVE);
+            let sign = if b_eq[i] < 0.0 { -1.0 } else { 1.0 };
+            for j in 0..n {
+                tableau[[i, j]] = sign * a_eq[[i, j]] / scale;
+            }
+            tableau[[i, n + i]] = 1.0;
+            tableau[[i, rhs]] = sign * b_eq[i] / scale;
+        }
+        let mut basis: Vec<usize> = (n..n + m).collect();
+
+        // 第 1 段階: 人工変数の和を最小化して実行可能基底を求める
+        let mut phase1_cost = Array1::<f64>::zeros(n + m);
+        phase1_cost.slice_mut(s![n..]).fill(1.0);
+        run_simplex(&mut tableau, &mut basis, &phase1_cost, n + m)?;
+        let infeasibility: f64 = basis.iter().enumerate().filter(|(_, &j)| j >= n).map(|(i, _)| tableau[[i, rhs]]).sum();
+        let b_scale = tableau.column(rhs).iter().fold(1.0_f64, |acc, v| acc.max(v.abs()));
+        if infeasibility > 1e-7 * b_scale {
+            return Err("線形計画問題が実行不可能です。");
+        }
+
+        // 基底に残った人工変数を追い出す (追い出せない行は冗長なので削除)
+        let mut i = 0;
+        while i < basis.len() {
+            if basis[i] >= n {
+                match (0..n).find(|&j| tableau[[i, j]].abs() > TOLERANCE) {
+                    Some(j) => pivot(&mut tableau, &mut basis, i, j),
+                    None => {
+                        let keep: Vec<usize> = (0..basis.len()).filter(|&k| k != i).collect();
+                        tableau = tableau.select(Axis(0), &keep);
+                        basis.remove(i);
+                        continue;
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        // 第 2 段階: 元の目的関数を最小化する (人工変数は基底に入れない)
+        let mut phase2_cost = Array1::<f64>::zeros(n + m);
+        phase2_cost.slice_mut(s![..n]).assign(c);
+        run_simplex(&mut tableau, &mut basis, &phase2_cost, n)?;
+
+        let mut x = Array1::<f64>::zeros(n);
+        for (i, &j) in basis.iter().enumerate() {
+            if j < n {
+                x[j] = tableau[[i, rhs]];
+            }
+        }
+        Ok(x)
+    }
+}
+
+/// **列 allowed 未満の変数だけを基底に入れて単体法を回す**
+fn run_simplex(tableau: &mut Array2<f64>, basis: &mut [usize], cost: &Array1<f64>, allowed: usize) -> Result<(), &'static str> {
+    let rhs = tableau.ncols() - 1;
+    for _ in 0..MAX_PIVOTS {
+        // 被約費用が負の最小添字の列を入れる
+        let entering = (0..allowed).find(|&j| {
+            if basis.contains(&j) {
+                return false;
+            }
+            let reduced = cost[j] - basis.iter().enumerate().map(|(i, &b)| cost[b] * tableau[[i, j]]).sum::<f64>();
+            reduced < -TOLERANCE
+        });
+        let Some(column) = entering else {
+            return Ok(());
+        };
+
+        // 比率判定 (同率なら基底の添字が小さい行)
+        let mut leaving: Option<(usize, f64)> = None;
+        for i in 0..basis.len() {
+            if tableau[[i, column]] > TOLERANCE {
+                let ratio = tableau[[i, rhs]] / tableau[[i, column]];
+                let better = match leaving {
+                    None => true,
+                    Some((r, best)) => ratio < best - TOLERANCE || (ratio <= best + TOLERANCE && basis[i] < basis[r]),
+                };
+                if better {
+                    leaving = Some((i, ratio));
+                }
+            }
+        }
+        let Some((row, _)) = leaving else {
+            return Err("線形計画問題が非有界です。");
+        };
+        pivot(tableau, basis, row, column);
+    }
+    Err("単体法が反復回数の上限に達しました。")
+}
+
+fn pivot(tableau: &mut Array2<f64>, basis: &mut [usize], row: usize, column: usize) {
+    let pivot_value = tableau[[row, column]];
+    tableau.row_mut(row).mapv_inplace(|v| v / pivot_value);
+    let pivot_row = tableau.row(row).to_owned();
+    for i in 0..tableau.nrows() {
+        if i != row {
+            let factor = tableau[[i, column]];
+            if factor != 0.0 {
+                tableau.row_mut(i).scaled_add(-factor, &pivot_row);
+            }
+        }
+    }
+    basis[row] = column;
+}
+
+#[cfg(test)]
+use ndarray::{arr1, arr2};
+
+#[test]
+fn test_linear_program() {
+    // min -x - 2y  s.t. x + y + s1 = 4, x + 3y + s2 = 6  →  (x, y) = (3, 1)
+    let c = arr1(&[-1.0, -2.0, 0.0, 0.0]);
+    let a = arr2(&[[1.0, 1.0, 1.0, 0.0], [1.0, 3.0, 0.0, 1.0]]);
+    let x = LinearProgram::minimize(&c, &a, &arr1(&[4.0, 6.0])).unwrap();
+    assert!((x[0] - 3.0).abs() < 1e-9 && (x[1] - 1.0).abs() < 1e-9);
+
+    // |x| の最小化を x = p - q で表す (右辺が負, 冗長な行を含む)
+    let c = arr1(&[1.0, 1.0]);
+    let a = arr2(&[[1.0, -1.0], [2.0, -2.0]]);
+    let x = LinearProgram::minimize(&c, &a, &arr1(&[-2.0, -4.0])).unwrap();
+    assert!((x[0]).abs() < 1e-9 && (x[1] - 2.0).abs() < 1e-9);
+
+    // 実行不可能
+    let a = arr2(&[[1.0, 1.0]]);
+    assert!(LinearProgram::minimize(&c, &a, &arr1(&[-1.0])).is_err());
+}
@@ -0,0 +1,164 @@
+use ndarray::{Array1, Array2, concatenate, s, Axis};
+use ndarray_linalg::Inverse;
+
+/// **QP の制約 (行列 M に対して M x が集合に入る)**
+#[derive(Debug, Clone)]
+pub enum QpConstraint {
+    /// lower ≤ M x ≤ upper (等式は lower = upper, 片側は ±inf)
+    Linear { matrix: Array2<f64>, lower: Array1<f64>, upper: Array1<f64> },
+    /// |M x| ≤ radius (2 ノルム)
+    NormBall { matrix: Array2<f64>, radius: f64 },
+}
+
+impl QpConstraint {
+    fn matrix(&self) -> &Array2<f64> {
+        match self {
+            QpConstraint::Linear { matrix, .. } => matrix,
+            QpConstraint::NormBall { matrix, .. } => matrix,
+        }
+    }
+
+    fn project(&self, v: &Array1<f64>) -> Array1<f64> {
+        match self {
+            QpConstraint::Linear { lower, upper, .. } => {
+                Array1::from_iter(v.iter().zip(lower.iter().zip(upper.iter())).map(|(x, (l, u))| x.clamp(*l, *u)))
+            }
+            QpConstraint::NormBall { radius, .. } => {
+                let norm = v.dot(v).sqrt();
+                if norm > *radius { v * (*radius / norm) } else { v.clone() }
+            }
+        }
+    }
+}
+
+/// rho を調整する間隔 (反復回数)
+const RHO_UPDATE_INTERVAL: usize = 25;
+
+/// **QP の解**
+#[derive(Debug, Clone)]
+pub struct QpSolution {
+    pub x: Array1<f64>,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// **ADMM による密な凸 QP ソルバ**
+/// minimize 1/2 x^T P x + q^T x  subject to  M_i x ∈ C_i (OSQP と同じ分割)
+#[derive(Debug, Clone)]
+pub struct AdmmQpSolver {
+    pub rho: f64,
+    pub sigma: f64,
+    pub alpha: f64,  // 過緩和係数
+    pub max_iterations: usize,
+    pub tolerance: f64,
+}
+
+impl AdmmQpSolver {
+    pub fn new(rho: f64, max_iterations: usize, tolerance: f64) -> Self {
+        Self {
+            rho,
+            sigma: 1e-6,
+            alpha: 1.6,
+            max_iterations,
+            tolerance,
+        }
+    }
+
+    pub fn solve(
+        &self,
+        p_matrix: &Array2<f64>,
+        q_vector: &Array1<f64>,
+        constraints: &[QpConstraint],
+        warm_start: Option<&Array1<f64>>,
+    ) -> Result<QpSolution, &'static str> {
+        let n = q_vector.len();
+        let views: Vec<_> = constraints.iter().map(|c| c.matrix().view()).collect();
+        let a_matrix = if views.is_empty() { Array2::<f64>::zeros((0, n)) } else { concatenate(Axis(0), &views).map_err(|_| "制約行列の列数が一致しません。")? };
+        let offsets: Vec<usize> = constraints
+            .iter()
+            .scan(0, |offset, c| {
+                let start = *offset;
+                *offset += c.matrix().nrows();
+                Some(start)
+            })
+            .collect();
+
+        let factorize = |rho: f64| {
+            let kkt = p_matrix + &(Array2::<f64>::eye(n) * self.sigma) + &(a_matrix.t().dot(&a_matrix) * rho);
+            kkt.inv().map_err(|_| "KKT 行列が正則ではありません。")
+        };
+        let mut rho = self.rho;
+        let mut kkt_inv = factorize(rho)?;
+
+        let project = |v: &Array1<f64>| {
+            let mut projected = v.clone();
+            for (constraint, &start) in constraints.iter().zip(offsets.iter()) {
+                let rows = constraint.matrix().nrows();
+                let block = constraint.project(&v.slice(s![start..start + rows]).to_owned());
+                projected.slice_mut(s![start..start + rows]).assign(&block);
+            }
+            projected
+        };
+        let max_abs = |v: &Array1<f64>| v.iter().fold(0.0_f64, |m, x| m.max(x.abs()));
+
+        let mut x = warm_start.cloned().unwrap_or_else(|| Array1::zeros(n));
+        let mut z = project(&a_matrix.dot(&x));
+        let mut y = Array1::<f64>::zeros(a_matrix.nrows());
+
+        for iteration in 1..=self.max_iterations {
+            let x_tilde = kkt_inv.dot(&(&x * self.sigma - q_vector + a_matrix.t().dot(&(&z * rho - &y))));
+            let z_tilde = a_matrix.dot(&x_tilde);
+            x = &x_tilde * self.alpha + &x * (1.0 - self.alpha);
+            let z_relaxed = &z_tilde * self.alpha + &z * (1.0 - self.alpha);
+            let z_next = project(&(&z_relaxed + &y / rho));
+            y = &y + &((&z_relaxed - &z_next) * rho);
+            z = z_next;
+
+            // 残差は OSQP と同じく各項の大きさに対する相対値で判定する
+            let ax = a_matrix.dot(&x);
+            let px = p_matrix.dot(&x);
+            let aty = a_matrix.t().dot(&y);
+            let primal = max_abs(&(&ax - &z));
+            let dual = max_abs(&(&px + q_vector + &aty));
+            let primal_scale = max_abs(&ax).max(max_abs(&z));
+            let dual_scale = max_abs(&px).max(max_abs(&aty)).max(max_abs(q_vector));
+            if primal <= self.tolerance * (1.0 + primal_scale) && dual <= self.tolerance * (1.0 + dual_scale) {
+                return Ok(QpSolution { x, iterations: iteration, converged: true });
+            }
+
+            // 主・双対残差の釣り合いで rho を調整する
+            if iteration % RHO_UPDATE_INTERVAL == 0 && primal_scale > 0.0 && dual_scale > 0.0 {
+                let ratio = ((primal / primal_scale) / (dual / dual_scale).max(1e-30)).sqrt();
+                if !(0.2..=5.0).contains(&ratio) {
+                    rho = (rho * ratio).clamp(1e-6, 1e6);
+                    kkt_inv = factorize(rho)?;
+                }
+            }
+        }
+
+        Ok(QpSolution { x, iterations: self.max_iterations, converged: false })
+    }
+}
+
+#[cfg(test)]
+use ndarray::{arr1, arr2};
+
+#[test]
+fn test_admm_qp_box_and_ball() {
+    let solver = AdmmQpSolver::new(1.0, 5000, 1e-8);
+    let p = arr2(&[[2.0, 0.0], [0.0, 2.0]]);
+    let q = arr1(&[-4.0, -2.0]);  // 制約なしの最適解は (2, 1)
+
+    // 0 ≤ x ≤ 1
+    let boxed = QpConstraint::Linear { matrix: Array2::eye(2), lower: arr1(&[0.0, 0.0]), upper: arr1(&[1.0, 1.0]) };
+    let solution = solver.solve(&p, &q, &[boxed], None).unwrap();
+    assert!(solution.converged);
+    assert!((&solution.x - &arr1(&[1.0, 1.0])).iter().all(|v| v.abs() < 1e-6));
+
+    // |x| ≤ 1 なら (2, 1) 方向の単位ベクトル
+    let ball = QpConstraint::NormBall { matrix: Array2::eye(2), radius: 1.0 };
+    let solution = solver.solve(&p, &q, &[ball], None).unwrap();
+    let expected = arr1(&[2.0, 1.0]) / 5.0_f64.sqrt();
+    assert!(solution.converged);
+    assert!((&solution.x - &expected).iter().all(|v| v.abs() < 1e-6));
+}
@@ -0,0 +1,78 @@
+use ndarray::{Array2, concatenate, s, Axis};
+use ndarray_linalg::Inverse;
+
+pub struct Riccati {}
+
+impl Riccati {
+    /// **連続時間代数リカッチ方程式 A^T P + P A - P B R^{-1} B^T P + Q = 0 の安定化解**
+    /// ハミルトン行列 H = [[A, -B R^{-1} B^T], [-Q, -A^T]] の行列符号関数 W = sign(H) を
+    /// ニュートン反復で求め, 安定部分空間の条件 (W + I) [I; P] = 0 を最小二乗で解く
+    pub fn solve_continuous(
+        a_matrix: &Array2<f64>,
+        b_matrix: &Array2<f64>,
+        q_matrix: &Array2<f64>,
+        r_matrix: &Array2<f64>,
+    ) -> Result<Array2<f64>, &'static str> {
+        let n = a_matrix.nrows();
+        let r_inv = r_matrix.inv().map_err(|_| "R が正則ではありません。")?;
+        let g_matrix = b_matrix.dot(&r_inv).dot(&b_matrix.t());
+
+        let top = concatenate![Axis(1), a_matrix.view(), (-&g_matrix).view()];
+        let bottom = concatenate![Axis(1), (-q_matrix).view(), (-&a_matrix.t()).view()];
+        let mut w = concatenate![Axis(0), top.view(), bottom.view()];
+
+        let mut converged = false;
+        for _ in 0..100 {
+            let w_inv = w.inv().map_err(|_| "ハミルトン行列が虚軸上に固有値を持ちます。")?;
+            // 収束を速めるためのスケーリング
+            let scale = (frobenius_norm(&w_inv) / frobenius_norm(&w)).sqrt();
+            let w_next = (&w * scale + &w_inv / scale) * 0.5;
+            let change = frobenius_norm(&(&w_next - &w)) / frobenius_norm(&w_next);
+            w = w_next;
+            if change < 1e-12 {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err("行列符号関数の反復が収束しませんでした。");
+        }
+
+        let identity = Array2::<f64>::eye(n);
+        let m_matrix = concatenate![
+            Axis(0),
+            w.slice(s![0..n, n..2 * n]),
+            (&w.slice(s![n..2 * n, n..2 * n]) + &identity).view()
+        ];
+        let n_matrix = -concatenate![
+            Axis(0),
+            (&w.slice(s![0..n, 0..n]) + &identity).view(),
+            w.slice(s![n..2 * n, 0..n])
+        ];
+        let normal = m_matrix.t().dot(&m_matrix).inv().map_err(|_| "安定化解が存在しません。")?;
+        let p = normal.dot(&m_matrix.t()).dot(&n_matrix);
+
+        Ok((&p + &p.t()) * 0.5)
+    }
+}
+
+fn frobenius_norm(matrix: &Array2<f64>) -> f64 {
+    matrix.iter().map(|v| v * v).sum::<f64>().sqrt()
+}
+
+#[cfg(test)]
+use ndarray::arr2;
+
+#[test]
+fn test_continuous_riccati_scalar_and_double_integrator() {
+    // スカラー: -P^2 + 1 = 0 (A = 0, B = Q = R = 1) → P = 1
+    let p = Riccati::solve_continuous(&arr2(&[[0.0]]), &arr2(&[[1.0]]), &arr2(&[[1.0]]), &arr2(&[[1.0]])).unwrap();
+    assert!((p[[0, 0]] - 1.0).abs() < 1e-9);
+
+    // 二重積分器: P = [[sqrt(3), 1], [1, sqrt(3)]]
+    let a = arr2(&[[0.0, 1.0], [0.0, 0.0]]);
+    let b = arr2(&[[0.0], [1.0]]);
+    let p = Riccati::solve_continuous(&a, &b, &Array2::eye(2), &arr2(&[[1.0]])).unwrap();
+    let expected = arr2(&[[3.0_f64.sqrt(), 1.0], [1.0, 3.0_f64.sqrt()]]);
+    assert!((&p - &expected).iter().all(|v| v.abs() < 1e-9));
+}
@@ -3,5 +3,7 @@ pub mod position_velocity_state_eci;
 pub mod position_velocity_covariance_state_lvlh;
 pub mod relative_position_velocity_state_lvlh;
 pub mod position_velocity_pair_state_eci;
+pub mod position_velocity_mass_pair_state_eci;
 pub mod orbital_elements;
-pub mod state_converter;
\ No newline at end of file
+pub mod state_converter;
+pub mod relative_orbital_elements;
\ No newline at end of file
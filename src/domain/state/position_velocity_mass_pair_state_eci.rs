@@ -0,0 +1,180 @@
+use ndarray::{Array1, Array2, arr1, concatenate, s, Axis};
+use std::ops::{Add, Sub, Mul, Div};
+
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::logger::loggable_trait::Loggable;
+use super::position_velocity_covariance_state_lvlh::PositionVelocityCovarianceStateLvlh;
+use super::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use super::position_velocity_state_eci::PositionVelocityStateEci;
+use super::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use super::state_converter::StateConverter;
+
+/// **推進剤質量と累積 Δv を含む 2 衛星の状態量 (ECI)**
+#[derive(Debug, Clone)]
+pub struct PositionVelocityMassPairStateEci {
+    state: Array1<f64>, // [chief_px, chief_py, chief_pz, chief_vx, chief_vy, chief_vz, chief_propellant, chief_dv,
+                        //  deputy_px, deputy_py, deputy_pz, deputy_vx, deputy_vy, deputy_vz, deputy_propellant, deputy_dv]
+}
+
+impl PositionVelocityMassPairStateEci {
+    pub fn form_from_list(chief: [f64; 8], deputy: [f64; 8]) -> Self {
+        let state = arr1(
+            &[chief[0], chief[1], chief[2], chief[3], chief[4], chief[5], chief[6], chief[7],
+             deputy[0], deputy[1], deputy[2], deputy[3], deputy[4], deputy[5], deputy[6], deputy[7]]
+            );
+        Self { state }
+    }
+
+    /// **位置・速度の状態に推進剤質量を付け加える (累積 Δv は 0)**
+    pub fn form_from_pair(pair: &PositionVelocityPairStateEci, propellant_chief: f64, propellant_deputy: f64) -> Self {
+        let state = concatenate![
+            Axis(0),
+            pair.chief(), arr1(&[propellant_chief, 0.0]),
+            pair.deputy(), arr1(&[propellant_deputy, 0.0])
+        ];
+        Self { state }
+    }
+
+    pub fn chief(&self) -> Array1<f64> {
+        self.state.slice(s![0..6]).to_owned()
+    }
+
+    pub fn deputy(&self) -> Array1<f64> {
+        self.state.slice(s![8..14]).to_owned()
+    }
+
+    pub fn propellant_chief(&self) -> f64 {
+        self.state[6]
+    }
+
+    pub fn propellant_deputy(&self) -> f64 {
+        self.state[14]
+    }
+
+    pub fn delta_v_chief(&self) -> f64 {
+        self.state[7]
+    }
+
+    pub fn delta_v_deputy(&self) -> f64 {
+        self.state[15]
+    }
+}
+
+impl StateVector for PositionVelocityMassPairStateEci {
+    fn get_vector(&self) -> &Array1<f64> {
+        &self.state
+    }
+
+    fn form_from_array(vec: Array1<f64>) -> Self {
+        Self { state: vec }
+    }
+}
+
+impl StateConverter<PositionVelocityPairStateEci> for PositionVelocityMassPairStateEci {
+    fn convert(&self) -> PositionVelocityPairStateEci {
+        PositionVelocityPairStateEci::form_from_array(concatenate![Axis(0), self.chief(), self.deputy()])
+    }
+}
+
+impl StateConverter<Vec<PositionVelocityStateEci>> for PositionVelocityMassPairStateEci {
+    fn convert(&self) -> Vec<PositionVelocityStateEci> {
+        vec![
+            PositionVelocityStateEci::form_from_array(self.chief()),
+            PositionVelocityStateEci::form_from_array(self.deputy()),
+        ]
+    }
+}
+
+impl StateConverter<PositionVelocityStateLvlh> for PositionVelocityMassPairStateEci {
+    fn convert(&self) -> PositionVelocityStateLvlh {
+        let pair: PositionVelocityPairStateEci = self.convert();
+        pair.convert()
+    }
+}
+
+impl StateConverter<PositionVelocityCovarianceStateLvlh> for PositionVelocityMassPairStateEci {
+    fn convert(&self) -> PositionVelocityCovarianceStateLvlh {
+        let pair: PositionVelocityPairStateEci = self.convert();
+        pair.convert()
+    }
+}
+
+impl Loggable for PositionVelocityMassPairStateEci {
+    fn output_log(&self) -> String {
+        let state_vec: PositionVelocityStateLvlh = self.convert();
+        let mut state_str: Vec<String> = state_vec.get_vector().iter().map(|v| v.to_string()).collect();
+        for value in [self.propellant_chief(), self.propellant_deputy(), self.delta_v_chief(), self.delta_v_deputy()] {
+            state_str.push(value.to_string());
+        }
+        state_str.join(",")
+    }
+
+    fn header(&self) -> String {
+        "p0,p1,p2,v0,v1,v2,propellant_chief,propellant_deputy,dv_chief,dv_deputy".to_string()
+    }
+}
+
+/// **演算子のオーバーロード**
+impl Add for PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn add(self, rhs: PositionVelocityMassPairStateEci) -> PositionVelocityMassPairStateEci {
+        self.add_vec(&rhs)
+    }
+}
+
+impl Add for &PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn add(self, rhs: &PositionVelocityMassPairStateEci) -> PositionVelocityMassPairStateEci {
+        self.add_vec(rhs)
+    }
+}
+
+impl Sub for PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn sub(self, rhs: PositionVelocityMassPairStateEci) -> PositionVelocityMassPairStateEci {
+        self.sub_vec(&rhs)
+    }
+}
+
+impl Sub for &PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn sub(self, rhs: &PositionVelocityMassPairStateEci) -> PositionVelocityMassPairStateEci {
+        self.sub_vec(rhs)
+    }
+}
+
+impl Mul<f64> for PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn mul(self, scalar: f64) -> PositionVelocityMassPairStateEci {
+        self.mul_scalar(scalar)
+    }
+}
+
+impl Mul<f64> for &PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn mul(self, scalar: f64) -> PositionVelocityMassPairStateEci {
+        self.mul_scalar(scalar)
+    }
+}
+
+impl Div<f64> for PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn div(self, scalar: f64) -> PositionVelocityMassPairStateEci {
+        self.div_scalar(scalar)
+    }
+}
+
+impl Div<f64> for &PositionVelocityMassPairStateEci {
+    type Output = PositionVelocityMassPairStateEci;
+    fn div(self, scalar: f64) -> PositionVelocityMassPairStateEci {
+        self.div_scalar(scalar)
+    }
+}
+
+impl Mul<PositionVelocityMassPairStateEci> for Array2<f64> {
+    type Output = PositionVelocityMassPairStateEci;
+    fn mul(self, rhs: PositionVelocityMassPairStateEci) -> PositionVelocityMassPairStateEci {
+        let result = self.dot(rhs.get_vector());
+        PositionVelocityMassPairStateEci::form_from_array(result)
+    }
+}
@@ -0,0 +1,138 @@
+use ndarray::{Array1, arr1};
+use std::f64::consts::PI;
+
+use super::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use super::position_velocity_state_eci::PositionVelocityStateEci;
+use super::state_trait::StateVector;
+use crate::domain::math::formulations::Math;
+use crate::infrastructure::logger::loggable_trait::Loggable;
+
+/// **準非特異な相対軌道要素 (D'Amico)**
+/// [δa, δλ, δex, δey, δix, δiy] (無次元). δa = (a_d - a_c) / a_c,
+/// δλ = (u_d - u_c) + (Ω_d - Ω_c) cos i_c, δe = e_d - e_c, δix = i_d - i_c, δiy = (Ω_d - Ω_c) sin i_c.
+/// u は平均引数緯度 (ω + M). 赤道軌道では昇交点が定まらないので扱えない
+#[derive(Debug, Clone)]
+pub struct RelativeOrbitalElements {
+    state: Array1<f64>,
+}
+
+impl RelativeOrbitalElements {
+    pub fn form_from_list(roe: [f64; 6]) -> Self {
+        Self { state: arr1(&roe) }
+    }
+
+    /// **準非特異な軌道要素 [a, ex, ey, i, Ω, u] (u は平均引数緯度)**
+    #[allow(non_snake_case)]
+    pub fn nonsingular_elements(state: &PositionVelocityStateEci, mu: f64) -> [f64; 6] {
+        let r = state.position();
+        let v = state.velocity();
+        let r_norm = r.dot(&r).sqrt();
+        let h = Math::cross_product(&r, &v);
+        let h_hat = Math::normalize(&h);
+        let n_hat = Math::normalize(&arr1(&[-h[1], h[0], 0.0]));
+        let m_hat = Math::cross_product(&h_hat, &n_hat);
+
+        let a = 1.0 / (2.0 / r_norm - v.dot(&v) / mu);
+        let e_vec = (&r * (v.dot(&v) - mu / r_norm) - &v * r.dot(&v)) / mu;
+        let e = e_vec.dot(&e_vec).sqrt();
+        let i_rad = h_hat[2].clamp(-1.0, 1.0).acos();
+        let Omega_rad = n_hat[1].atan2(n_hat[0]);
+
+        // 真の引数緯度から真近点角と平均近点角の差を引いて平均引数緯度にする
+        let u_true = r.dot(&m_hat).atan2(r.dot(&n_hat));
+        let eccentric_anomaly = (r.dot(&v) / (mu * a).sqrt()).atan2(1.0 - r_norm / a);
+        let true_anomaly = ((1.0 - e * e).sqrt() * eccentric_anomaly.sin()).atan2(eccentric_anomaly.cos() - e);
+        let mean_anomaly = eccentric_anomaly - e * eccentric_anomaly.sin();
+
+        [a, e_vec.dot(&n_hat), e_vec.dot(&m_hat), i_rad, Omega_rad, u_true + mean_anomaly - true_anomaly]
+    }
+
+    /// **chief と deputy の ECI 状態から計算**
+    pub fn from_pair(pair: &PositionVelocityPairStateEci, mu: f64) -> Self {
+        let chief = Self::nonsingular_elements(&PositionVelocityStateEci::form_from_array(pair.chief()), mu);
+        let deputy = Self::nonsingular_elements(&PositionVelocityStateEci::form_from_array(pair.deputy()), mu);
+        let d_raan = wrap_angle(deputy[4] - chief[4]);
+        Self::form_from_list([
+            (deputy[0] - chief[0]) / chief[0],
+            wrap_angle(deputy[5] - chief[5] + d_raan * chief[3].cos()),
+            deputy[1] - chief[1],
+            deputy[2] - chief[2],
+            deputy[3] - chief[3],
+            d_raan * chief[3].sin(),
+        ])
+    }
+
+    /// **目標との差 (δλ は (-π, π] に折り返す)**
+    pub fn error_from(&self, target: &RelativeOrbitalElements) -> Array1<f64> {
+        let mut error = self.get_vector() - target.get_vector();
+        error[1] = wrap_angle(error[1]);
+        error
+    }
+}
+
+/// **角度を (-π, π] に折り返す**
+fn wrap_angle(angle: f64) -> f64 {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped == -PI { PI } else { wrapped }
+}
+
+impl StateVector for RelativeOrbitalElements {
+    fn get_vector(&self) -> &Array1<f64> {
+        &self.state
+    }
+
+    fn form_from_array(vec: Array1<f64>) -> Self {
+        Self { state: vec }
+    }
+}
+
+impl Loggable for RelativeOrbitalElements {
+    fn output_log(&self) -> String {
+        let state_str: Vec<String> = self.get_vector().iter().map(|v| v.to_string()).collect();
+        state_str.join(",")
+    }
+
+    fn header(&self) -> String {
+        "da,dlambda,dex,dey,dix,diy".to_string()
+    }
+}
+
+#[cfg(test)]
+use super::orbital_elements::OrbitalElements;
+#[cfg(test)]
+use super::state_converter::StateConverter;
+#[cfg(test)]
+use crate::infrastructure::settings::constants::CONSTANTS;
+
+#[test]
+fn test_relative_orbital_elements_from_pair() {
+    let a = CONSTANTS.radius + 500.0e3;
+    let (i, raan, omega, nu) = (1.0_f64, 0.3, 0.5_f64, 0.2_f64);
+    let (e_chief, e_deputy) = (0.001, 0.0012);
+    let chief = OrbitalElements::form_from_elements(a, e_chief, i, omega, raan, nu).unwrap();
+    let deputy = OrbitalElements::form_from_elements(a + 100.0, e_deputy, i + 1e-5, omega, raan + 2e-5, nu).unwrap();
+    let pair: PositionVelocityPairStateEci = vec![chief, deputy].convert();
+    let roe = RelativeOrbitalElements::from_pair(&pair, CONSTANTS.mu);
+
+    // ω, ν が同じなので δλ は平均近点角の差と昇交点の差による分
+    let mean_anomaly = |e: f64| {
+        let eccentric_anomaly = 2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * (nu / 2.0).tan()).atan();
+        eccentric_anomaly - e * eccentric_anomaly.sin()
+    };
+    let expected = [
+        100.0 / a,
+        mean_anomaly(e_deputy) - mean_anomaly(e_chief) + 2e-5 * i.cos(),
+        (e_deputy - e_chief) * omega.cos(),
+        (e_deputy - e_chief) * omega.sin(),
+        1e-5,
+        2e-5 * i.sin(),
+    ];
+    for (k, value) in expected.iter().enumerate() {
+        assert!((roe.get_vector()[k] - value).abs() < 1e-8);
+    }
+
+    // 同じ状態同士では 0
+    let same = PositionVelocityPairStateEci::form_from_array(ndarray::concatenate![ndarray::Axis(0), pair.chief(), pair.chief()]);
+    let roe = RelativeOrbitalElements::from_pair(&same, CONSTANTS.mu);
+    assert!(roe.get_vector().iter().all(|v| v.abs() < 1e-12));
+}
@@ -5,7 +5,7 @@ use crate::domain::state::position_velocity_covariance_state_lvlh::PositionVeloc
 use crate::domain::state::orbital_elements::OrbitalElements;
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::math::formulations::Math;
-use ndarray::{arr1, concatenate, s, Axis};
+use ndarray::{arr1, concatenate, s, Array2, Axis};
 use std::f64::consts::PI;
 use crate::infrastructure::settings::constants::CONSTANTS;
 
@@ -121,6 +121,15 @@ impl StateConverter<PositionVelocityStateLvlh> for PositionVelocityPairStateEci
     }
 }
 
+/// **相対状態量を平均, 共分散を 0 とした共分散付きの状態量**
+impl StateConverter<PositionVelocityCovarianceStateLvlh> for PositionVelocityPairStateEci {
+    fn convert(&self) -> PositionVelocityCovarianceStateLvlh {
+        let pos_vec_lvlh: PositionVelocityStateLvlh = self.convert();
+        let p = Array2::<f64>::zeros((6, 6));
+        PositionVelocityCovarianceStateLvlh::from_from_states(&pos_vec_lvlh, &pos_vec_lvlh, p)
+    }
+}
+
 impl StateConverter<Vec<PositionVelocityStateEci>> for PositionVelocityPairStateEci {
     fn convert(&self) -> Vec<PositionVelocityStateEci> {
         let chief = PositionVelocityStateEci::form_from_array(self.chief());
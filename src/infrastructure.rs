@@ -1,3 +1,4 @@
 pub mod factory;
 pub mod settings;
-pub mod logger;
\ No newline at end of file
+pub mod logger;
+pub mod reader;
\ No newline at end of file
@@ -1,3 +1,11 @@
 pub mod initialization_wrapper;
 pub mod simulator_factory;
-pub mod mode_scheduler_factory;
\ No newline at end of file
+pub mod mode_scheduler_factory;
+pub mod actuator_factory;
+pub mod baseline_controller_factory;
+pub mod controller_wrapper_factory;
+pub mod lqr_controller_factory;
+pub mod mpc_controller_factory;
+pub mod roe_lyapunov_controller_factory;
+pub mod ilqr_controller_factory;
+pub mod differential_drag_controller_factory;
\ No newline at end of file
@@ -0,0 +1,22 @@
+use crate::domain::actuator::thruster_actuator::ThrusterActuator;
+use crate::domain::force::force_trait::Force;
+use crate::infrastructure::settings::actuator_settings::ThrusterActuatorConfig;
+
+pub struct ActuatorFactory;
+
+impl ActuatorFactory {
+    /// **設定値から `ThrusterActuator` を作成**
+    /// 指令が 3 次元の力でないなど, 設定値が不正ならエラーを返す
+    pub fn create_thruster_actuator<U: Force>(config: &ThrusterActuatorConfig) -> Result<ThrusterActuator<U>, &'static str> {
+        Ok(ThrusterActuator::new(
+            config.thrusters.clone(),
+            config.mass,
+            config.minimum_impulse_bit,
+            config.on_time_resolution,
+            config.time_constant,
+            config.magnitude_noise,
+            config.seed,
+        )?
+        .with_dry_mass(config.dry_mass))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::domain::controller::pd_controller::PdController;
+use crate::domain::controller::sliding_mode_controller::SlidingModeController;
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::infrastructure::factory::simulator_factory::SimulationConfig;
+use crate::infrastructure::settings::baseline_controller_settings::{PdConfig, SlidingModeConfig};
+
+/// **HCW のシナリオで使う基準の制御器 (PD, スライディングモード) を作る**
+pub struct BaselineControllerFactory;
+
+impl BaselineControllerFactory {
+    /// **基準軌道の HCW モデルで補償する PD 制御器 (設定値が不正ならエラーを返す)**
+    pub fn create_pd_controller(simulation_config: &SimulationConfig, config: &PdConfig) -> Result<PdController, &'static str> {
+        PdController::new(
+            &HcwDynamics::new(simulation_config.constants.a),
+            config.kp.clone(),
+            config.kd.clone(),
+            PositionVelocityStateLvlh::form_from_list(config.target_position, [0.0, 0.0, 0.0]),
+            config.u_max,
+        )
+    }
+
+    /// **基準軌道の HCW モデルで補償するスライディングモード制御器 (設定値が不正ならエラーを返す)**
+    pub fn create_sliding_mode_controller(
+        simulation_config: &SimulationConfig,
+        config: &SlidingModeConfig,
+    ) -> Result<SlidingModeController, &'static str> {
+        SlidingModeController::new(
+            &HcwDynamics::new(simulation_config.constants.a),
+            config.lambda,
+            config.eta,
+            config.boundary_layer,
+            PositionVelocityStateLvlh::form_from_list(config.target_position, [0.0, 0.0, 0.0]),
+            config.u_max,
+        )
+    }
+}
+
+#[cfg(test)]
+use ndarray::arr1;
+#[cfg(test)]
+use crate::infrastructure::settings::baseline_controller_settings::{default_pd_config, default_sliding_mode_config};
+#[cfg(test)]
+use crate::infrastructure::settings::simulation_config_hcw::default_hcw_simulation_config;
+
+#[test]
+fn test_invalid_baseline_controller_config_is_reported() {
+    let simulation_config = default_hcw_simulation_config();
+    assert!(BaselineControllerFactory::create_pd_controller(&simulation_config, &default_pd_config()).is_ok());
+    assert!(BaselineControllerFactory::create_sliding_mode_controller(&simulation_config, &default_sliding_mode_config()).is_ok());
+
+    // 設定の誤りはパニックせず呼び出し側へ返す
+    let pd = PdConfig { kp: arr1(&[1e-4, 0.0, 1e-4]), ..default_pd_config() };
+    assert!(BaselineControllerFactory::create_pd_controller(&simulation_config, &pd).is_err());
+    let sliding_mode = SlidingModeConfig { eta: -1.0, ..default_sliding_mode_config() };
+    assert!(BaselineControllerFactory::create_sliding_mode_controller(&simulation_config, &sliding_mode).is_err());
+}
@@ -0,0 +1,28 @@
+use crate::domain::controller::controller_trait::Controller;
+use crate::domain::controller::sample_and_hold_controller::SampleAndHoldController;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::factory::simulator_factory::SimulationConfig;
+
+/// **制御器を包むラッパーを設定値から作る**
+pub struct ControllerWrapperFactory;
+
+impl ControllerWrapperFactory {
+    /// **`SimulationConfig` の制御周期・計算遅れ・揺らぎで評価するサンプル & ホールドの制御器**
+    /// 制御周期などの設定値が不正ならエラーを返す
+    pub fn create_sample_and_hold<C, T, U>(controller: C, config: &SimulationConfig) -> Result<SampleAndHoldController<C, T, U>, &'static str>
+    where
+        C: Controller<T, U>,
+        T: StateVector,
+        U: Force,
+    {
+        SampleAndHoldController::new(
+            controller,
+            config.constants.t0,
+            config.control_period,
+            config.control_delay,
+            config.control_jitter,
+            config.seed,
+        )
+    }
+}
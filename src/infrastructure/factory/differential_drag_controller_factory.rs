@@ -0,0 +1,207 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use ndarray::Array2;
+
+use crate::domain::attitude::attitude_provider::{DragConfiguration, LvlhPointingAttitude};
+use crate::domain::controller::differential_drag_controller::{
+    DifferentialDragController, DifferentialDragModeDynamics, DifferentialDragModel, DragMode,
+};
+use crate::domain::controller::mode_controller::mode_optimizer::{
+    ContinuousDynamicsAndDifferentiable, CostAndDifferentiable, ModeScheduler, PassiveModeId,
+};
+use crate::domain::cost::quadric_cost::QuadraticCost;
+use crate::domain::disturbance::air_drag_disturbance::{AirDragStateEci, Surface};
+use crate::domain::disturbance::disturbance_trait::DisturbanceCalculator;
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::force_trait::Force;
+use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::factory::initialization_wrapper::{initialize_atmosphere, initialize_reference_orbit};
+use crate::infrastructure::factory::simulator_factory::{AttitudeEnum, SimulationConfig};
+use crate::infrastructure::settings::constants::CONSTANTS;
+use crate::infrastructure::settings::differential_drag_settings::DifferentialDragConfig;
+
+pub struct DifferentialDragControllerFactory;
+
+impl DifferentialDragControllerFactory {
+    /// **基準軌道上での抗力による減速度 (進行方向の大きさ)**
+    fn along_track_deceleration(
+        simulation_config: &SimulationConfig,
+        molecular_weight: f64,
+        wall_temperature: f64,
+        mass: f64,
+        surfaces: &[Surface],
+        euler_rad: &[f64; 3],
+    ) -> Result<f64, &'static str> {
+        let constants = &simulation_config.constants;
+        let reference = initialize_reference_orbit(simulation_config);
+        let state = PositionVelocityStateEci::form_from_array(ndarray::concatenate![
+            ndarray::Axis(0),
+            reference.position_eci(0.0),
+            reference.velocity_eci(0.0)
+        ]);
+        let drag = AirDragStateEci::new(
+            molecular_weight,
+            wall_temperature,
+            constants.molecular_temperature,
+            mass,
+            surfaces.to_vec(),
+            Box::new(LvlhPointingAttitude::new(euler_rad[0], euler_rad[1], euler_rad[2])),
+            initialize_atmosphere(simulation_config)?,
+            None,
+        );
+        let velocity = state.velocity();
+        Ok(-drag.calc_force(&state, 0.0).get_vector().dot(&velocity) / velocity.dot(&velocity).sqrt())
+    }
+
+    /// **各衛星・各構成の減速度を基準軌道で見積もる**
+    /// 大気モデルを生成できなければエラーを返す
+    pub fn estimate_differential_drag_model(simulation_config: &SimulationConfig, config: &DifferentialDragConfig) -> Result<DifferentialDragModel, &'static str> {
+        let constants = &simulation_config.constants;
+        let chief = |euler: &[f64; 3]| Self::along_track_deceleration(
+            simulation_config, constants.molecular_weight_chief, constants.wall_temperature_chief,
+            constants.mass_chief, &constants.surfaces_chief, euler,
+        );
+        let deputy = |euler: &[f64; 3]| Self::along_track_deceleration(
+            simulation_config, constants.molecular_weight_deputy, constants.wall_temperature_deputy,
+            constants.mass_deputy, &constants.surfaces_deputy, euler,
+        );
+        Ok(DifferentialDragModel {
+            chief_high: chief(&config.high_drag_euler_rad)?,
+            chief_low: chief(&config.low_drag_euler_rad)?,
+            deputy_high: deputy(&config.high_drag_euler_rad)?,
+            deputy_low: deputy(&config.low_drag_euler_rad)?,
+        })
+    }
+
+    /// **差動抗力制御器を作り, 両衛星の姿勢を切り替え可能な構成にする**
+    /// シミュレータはこの後に `simulation_config` から作る必要がある. 設定値が不正ならエラーを返す
+    pub fn create_differential_drag_controller(
+        simulation_config: &mut SimulationConfig,
+        config: &DifferentialDragConfig,
+    ) -> Result<DifferentialDragController, &'static str> {
+        let chief_configuration = Rc::new(Cell::new(DragConfiguration::LowDrag));
+        let deputy_configuration = Rc::new(Cell::new(DragConfiguration::LowDrag));
+        simulation_config.attitude_chief = AttitudeEnum::SwitchableDrag {
+            high_drag_euler_rad: config.high_drag_euler_rad,
+            low_drag_euler_rad: config.low_drag_euler_rad,
+            configuration: chief_configuration.clone(),
+        };
+        simulation_config.attitude_deputy = AttitudeEnum::SwitchableDrag {
+            high_drag_euler_rad: config.high_drag_euler_rad,
+            low_drag_euler_rad: config.low_drag_euler_rad,
+            configuration: deputy_configuration.clone(),
+        };
+        DifferentialDragController::new(
+            Self::estimate_differential_drag_model(simulation_config, config)?,
+            config.target_along_track_m,
+            config.time_constant,
+            config.deadband_m,
+            chief_configuration,
+            deputy_configuration,
+            CONSTANTS.mu,
+        )
+    }
+
+    /// **差動抗力の 4 モードの切り替えを ModeScheduler で最適化する**
+    /// 受動モードは 1 つだけ (遷移なし). 結果の ModeId は `DifferentialDragController::apply_mode_id` で反映できる.
+    /// horizon や刻みが不正ならエラーを返す
+    pub fn create_differential_drag_mode_scheduler(
+        simulation_config: &SimulationConfig,
+        config: &DifferentialDragConfig,
+        model: &DifferentialDragModel,
+        x0: &PositionVelocityStateLvlh,
+    ) -> Result<ModeScheduler<'static, PositionVelocityStateLvlh, Force3dLvlh, RungeKutta4Propagator>, &'static str> {
+        if config.horizon == 0 {
+            return Err("horizon は 1 以上である必要があります。");
+        }
+        if config.dt <= 0.0 || config.max_iterations == 0 {
+            return Err("刻み dt と反復回数は正である必要があります。");
+        }
+        let a = simulation_config.constants.a;
+        let t_last = config.dt * config.horizon as f64;
+        let mut dynamics_mapping: HashMap<_, Box<dyn ContinuousDynamicsAndDifferentiable<PositionVelocityStateLvlh, Force3dLvlh>>> = HashMap::new();
+        let mut cost_mapping: HashMap<_, Box<dyn CostAndDifferentiable<PositionVelocityStateLvlh, Force3dLvlh>>> = HashMap::new();
+        for mode in DragMode::ALL.iter() {
+            dynamics_mapping.insert(mode.mode_id(), Box::new(DifferentialDragModeDynamics::new(a, model, mode)));
+            let cost = QuadraticCost::new(config.q_matrix.clone(), Array2::zeros((3, 3)), config.qf_matrix.clone(), t_last);
+            cost_mapping.insert(mode.mode_id(), Box::new(cost));
+        }
+
+        let mut noise_matrix = HashMap::new();
+        noise_matrix.insert(PassiveModeId::new(0), Array2::<f64>::zeros((3, 3)));
+        Ok(ModeScheduler::new(
+            0.9,
+            0.5,
+            0.5,
+            config.max_iterations,
+            0,
+            config.horizon,
+            x0.clone(),
+            dynamics_mapping,
+            cost_mapping,
+            RungeKutta4Propagator,
+            config.dt,
+            noise_matrix,
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+        ))
+    }
+}
+
+#[cfg(test)]
+use crate::domain::controller::mode_controller::mode_optimizer::ModeId;
+#[cfg(test)]
+use crate::infrastructure::settings::differential_drag_settings::default_differential_drag_config;
+#[cfg(test)]
+use crate::infrastructure::settings::simulation_config::default_pair_simulation_config;
+
+#[test]
+fn test_differential_drag_factory() {
+    let mut simulation_config = default_pair_simulation_config();
+    let config = default_differential_drag_config();
+
+    // 高抵抗の構成の方が減速度が大きく, 面積の大きい chief の方が減速度が大きい
+    let model = DifferentialDragControllerFactory::estimate_differential_drag_model(&simulation_config, &config).unwrap();
+    assert!(model.chief_high > model.chief_low && model.chief_low > 0.0);
+    assert!(model.deputy_high > model.deputy_low && model.deputy_low > 0.0);
+    assert!(model.chief_low > model.deputy_high);
+
+    // 制御器を作ると両衛星の姿勢が切り替え可能になり, ModeId で構成を変えられる
+    let controller = DifferentialDragControllerFactory::create_differential_drag_controller(&mut simulation_config, &config).unwrap();
+    let AttitudeEnum::SwitchableDrag { configuration, .. } = &simulation_config.attitude_deputy else {
+        panic!("deputy attitude is not switchable");
+    };
+    controller.apply_mode_id(DragMode::ALL[2].mode_id()).unwrap();
+    assert_eq!(configuration.get(), DragConfiguration::HighDrag);
+    assert!(controller.apply_mode_id(ModeId::new(9)).is_err());
+
+    // deputy が後方にいると, 最適化したスケジュールは前方へ進むモードを含む
+    let mut config = config;
+    config.horizon = 30;
+    config.max_iterations = 5;
+    let x0 = PositionVelocityStateLvlh::form_from_list([0.0, -100.0, 0.0], [0.0, 0.0, 0.0]);
+    let scheduler = DifferentialDragControllerFactory::create_differential_drag_mode_scheduler(&simulation_config, &config, controller.model(), &x0).unwrap();
+    let schedule = scheduler.mode_schedule.as_ref().unwrap();
+    let modes: Vec<DragMode> = (0..config.horizon).map(|k| DragMode::from_mode_id(*schedule.get(k).unwrap()).unwrap()).collect();
+    assert!(modes.iter().any(|mode| controller.model().relative_acceleration(mode) > 0.0));
+}
+
+#[test]
+fn test_invalid_differential_drag_config_is_reported() {
+    let mut simulation_config = default_pair_simulation_config();
+    let config = default_differential_drag_config();
+    let invalid = DifferentialDragConfig { time_constant: 0.0, ..config.clone() };
+    assert!(DifferentialDragControllerFactory::create_differential_drag_controller(&mut simulation_config, &invalid).is_err());
+
+    let model = DifferentialDragControllerFactory::estimate_differential_drag_model(&simulation_config, &config).unwrap();
+    let x0 = PositionVelocityStateLvlh::form_from_list([0.0, -100.0, 0.0], [0.0, 0.0, 0.0]);
+    let invalid = DifferentialDragConfig { horizon: 0, ..config };
+    assert!(DifferentialDragControllerFactory::create_differential_drag_mode_scheduler(&simulation_config, &invalid, &model, &x0).is_err());
+}
@@ -0,0 +1,33 @@
+use crate::domain::controller::ilqr_controller::{IlqrController, IlqrOptimizer, InputBounds};
+use crate::domain::cost::quadric_cost::QuadraticCost;
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+use crate::domain::dynamics::propagator::RungeKutta4Propagator;
+use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+use crate::infrastructure::factory::simulator_factory::SimulationConfig;
+use crate::infrastructure::settings::ilqr_settings::IlqrConfig;
+
+pub struct IlqrControllerFactory;
+
+impl IlqrControllerFactory {
+    /// **基準軌道の HCW モデルで初期状態から最適化した iLQR 制御器**
+    /// 設定値が不正なときや最適化に失敗したときはエラーを返す
+    pub fn create_hcw_ilqr_controller(
+        simulation_config: &SimulationConfig,
+        config: &IlqrConfig,
+        initial_state: &PositionVelocityStateLvlh,
+        t0: f64,
+    ) -> Result<IlqrController<PositionVelocityStateLvlh, Force3dLvlh>, &'static str> {
+        let dynamics = HcwDynamics::new(simulation_config.constants.a);
+        let cost = QuadraticCost::new(
+            config.q_matrix.clone(),
+            config.r_matrix.clone(),
+            config.qf_matrix.clone(),
+            t0 + config.dt * config.horizon as f64,
+        );
+        let bounds = config.u_max.map(|u_max| InputBounds::symmetric(u_max, 3));
+        let optimizer = IlqrOptimizer::new(config.horizon, config.dt, config.max_iterations, config.tolerance, bounds)?;
+        let solution = optimizer.optimize(&dynamics, &cost, &RungeKutta4Propagator, initial_state, t0, None)?;
+        IlqrController::from_optimizer(solution, &optimizer, t0)
+    }
+}
@@ -1,21 +1,36 @@
+use ndarray::Array1;
 use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
 use crate::domain::dynamics::dynamics_2sat_2body::PairTwoBodyDynamics;
+use crate::domain::dynamics::dynamics_2sat_2body_variable_mass::VariableMassPairTwoBodyDynamics;
 use crate::domain::dynamics::dynamics_2body::TwoBodyDynamics;
 use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
 use crate::domain::dynamics::propagator::Propagator;
 use crate::domain::force::force_3d_eci::Force3dEci;
 use crate::domain::force::force_6d_eci::Force6dEci;
 use crate::domain::force::force_3d_lvlh::Force3dLvlh;
+use crate::domain::force::thrust_acceleration_pair_eci::ThrustAccelerationPairEci;
 use crate::domain::state::orbital_elements::OrbitalElements;
-use crate::infrastructure::factory::simulator_factory::{SimulationConfig, DisturbanceEnum, InitializationTypeEnum};
+use crate::infrastructure::factory::simulator_factory::{SimulationConfig, DisturbanceEnum, InitializationTypeEnum, AtmosphereModelEnum, WindModelEnum, AttitudeEnum};
 use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
+use crate::domain::state::position_velocity_mass_pair_state_eci::PositionVelocityMassPairStateEci;
 use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
 use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
 use crate::application::simulator::simulator::Simulator;
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::force::force_trait::Force;
+use crate::domain::maneuver::impulsive_maneuver::{ImpulsiveState, ImpulsivePropulsion};
 use crate::domain::disturbance::air_drag_disturbance::{AirDragStateEci, AirDragStatePairEci};
 use crate::domain::disturbance::j2_disturbance::{J2StateEci, J2StatePairEci};
+use crate::domain::disturbance::disturbance_trait::DisturbanceCalculator;
+use crate::domain::disturbance::earth_radiation_disturbance::{DifferentialEarthRadiationLvlh, EarthRadiationStateEci, EarthRadiationStatePairEci, EarthSurfaceGrid, KnockeEarthRadiation};
+use crate::domain::disturbance::relative_disturbance::{ReferenceOrbit, DifferentialJ2Lvlh, DifferentialAirDragLvlh};
+use crate::domain::disturbance::variable_mass_disturbance::VariableMassPairDisturbance;
+use crate::domain::disturbance::stochastic_disturbance::{WhiteNoiseAcceleration, GaussMarkovAcceleration, RandomWalkAcceleration};
+use crate::domain::disturbance::atmosphere_model::{AtmosphereModel, ExponentialAtmosphere, HarrisPriesterAtmosphere, Jacchia71Atmosphere};
+use crate::domain::attitude::attitude_provider::{AttitudeProvider, InertialAttitude, LvlhPointingAttitude, CommandedAttitudeProfile, SharedAttitudeState, SwitchableDragAttitude};
+use crate::domain::disturbance::wind_model::{WindModel, ConstantHorizontalWind};
+use crate::domain::disturbance::space_weather::{SpaceWeather, ConstantSpaceWeather};
+use crate::infrastructure::reader::space_weather_reader::SpaceWeatherReader;
 use crate::domain::state::state_converter::StateConverter;
 
 // この実装はここでいいのか...?
@@ -52,6 +67,16 @@ impl InitializeState for PositionVelocityPairStateEci {
     }
 }
 
+impl InitializeState for PositionVelocityMassPairStateEci {
+    fn initialize(config: &SimulationConfig) -> Self {
+        PositionVelocityMassPairStateEci::form_from_pair(
+            &PositionVelocityPairStateEci::initialize(config),
+            config.constants.propellant_mass_chief,
+            config.constants.propellant_mass_deputy,
+        )
+    }
+}
+
 impl InitializeState for PositionVelocityStateLvlh {
     fn initialize(config: &SimulationConfig) -> Self {
         let init_data = &config.init_data;
@@ -92,6 +117,29 @@ impl InitializeState for PositionVelocityStateEci {
     }
 }
 
+/// **インパルス噴射で推進剤を減らすための推進系の諸元 (推進剤を持たない状態量は None)**
+pub trait InitializePropulsion: ImpulsiveState {
+    fn initialize_propulsion(_config: &SimulationConfig) -> Option<Self::Propulsion> {
+        None
+    }
+}
+
+impl InitializePropulsion for PositionVelocityPairStateEci {}
+
+impl InitializePropulsion for PositionVelocityStateEci {}
+
+impl InitializePropulsion for PositionVelocityStateLvlh {}
+
+impl InitializePropulsion for PositionVelocityMassPairStateEci {
+    fn initialize_propulsion(config: &SimulationConfig) -> Option<ImpulsivePropulsion> {
+        let constants = &config.constants;
+        Some(ImpulsivePropulsion {
+            dry_mass: constants.mass_deputy - constants.propellant_mass_deputy,
+            isp: constants.isp_deputy,
+        })
+    }
+}
+
 // ここも
 pub trait InitializeDynamics {
     fn initialize(config: &SimulationConfig) -> Self;
@@ -111,46 +159,189 @@ impl InitializeDynamics for TwoBodyDynamics {
     }
 }
 
+impl InitializeDynamics for VariableMassPairTwoBodyDynamics {
+    fn initialize(config: &SimulationConfig) -> Self {
+        let constants = &config.constants;
+        Self::new(
+            constants.mass_chief - constants.propellant_mass_chief,
+            constants.mass_deputy - constants.propellant_mass_deputy,
+            constants.isp_chief,
+            constants.isp_deputy,
+        )
+    }
+}
+
 impl InitializeDynamics for HcwDynamics {
     fn initialize(config: &SimulationConfig) -> Self {
         Self::new(config.constants.a)
     }
 }
 
+/// **設定に応じた大気モデルを生成**
+/// 宇宙天気ファイルを読み込めなければエラーを返す
+pub fn initialize_atmosphere(config: &SimulationConfig) -> Result<Box<dyn AtmosphereModel>, &'static str> {
+    let constants = &config.constants;
+    Ok(match config.atmosphere {
+        AtmosphereModelEnum::Exponential => Box::new(ExponentialAtmosphere::new()),
+        AtmosphereModelEnum::HarrisPriester => Box::new(HarrisPriesterAtmosphere::new(
+            constants.epoch_jd,
+            constants.harris_priester_exponent,
+        )),
+        AtmosphereModelEnum::Jacchia71 => Box::new(Jacchia71Atmosphere::new(
+            constants.epoch_jd,
+            initialize_space_weather(config)?,
+        )),
+    })
+}
+
+/// **宇宙天気入力を生成 (ファイル指定があれば読み込む)**
+/// ファイルが無い, または形式が不正ならエラーを返す
+pub fn initialize_space_weather(config: &SimulationConfig) -> Result<Box<dyn SpaceWeather>, &'static str> {
+    let constants = &config.constants;
+    Ok(match &constants.space_weather_file {
+        Some(path) => Box::new(SpaceWeatherReader::read(path)?),
+        None => Box::new(ConstantSpaceWeather::new(constants.f107, constants.f107a, constants.ap)),
+    })
+}
+
+/// **設定に応じた風モデルを生成 (無風なら None)**
+pub fn initialize_wind(config: &SimulationConfig) -> Option<Box<dyn WindModel>> {
+    match config.wind {
+        WindModelEnum::NoWind => None,
+        WindModelEnum::ConstantHorizontal { east_mps, north_mps } => {
+            Some(Box::new(ConstantHorizontalWind::new(east_mps, north_mps)))
+        }
+    }
+}
+
+/// **設定に応じた姿勢を生成**
+pub fn initialize_attitude(attitude: &AttitudeEnum) -> Box<dyn AttitudeProvider> {
+    match attitude {
+        AttitudeEnum::Inertial => Box::new(InertialAttitude::new()),
+        AttitudeEnum::LvlhPointing { roll_rad, pitch_rad, yaw_rad } => {
+            Box::new(LvlhPointingAttitude::new(*roll_rad, *pitch_rad, *yaw_rad))
+        }
+        AttitudeEnum::CommandedProfile(commands) => Box::new(
+            CommandedAttitudeProfile::new(commands.clone()).expect("Invalid attitude command profile"),
+        ),
+        AttitudeEnum::Shared(state) => Box::new(SharedAttitudeState::new(state.clone())),
+        AttitudeEnum::SwitchableDrag { high_drag_euler_rad: high, low_drag_euler_rad: low, configuration } => Box::new(SwitchableDragAttitude::new(
+            LvlhPointingAttitude::new(high[0], high[1], high[2]),
+            LvlhPointingAttitude::new(low[0], low[1], low[2]),
+            configuration.clone(),
+        )),
+    }
+}
+
+/// **確率的な外乱を生成 (各軸同じ強さ, dim は力の次元)**
+/// 確率的な外乱でない, または相関時間が不正ならエラーを返す
+pub fn initialize_stochastic_disturbance<T, U>(disturbance: &DisturbanceEnum, dim: usize) -> Result<Box<dyn DisturbanceCalculator<T, U>>, &'static str>
+where
+    T: StateVector,
+    U: Force + 'static,
+{
+    let axes = |value: f64| Array1::from_elem(dim, value);
+    match disturbance {
+        DisturbanceEnum::WhiteNoise { spectral_density } => Ok(Box::new(WhiteNoiseAcceleration::<U>::new(axes(*spectral_density)))),
+        DisturbanceEnum::GaussMarkov { sigma, tau } => Ok(Box::new(GaussMarkovAcceleration::<U>::new(axes(*sigma), *tau)?)),
+        DisturbanceEnum::RandomWalk { sigma } => Ok(Box::new(RandomWalkAcceleration::<U>::new(axes(*sigma)))),
+        _ => Err("確率的な外乱ではありません。"),
+    }
+}
+
+/// **相対運動の基準軌道を生成**
+pub fn initialize_reference_orbit(config: &SimulationConfig) -> ReferenceOrbit {
+    let constants = &config.constants;
+    ReferenceOrbit::new(
+        constants.a,
+        constants.reference_inclination,
+        constants.reference_raan,
+        constants.reference_argument_of_latitude,
+    )
+}
+
 pub trait DisturbanceInitializer<T, U> 
 where
-    T: StateVector + Clone,
+    T: StateVector + ImpulsiveState + Clone,
     U: Force + Clone,
 {
-    fn initialize_disturbances(config: &SimulationConfig, simulator: &mut Simulator<T, U, impl Propagator<T, U>, impl ContinuousDynamics<T, U>>);
+    fn initialize_disturbances(config: &SimulationConfig, simulator: &mut Simulator<T, U, impl Propagator<T, U>, impl ContinuousDynamics<T, U>>) -> Result<(), &'static str>;
 }
 
 
+/// **2 衛星 (ECI) の外乱を 1 つ生成**
+pub fn initialize_pair_disturbance(
+    config: &SimulationConfig,
+    disturbance_type: &DisturbanceEnum,
+) -> Result<Box<dyn DisturbanceCalculator<PositionVelocityPairStateEci, Force6dEci>>, &'static str> {
+    Ok(match disturbance_type {
+        DisturbanceEnum::AirDrag => Box::new(AirDragStatePairEci::new(
+            config.constants.molecular_weight_chief,
+            config.constants.wall_temperature_chief,
+            config.constants.molecular_temperature,
+            config.constants.mass_chief,
+            config.constants.surfaces_chief.clone(),
+            initialize_attitude(&config.attitude_chief),
+            config.constants.molecular_weight_deputy,
+            config.constants.wall_temperature_deputy,
+            config.constants.mass_deputy,
+            config.constants.surfaces_deputy.clone(),
+            initialize_attitude(&config.attitude_deputy),
+            initialize_atmosphere(config)?,
+            initialize_wind(config),
+        )),
+        DisturbanceEnum::J2 => Box::new(J2StatePairEci::new()),
+        DisturbanceEnum::EarthRadiation => Box::new(EarthRadiationStatePairEci::new(
+            config.constants.mass_chief,
+            config.constants.surfaces_chief.clone(),
+            initialize_attitude(&config.attitude_chief),
+            config.constants.mass_deputy,
+            config.constants.surfaces_deputy.clone(),
+            initialize_attitude(&config.attitude_deputy),
+            config.constants.epoch_jd,
+            EarthSurfaceGrid::coarse(),
+            Box::new(KnockeEarthRadiation::new()),
+        )),
+        DisturbanceEnum::WhiteNoise { .. } | DisturbanceEnum::GaussMarkov { .. } | DisturbanceEnum::RandomWalk { .. } => {
+            initialize_stochastic_disturbance(disturbance_type, 6)?
+        }
+    })
+}
+
 impl DisturbanceInitializer<PositionVelocityPairStateEci, Force6dEci> for PositionVelocityPairStateEci {
     fn initialize_disturbances(
         config: &SimulationConfig,
         simulator: &mut Simulator<PositionVelocityPairStateEci, Force6dEci, impl Propagator<PositionVelocityPairStateEci, Force6dEci>, impl ContinuousDynamics<PositionVelocityPairStateEci, Force6dEci>>,
-    ) {
+    ) -> Result<(), &'static str> {
         for disturbance_type in config.disturbances.iter() {
+            simulator.add_disturbance(initialize_pair_disturbance(config, disturbance_type)?);
+        }
+        Ok(())
+    }
+}
+
+impl DisturbanceInitializer<PositionVelocityMassPairStateEci, ThrustAccelerationPairEci> for PositionVelocityMassPairStateEci {
+    fn initialize_disturbances(
+        config: &SimulationConfig,
+        simulator: &mut Simulator<PositionVelocityMassPairStateEci, ThrustAccelerationPairEci, impl Propagator<PositionVelocityMassPairStateEci, ThrustAccelerationPairEci>, impl ContinuousDynamics<PositionVelocityMassPairStateEci, ThrustAccelerationPairEci>>,
+    ) -> Result<(), &'static str> {
+        let constants = &config.constants;
+        for disturbance_type in config.disturbances.iter() {
+            let disturbance = initialize_pair_disturbance(config, disturbance_type)?;
             match disturbance_type {
-                DisturbanceEnum::AirDrag => {
-                    simulator.add_disturbance(Box::new(AirDragStatePairEci::new(
-                        config.constants.molecular_weight_chief,
-                        config.constants.wall_temperature_chief,
-                        config.constants.molecular_temperature,
-                        config.constants.mass_chief,
-                        config.constants.surfaces_chief.clone(),
-                        config.constants.molecular_weight_deputy,
-                        config.constants.wall_temperature_deputy,
-                        config.constants.mass_deputy,
-                        config.constants.surfaces_deputy.clone(),
+                DisturbanceEnum::AirDrag | DisturbanceEnum::EarthRadiation => {
+                    simulator.add_disturbance(Box::new(VariableMassPairDisturbance::inversely_proportional_to_mass(
+                        disturbance,
+                        constants.mass_chief,
+                        constants.mass_deputy,
+                        constants.mass_chief - constants.propellant_mass_chief,
+                        constants.mass_deputy - constants.propellant_mass_deputy,
                     )));
                 }
-                DisturbanceEnum::J2 => {
-                    simulator.add_disturbance(Box::new(J2StatePairEci::new()));
-                }
+                _ => simulator.add_disturbance(Box::new(VariableMassPairDisturbance::new(disturbance))),
             }
         }
+        Ok(())
     }
 }
 
@@ -158,7 +349,7 @@ impl DisturbanceInitializer<PositionVelocityStateEci, Force3dEci> for PositionVe
     fn initialize_disturbances(
         config: &SimulationConfig,
         simulator: &mut Simulator<PositionVelocityStateEci, Force3dEci, impl Propagator<PositionVelocityStateEci, Force3dEci>, impl ContinuousDynamics<PositionVelocityStateEci, Force3dEci>>,
-    ) {
+    ) -> Result<(), &'static str> {
         for disturbance_type in config.disturbances.iter() {
             match disturbance_type {
                 DisturbanceEnum::AirDrag => {
@@ -168,20 +359,70 @@ impl DisturbanceInitializer<PositionVelocityStateEci, Force3dEci> for PositionVe
                         config.constants.molecular_temperature,
                         config.constants.mass_chief,
                         config.constants.surfaces_chief.clone(),
+                        initialize_attitude(&config.attitude_chief),
+                        initialize_atmosphere(config)?,
+                        initialize_wind(config),
                     )));
                 }
                 DisturbanceEnum::J2 => {
                     simulator.add_disturbance(Box::new(J2StateEci::new()));
                 }
+                DisturbanceEnum::EarthRadiation => {
+                    simulator.add_disturbance(Box::new(EarthRadiationStateEci::new(
+                        config.constants.mass_chief,
+                        config.constants.surfaces_chief.clone(),
+                        initialize_attitude(&config.attitude_chief),
+                        config.constants.epoch_jd,
+                        EarthSurfaceGrid::coarse(),
+                        Box::new(KnockeEarthRadiation::new()),
+                    )));
+                }
+                DisturbanceEnum::WhiteNoise { .. } | DisturbanceEnum::GaussMarkov { .. } | DisturbanceEnum::RandomWalk { .. } => {
+                    simulator.add_disturbance(initialize_stochastic_disturbance(disturbance_type, 3)?);
+                }
             }
         }
+        Ok(())
     }
 }
 
-#[allow(unused)]
 impl DisturbanceInitializer<PositionVelocityStateLvlh, Force3dLvlh> for PositionVelocityStateLvlh {
     fn initialize_disturbances(
         config: &SimulationConfig,
         simulator: &mut Simulator<PositionVelocityStateLvlh, Force3dLvlh, impl Propagator<PositionVelocityStateLvlh, Force3dLvlh>, impl ContinuousDynamics<PositionVelocityStateLvlh, Force3dLvlh>>,
-    ){}
+    ) -> Result<(), &'static str> {
+        for disturbance_type in config.disturbances.iter() {
+            match disturbance_type {
+                DisturbanceEnum::AirDrag => {
+                    simulator.add_disturbance(Box::new(DifferentialAirDragLvlh::new(
+                        config.constants.ballistic_coefficient_chief,
+                        config.constants.ballistic_coefficient_deputy,
+                        initialize_reference_orbit(config),
+                        initialize_atmosphere(config)?,
+                    )));
+                }
+                DisturbanceEnum::J2 => {
+                    simulator.add_disturbance(Box::new(DifferentialJ2Lvlh::new(initialize_reference_orbit(config))));
+                }
+                DisturbanceEnum::EarthRadiation => {
+                    simulator.add_disturbance(Box::new(DifferentialEarthRadiationLvlh::new(
+                        config.constants.mass_chief,
+                        config.constants.surfaces_chief.clone(),
+                        initialize_attitude(&config.attitude_chief),
+                        config.constants.mass_deputy,
+                        config.constants.surfaces_deputy.clone(),
+                        initialize_attitude(&config.attitude_deputy),
+                        initialize_reference_orbit(config),
+                        config.constants.epoch_jd,
+                        EarthSurfaceGrid::coarse(),
+                        Box::new(KnockeEarthRadiation::new()),
+                    )));
+                }
+                DisturbanceEnum::WhiteNoise { .. } | DisturbanceEnum::GaussMarkov { .. } | DisturbanceEnum::RandomWalk { .. } => {
+                    simulator.add_disturbance(initialize_stochastic_disturbance(disturbance_type, 3)?);
+                }
+            }
+        }
+        Ok(())
+    }
 }
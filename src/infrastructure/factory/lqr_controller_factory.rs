@@ -0,0 +1,37 @@
+use crate::domain::controller::lqr_controller::LqrController;
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+use crate::domain::dynamics::dynamics_trait::LinearSystem;
+use crate::infrastructure::factory::simulator_factory::SimulationConfig;
+use crate::infrastructure::settings::lqr_settings::LqrConfig;
+
+pub struct LqrControllerFactory;
+
+impl LqrControllerFactory {
+    /// **基準軌道の HCW モデルに対する LQR 制御器**
+    /// リカッチ方程式が解けない (重みが不正, 可安定でないなど) ときはエラーを返す
+    pub fn create_hcw_lqr_controller(simulation_config: &SimulationConfig, config: &LqrConfig) -> Result<LqrController, &'static str> {
+        let dynamics = HcwDynamics::new(simulation_config.constants.a);
+        LqrController::new(
+            &dynamics.system_matrix(),
+            &dynamics.input_matrix(),
+            &config.q_matrix,
+            &config.r_matrix,
+            config.u_max,
+        )
+    }
+}
+
+#[cfg(test)]
+use crate::infrastructure::settings::lqr_settings::default_lqr_config;
+#[cfg(test)]
+use crate::infrastructure::settings::simulation_config_hcw::default_hcw_simulation_config;
+
+#[test]
+fn test_invalid_lqr_config_is_reported() {
+    let simulation_config = default_hcw_simulation_config();
+    assert!(LqrControllerFactory::create_hcw_lqr_controller(&simulation_config, &default_lqr_config()).is_ok());
+
+    // R が正則でなければリカッチ方程式は解けない
+    let singular = LqrConfig { r_matrix: ndarray::Array2::zeros((3, 3)), ..default_lqr_config() };
+    assert!(LqrControllerFactory::create_hcw_lqr_controller(&simulation_config, &singular).is_err());
+}
@@ -0,0 +1,86 @@
+use crate::domain::controller::mpc_controller::ModelPredictiveController;
+use crate::domain::cost::quadric_cost::QuadraticCost;
+use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
+use crate::domain::math::qp_solver::AdmmQpSolver;
+use crate::infrastructure::factory::simulator_factory::SimulationConfig;
+use crate::infrastructure::settings::mpc_settings::MpcConfig;
+
+pub struct MpcControllerFactory;
+
+impl MpcControllerFactory {
+    /// **基準軌道の HCW モデルに対する MPC 制御器**
+    /// horizon や刻み, QP ソルバの設定が不正ならエラーを返す
+    pub fn create_hcw_mpc_controller(simulation_config: &SimulationConfig, config: &MpcConfig) -> Result<ModelPredictiveController, &'static str> {
+        let dynamics = HcwDynamics::new(simulation_config.constants.a);
+        let cost = QuadraticCost::new(
+            config.q_matrix.clone(),
+            config.r_matrix.clone(),
+            config.qf_matrix.clone(),
+            config.dt * config.horizon as f64,
+        );
+        ModelPredictiveController::new(
+            &dynamics,
+            &cost,
+            config.horizon,
+            config.dt,
+            config.constraints.clone(),
+            AdmmQpSolver::new(config.rho, config.max_iterations, config.tolerance),
+        )
+    }
+}
+
+#[cfg(test)]
+use crate::domain::controller::controller_trait::Controller;
+#[cfg(test)]
+use crate::domain::controller::mpc_controller::MpcSolveStatus;
+#[cfg(test)]
+use crate::domain::dynamics::propagator::{Propagator, RungeKutta4Propagator};
+#[cfg(test)]
+use crate::domain::force::force_trait::Force;
+#[cfg(test)]
+use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
+#[cfg(test)]
+use crate::infrastructure::settings::mpc_settings::default_mpc_config;
+#[cfg(test)]
+use crate::infrastructure::settings::simulation_config_hcw::default_hcw_simulation_config;
+
+#[test]
+fn test_default_mpc_respects_approach_cone() {
+    let simulation_config = default_hcw_simulation_config();
+    let config = default_mpc_config();
+    let dynamics = HcwDynamics::new(simulation_config.constants.a);
+    let cone = config.constraints.approach_cone.clone().unwrap();
+    let u_max = config.constraints.u_max.unwrap();
+
+    // 軸から約 17 度の位置から接近したときの, 相対距離 1 m 以上での軸からの最大角
+    let max_angle = |config: &MpcConfig| {
+        let controller = MpcControllerFactory::create_hcw_mpc_controller(&simulation_config, config).unwrap();
+        let mut state = PositionVelocityStateLvlh::form_from_list([45.0, -150.0, 0.0], [0.0, 0.0, 0.0]);
+        let mut max_angle = 0.0_f64;
+        for k in 0..60 {
+            let t = k as f64 * config.dt;
+            let u = controller.compute_control_input(&state, t);
+            assert!(u.get_vector().iter().all(|v| v.abs() <= u_max + 1e-9));
+            // 頂点のごく近くでは線形化した制約が退化して解けないことがある (前回の入力列で代用する)
+            if state.position().dot(&state.position()).sqrt() > 1.0 {
+                assert_eq!(controller.last_status(), MpcSolveStatus::Solved);
+            }
+            state = RungeKutta4Propagator.propagate_continuous(&state, &u, &dynamics, t, config.dt);
+
+            let position = state.position();
+            let distance = position.dot(&position).sqrt();
+            if distance > 1.0 {
+                max_angle = max_angle.max((cone.axis.dot(&position) / distance).clamp(-1.0, 1.0).acos());
+            }
+        }
+        assert!(state.position().dot(&state.position()).sqrt() < 1.0);
+        max_angle
+    };
+
+    // コーンがあれば最後まで半頂角の内側にとどまり, なければ外へ回り込む
+    let constrained = max_angle(&config);
+    assert!(constrained <= cone.half_angle_rad + 1.0_f64.to_radians(), "max angle = {} deg", constrained.to_degrees());
+    let mut unconstrained_config = config.clone();
+    unconstrained_config.constraints.approach_cone = None;
+    assert!(max_angle(&unconstrained_config) > cone.half_angle_rad);
+}
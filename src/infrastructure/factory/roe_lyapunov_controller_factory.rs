@@ -0,0 +1,46 @@
+use ndarray::arr1;
+
+use crate::domain::controller::roe_lyapunov_controller::RoeLyapunovController;
+use crate::domain::state::relative_orbital_elements::RelativeOrbitalElements;
+use crate::domain::state::state_trait::StateVector;
+use crate::infrastructure::factory::simulator_factory::SimulationConfig;
+use crate::infrastructure::settings::constants::CONSTANTS;
+use crate::infrastructure::settings::roe_lyapunov_settings::RoeLyapunovConfig;
+
+pub struct RoeLyapunovControllerFactory;
+
+impl RoeLyapunovControllerFactory {
+    /// **基準軌道の長半径で無次元化した目標に向かう制御器**
+    /// 設定値が不正 (重みや収束率が正でないなど) ならエラーを返す
+    pub fn create_roe_lyapunov_controller(
+        simulation_config: &SimulationConfig,
+        config: &RoeLyapunovConfig,
+    ) -> Result<RoeLyapunovController, &'static str> {
+        let a = simulation_config.constants.a;
+        let target = RelativeOrbitalElements::form_from_array(arr1(&config.target_roe_m) / a);
+        RoeLyapunovController::new(
+            target,
+            config.weights.clone(),
+            config.convergence_rate,
+            config.drift_time_constant,
+            config.u_max,
+            CONSTANTS.mu,
+        )
+    }
+}
+
+#[cfg(test)]
+use crate::infrastructure::settings::roe_lyapunov_settings::default_roe_lyapunov_config;
+#[cfg(test)]
+use crate::infrastructure::settings::simulation_config::default_pair_simulation_config;
+
+#[test]
+fn test_invalid_roe_lyapunov_config_is_reported() {
+    let simulation_config = default_pair_simulation_config();
+    let config = default_roe_lyapunov_config();
+    assert!(RoeLyapunovControllerFactory::create_roe_lyapunov_controller(&simulation_config, &config).is_ok());
+
+    // 設定の誤りはパニックせず呼び出し側へ返す
+    let invalid = RoeLyapunovConfig { convergence_rate: 0.0, ..config };
+    assert!(RoeLyapunovControllerFactory::create_roe_lyapunov_controller(&simulation_config, &invalid).is_err());
+}
@@ -5,12 +5,13 @@ use std::ops::{Add, Sub, Div, Mul};
 use crate::domain::disturbance::air_drag_disturbance::{AirDragStateEci, AirDragStatePairEci, Surface};
 #[allow(unused)]
 use crate::domain::disturbance::j2_disturbance::{J2StateEci, J2StatePairEci};
+use crate::domain::attitude::attitude_provider::{AttitudeCommand, AttitudeStateHandle, DragConfigurationHandle};
 use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
 use crate::domain::dynamics::propagator::Propagator;
 use crate::application::simulator::simulator::Simulator;
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::force::force_trait::Force;
-use crate::infrastructure::factory::initialization_wrapper::{InitializeState, InitializeDynamics, DisturbanceInitializer};
+use crate::infrastructure::factory::initialization_wrapper::{InitializeState, InitializeDynamics, InitializePropulsion, DisturbanceInitializer};
 
 #[derive(Debug)]
 pub enum InitializationTypeEnum {
@@ -23,6 +24,32 @@ pub enum InitializationTypeEnum {
 pub enum DisturbanceEnum{
     J2,
     AirDrag,
+    EarthRadiation,  // アルベド・赤外放射圧
+    WhiteNoise { spectral_density: f64 },     // (m^2/s^3)
+    GaussMarkov { sigma: f64, tau: f64 },     // (m/s^2), (s)
+    RandomWalk { sigma: f64 },                // (m/s^2/sqrt(s))
+}
+
+#[derive(Debug, Clone)]
+pub enum AtmosphereModelEnum{
+    Exponential,
+    HarrisPriester,
+    Jacchia71,
+}
+
+#[derive(Debug, Clone)]
+pub enum AttitudeEnum{
+    Inertial,
+    LvlhPointing { roll_rad: f64, pitch_rad: f64, yaw_rad: f64 },
+    CommandedProfile(Vec<AttitudeCommand>),
+    Shared(AttitudeStateHandle),
+    SwitchableDrag { high_drag_euler_rad: [f64; 3], low_drag_euler_rad: [f64; 3], configuration: DragConfigurationHandle },
+}
+
+#[derive(Debug, Clone)]
+pub enum WindModelEnum{
+    NoWind,
+    ConstantHorizontal { east_mps: f64, north_mps: f64 },
 }
 
 #[derive(Debug)]
@@ -31,6 +58,14 @@ pub struct SimulationConfig{
     pub init_data: Vec<f64>,
     pub constants: SimulationConstants,
     pub disturbances:Vec<DisturbanceEnum>,
+    pub atmosphere: AtmosphereModelEnum,
+    pub wind: WindModelEnum,
+    pub attitude_chief: AttitudeEnum,
+    pub attitude_deputy: AttitudeEnum,
+    pub seed: u64,  // 確率的な外乱の乱数シード
+    pub control_period: f64,  // 制御器を評価する周期 (s)
+    pub control_delay: f64,   // 制御器の計算遅れ (s)
+    pub control_jitter: f64,  // 評価時刻の揺らぎの最大値 (s, 一様分布)
 }
 
 #[derive(Debug)]
@@ -42,22 +77,38 @@ pub struct SimulationConstants {
     pub molecular_weight_chief: f64,
     pub wall_temperature_chief: f64,
     pub molecular_temperature: f64,
-    pub mass_chief: f64,
+    pub mass_chief: f64,  // 初期質量 (推進剤を含む)
     pub surfaces_chief: Vec<Surface>,
     pub molecular_weight_deputy: f64,
     pub wall_temperature_deputy: f64,
     pub mass_deputy: f64,
     pub surfaces_deputy: Vec<Surface>,
+    pub epoch_jd: f64,  // t = 0 のユリウス日
+    pub f107: f64,      // 太陽フラックス F10.7 (sfu)
+    pub f107a: f64,     // F10.7 の 81 日平均 (sfu)
+    pub ap: f64,        // 地磁気指数 Ap
+    pub space_weather_file: Option<String>,  // CelesTrak 形式の宇宙天気ファイル (None なら上の一定値)
+    pub harris_priester_exponent: f64,  // Harris-Priester の cos 指数 (2 ~ 6)
+    pub reference_inclination: f64,  // 相対運動の基準軌道の軌道傾斜角 (rad)
+    pub reference_raan: f64,  // 基準軌道の昇交点赤経 (rad)
+    pub reference_argument_of_latitude: f64,  // t = 0 での基準軌道の引数緯度 (rad)
+    pub ballistic_coefficient_chief: f64,  // m / (C_D A) (kg/m^2)
+    pub ballistic_coefficient_deputy: f64,
+    pub propellant_mass_chief: f64,  // 初期推進剤質量 (kg, mass_chief に含まれる)
+    pub propellant_mass_deputy: f64,
+    pub isp_chief: f64,  // 比推力 (s)
+    pub isp_deputy: f64,
 }
 
 pub struct SimulatorFactory;
 
 impl SimulatorFactory {
+    /// 外乱の生成に失敗したら (宇宙天気ファイルが読めないなど) エラーを返す
     pub fn create_simulator<T, U, P, D>(
         config: &SimulationConfig,
-    ) -> Box<dyn Any>
+    ) -> Result<Box<dyn Any>, &'static str>
     where 
-        T: StateVector + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> + Clone + InitializeState + DisturbanceInitializer<T, U> + 'static,
+        T: StateVector + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> + Clone + InitializeState + InitializePropulsion + DisturbanceInitializer<T, U> + 'static,
         U: Force + Add<Output = U> + Sub<Output = U> + Mul<f64, Output = U> + Div<f64, Output = U> + Clone + 'static,
         P: Propagator<T, U> + 'static,
         D: ContinuousDynamics<T, U> + InitializeDynamics + 'static,
@@ -67,22 +118,41 @@ impl SimulatorFactory {
 
         let state = T::initialize(&config);  // T に応じた初期化を呼び出す
 
-        let simulator: Simulator<T, U, P, D> = Simulator::new(propagator, dynamics, state, config.constants.dt, config.constants.step, config.constants.t0);
-        Box::new(SimulatorFactory::add_disturbance(simulator, config))
+        let mut simulator: Simulator<T, U, P, D> = Simulator::new(propagator, dynamics, state, config.constants.dt, config.constants.step, config.constants.t0);
+        simulator.set_seed(config.seed);
+        if let Some(propulsion) = T::initialize_propulsion(config) {
+            simulator.set_propulsion(propulsion);
+        }
+        Ok(Box::new(SimulatorFactory::add_disturbance(simulator, config)?))
+    }
+
+    /// **`create_simulator` の結果を `Simulator` に戻して返す**
+    pub fn create_typed_simulator<T, U, P, D>(
+        config: &SimulationConfig,
+    ) -> Result<Simulator<T, U, P, D>, &'static str>
+    where 
+        T: StateVector + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> + Clone + InitializeState + InitializePropulsion + DisturbanceInitializer<T, U> + 'static,
+        U: Force + Add<Output = U> + Sub<Output = U> + Mul<f64, Output = U> + Div<f64, Output = U> + Clone + 'static,
+        P: Propagator<T, U> + 'static,
+        D: ContinuousDynamics<T, U> + InitializeDynamics + 'static,
+    {
+        Ok(*Self::create_simulator::<T, U, P, D>(config)?
+            .downcast::<Simulator<T, U, P, D>>()
+            .expect("Failed to cast Box<dyn Any> to Simulator"))
     }
 
     fn add_disturbance<T, U, P, D>(
         mut simulator: Simulator<T, U, P, D>,
         config: &SimulationConfig,
-    ) -> Simulator<T, U, P, D>
+    ) -> Result<Simulator<T, U, P, D>, &'static str>
     where
-        T: StateVector + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> + Clone + InitializeState + DisturbanceInitializer<T, U>,
+        T: StateVector + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T> + Clone + InitializeState + InitializePropulsion + DisturbanceInitializer<T, U>,
         U: Force + Add<Output = U> + Sub<Output = U> + Mul<f64, Output = U> + Div<f64, Output = U> + Clone,
         P: Propagator<T, U>, 
         D: ContinuousDynamics<T, U> + InitializeDynamics,
     {
-        T::initialize_disturbances(config, &mut simulator);
-        simulator
+        T::initialize_disturbances(config, &mut simulator)?;
+        Ok(simulator)
     }
     
 }
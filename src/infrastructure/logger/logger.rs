@@ -47,6 +47,11 @@ impl Logger {
         self.log_entries.push(Box::new(data));
     }
 
+    /// **ボックス化したデータをログに追加**
+    pub fn add_boxed_entry(&mut self, data: Box<dyn Loggable>) {
+        self.log_entries.push(data);
+    }
+
     /// **バッファにあるデータを一括で出力**
     pub fn log(&mut self, time: f64) {
         if !self.headers_written {
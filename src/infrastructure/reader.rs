@@ -0,0 +1 @@
+pub mod space_weather_reader;
\ No newline at end of file
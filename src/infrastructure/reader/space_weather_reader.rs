@@ -0,0 +1,156 @@
+use std::fs;
+use std::path::Path;
+
+use chrono::NaiveDate;
+
+use crate::domain::disturbance::space_weather::{SpaceWeatherRecord, SpaceWeatherTable};
+
+/// **CelesTrak 形式の宇宙天気ファイルを読み込む**
+/// - `SW-All.csv` (CSV 形式): DATE, AP_AVG, F10.7_OBS, F10.7_OBS_CENTER81 列を使用
+/// - `SW-All.txt` (テキスト形式): OBSERVED / DAILY_PREDICTED ブロックの行を使用
+pub struct SpaceWeatherReader;
+
+impl SpaceWeatherReader {
+    /// **拡張子で形式を判定して読み込む**
+    pub fn read(path: &str) -> Result<SpaceWeatherTable, &'static str> {
+        let is_csv = Path::new(path)
+            .extension()
+            .map(|ext| ext.eq_ignore_ascii_case("csv"))
+            .unwrap_or(false);
+        if is_csv {
+            Self::read_csv(path)
+        } else {
+            Self::read_txt(path)
+        }
+    }
+
+    pub fn read_csv(path: &str) -> Result<SpaceWeatherTable, &'static str> {
+        let mut reader = csv::Reader::from_path(path)
+            .map_err(|_| "宇宙天気ファイルを開けません。")?;
+        let headers = reader.headers().map_err(|_| "宇宙天気ファイルのヘッダを読み込めません。")?.clone();
+        let column = |name: &str| {
+            headers.iter()
+                .position(|h| h.trim() == name)
+                .ok_or("宇宙天気ファイルに必要な列がありません。")
+        };
+        let date_index = column("DATE")?;
+        let ap_index = column("AP_AVG")?;
+        let f107_index = column("F10.7_OBS")?;
+        let f107a_index = column("F10.7_OBS_CENTER81")?;
+
+        let mut records = Vec::new();
+        for row in reader.records() {
+            let row = row.map_err(|_| "宇宙天気ファイルの行を読み込めません。")?;
+            let (Some(date), Some(ap), Some(f107), Some(f107a)) = (
+                row.get(date_index),
+                row.get(ap_index),
+                row.get(f107_index),
+                row.get(f107a_index),
+            ) else {
+                continue;
+            };
+            // 予報区間など値が欠けている行は飛ばす
+            let (Ok(ap), Ok(f107), Ok(f107a)) = (ap.trim().parse(), f107.trim().parse(), f107a.trim().parse()) else {
+                continue;
+            };
+            let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+                .map_err(|_| "宇宙天気ファイルの日付が不正です。")?;
+            records.push(SpaceWeatherRecord {
+                julian_date: Self::julian_date(date),
+                f107,
+                f107a,
+                ap,
+            });
+        }
+
+        SpaceWeatherTable::new(records)
+    }
+
+    pub fn read_txt(path: &str) -> Result<SpaceWeatherTable, &'static str> {
+        let text = fs::read_to_string(path)
+            .map_err(|_| "宇宙天気ファイルを開けません。")?;
+
+        let mut records = Vec::new();
+        let mut in_data_block = false;
+        for line in text.lines() {
+            let line = line.trim();
+            if line.starts_with("BEGIN OBSERVED") || line.starts_with("BEGIN DAILY_PREDICTED") {
+                in_data_block = true;
+                continue;
+            }
+            if line.starts_with("END") {
+                in_data_block = false;
+                continue;
+            }
+            if !in_data_block {
+                continue;
+            }
+
+            // yyyy mm dd BSRN ND Kp*8 Sum Ap*8 Avg Cp C9 ISN F10.7adj Q Ctr81adj Lst81adj F10.7obs Ctr81obs Lst81obs
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 32 {
+                continue;
+            }
+            let parse = |i: usize| fields[i].parse::<f64>().ok();
+            let (Some(year), Some(month), Some(day)) = (
+                fields[0].parse::<i32>().ok(),
+                fields[1].parse::<u32>().ok(),
+                fields[2].parse::<u32>().ok(),
+            ) else {
+                continue;
+            };
+            let (Some(ap), Some(f107), Some(f107a)) = (parse(22), parse(30), parse(31)) else {
+                continue;
+            };
+            let date = NaiveDate::from_ymd_opt(year, month, day)
+                .ok_or("宇宙天気ファイルの日付が不正です。")?;
+            records.push(SpaceWeatherRecord {
+                julian_date: Self::julian_date(date),
+                f107,
+                f107a,
+                ap,
+            });
+        }
+
+        SpaceWeatherTable::new(records)
+    }
+
+    /// **日付 (0h UTC) のユリウス日**
+    fn julian_date(date: NaiveDate) -> f64 {
+        let unix_epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        2440587.5 + (date - unix_epoch).num_days() as f64
+    }
+}
+
+#[cfg(test)]
+use crate::domain::disturbance::space_weather::SpaceWeather;
+
+#[test]
+fn test_read_space_weather_csv() {
+    let path = std::env::temp_dir().join("test_space_weather.csv");
+    fs::write(
+        &path,
+        "DATE,BSRN,ND,AP_AVG,F10.7_OBS,F10.7_ADJ,F10.7_OBS_CENTER81\n\
+         2025-01-01,2606,1,12,180.5,174.0,170.0\n\
+         2025-01-02,2606,2,20,190.5,184.0,171.0\n\
+         2025-01-03,2606,3,,,,\n",
+    )
+    .unwrap();
+
+    let table = SpaceWeatherReader::read(path.to_str().unwrap()).unwrap();
+    assert_eq!(table.records().len(), 2);
+    assert!((table.records()[0].julian_date - 2460676.5).abs() < 1e-9);
+    assert!((table.ap(2460677.0) - 16.0).abs() < 1e-9);
+    assert!((table.f107(2460677.5) - 180.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_read_space_weather_reports_invalid_files() {
+    let missing = std::env::temp_dir().join("test_space_weather_missing.csv");
+    let _ = fs::remove_file(&missing);
+    assert!(SpaceWeatherReader::read(missing.to_str().unwrap()).is_err());
+
+    let path = std::env::temp_dir().join("test_space_weather_no_column.csv");
+    fs::write(&path, "DATE,AP_AVG,F10.7_OBS\n2025-01-01,12,180.5\n").unwrap();
+    assert!(SpaceWeatherReader::read(path.to_str().unwrap()).is_err());
+}
@@ -1,4 +1,11 @@
 pub mod constants;
 pub mod simulation_config;
 pub mod mode_shcedule_settings;
-pub mod simulation_config_hcw;
\ No newline at end of file
+pub mod simulation_config_hcw;
+pub mod actuator_settings;
+pub mod lqr_settings;
+pub mod mpc_settings;
+pub mod ilqr_settings;
+pub mod roe_lyapunov_settings;
+pub mod differential_drag_settings;
+pub mod baseline_controller_settings;
\ No newline at end of file
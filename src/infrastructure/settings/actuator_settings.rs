@@ -0,0 +1,42 @@
+use ndarray::arr1;
+
+use crate::domain::actuator::thruster_actuator::Thruster;
+use crate::infrastructure::factory::simulator_factory::SimulationConfig;
+
+/// **スラスタアクチュエータの設定値**
+#[derive(Debug, Clone)]
+pub struct ThrusterActuatorConfig {
+    pub thrusters: Vec<Thruster>,
+    pub mass: f64,                  // 機体質量 (kg)
+    pub dry_mass: f64,              // 乾燥質量 (kg, 推進剤を消費する状態量では推進剤質量を足して機体質量にする)
+    pub minimum_impulse_bit: f64,   // 最小インパルスビット (N s)
+    pub on_time_resolution: f64,    // オン時間の分解能 (s)
+    pub time_constant: f64,         // 一次遅れの時定数 (s)
+    pub magnitude_noise: f64,       // 推力の大きさの誤差 (1σ, 比率)
+    pub seed: u64,
+}
+
+/// **デフォルトの `ThrusterActuatorConfig`**
+/// 各軸 ± 方向に 1 基ずつ, 計 6 基のスラスタを deputy に搭載する
+pub fn default_thruster_actuator_config(simulation_config: &SimulationConfig) -> ThrusterActuatorConfig {
+    let max_thrust = 0.1;
+    let thrusters = vec![
+        Thruster::new(arr1(&[1.0, 0.0, 0.0]), max_thrust),
+        Thruster::new(arr1(&[-1.0, 0.0, 0.0]), max_thrust),
+        Thruster::new(arr1(&[0.0, 1.0, 0.0]), max_thrust),
+        Thruster::new(arr1(&[0.0, -1.0, 0.0]), max_thrust),
+        Thruster::new(arr1(&[0.0, 0.0, 1.0]), max_thrust),
+        Thruster::new(arr1(&[0.0, 0.0, -1.0]), max_thrust),
+    ];
+
+    ThrusterActuatorConfig {
+        thrusters,
+        mass: simulation_config.constants.mass_deputy,
+        dry_mass: simulation_config.constants.mass_deputy - simulation_config.constants.propellant_mass_deputy,
+        minimum_impulse_bit: 1e-4,
+        on_time_resolution: 1e-3,
+        time_constant: 0.05,
+        magnitude_noise: 0.02,
+        seed: simulation_config.seed,
+    }
+}
@@ -0,0 +1,41 @@
+use ndarray::{Array1, arr1};
+
+/// **PD 制御器の設定値**
+#[derive(Debug, Clone)]
+pub struct PdConfig {
+    pub kp: Array1<f64>,
+    pub kd: Array1<f64>,
+    pub target_position: [f64; 3],  // 目標の相対位置 (LVLH, 速度は 0)
+    pub u_max: Option<f64>,
+}
+
+/// **デフォルトの `PdConfig` (各軸の固有角周波数 0.01 rad/s, 減衰比 1)**
+pub fn default_pd_config() -> PdConfig {
+    PdConfig {
+        kp: arr1(&[1e-4, 1e-4, 1e-4]),
+        kd: arr1(&[2e-2, 2e-2, 2e-2]),
+        target_position: [0.0, 0.0, 0.0],
+        u_max: Some(0.001),
+    }
+}
+
+/// **スライディングモード制御器の設定値**
+#[derive(Debug, Clone)]
+pub struct SlidingModeConfig {
+    pub lambda: f64,
+    pub eta: f64,
+    pub boundary_layer: f64,
+    pub target_position: [f64; 3],
+    pub u_max: Option<f64>,
+}
+
+/// **デフォルトの `SlidingModeConfig` (モードスケジューラと同じ入力上限)**
+pub fn default_sliding_mode_config() -> SlidingModeConfig {
+    SlidingModeConfig {
+        lambda: 0.01,
+        eta: 5e-4,
+        boundary_layer: 1e-2,
+        target_position: [0.0, 0.0, 0.0],
+        u_max: Some(0.001),
+    }
+}
@@ -3,6 +3,10 @@ pub struct Constants {
     pub boltzmann_constant: f64,
     pub radius: f64,
     pub j2: f64,
+    pub earth_rotation_rate: f64,
+    pub solar_flux: f64,
+    pub speed_of_light: f64,
+    pub standard_gravity: f64,
 }
 
 pub static CONSTANTS: Constants = Constants {
@@ -10,4 +14,8 @@ pub static CONSTANTS: Constants = Constants {
     boltzmann_constant: 1.380649e-23,
     radius: 6378.1e3,
     j2: 1.08263e-3,
+    earth_rotation_rate: 7.2921159e-5,
+    solar_flux: 1361.0,
+    speed_of_light: 299792458.0,
+    standard_gravity: 9.80665,
 };
\ No newline at end of file
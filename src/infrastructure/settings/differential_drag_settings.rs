@@ -0,0 +1,35 @@
+use ndarray::{Array2, arr1};
+
+/// **差動抗力制御の設定値**
+#[derive(Debug, Clone)]
+pub struct DifferentialDragConfig {
+    pub high_drag_euler_rad: [f64; 3],  // 高抵抗の構成の LVLH に対するオイラー角 (roll, pitch, yaw)
+    pub low_drag_euler_rad: [f64; 3],   // 低抵抗の構成
+    pub target_along_track_m: f64,  // 目標の進行方向の間隔 (deputy が前方なら正)
+    pub time_constant: f64,         // 切り替え面の時定数 (s)
+    pub deadband_m: f64,
+    // ModeScheduler で最適化するときの設定
+    pub horizon: usize,
+    pub dt: f64,
+    pub q_matrix: Array2<f64>,
+    pub qf_matrix: Array2<f64>,
+    pub max_iterations: usize,
+}
+
+/// **デフォルトの `DifferentialDragConfig`**
+/// 立方体の機体は LVLH 指向で 1 面が流れに正対し, ヨー 45 度で 2 面が斜めに流れを受ける.
+/// 面ごとの抗力係数のモデルでは正対した方が減速度が大きい
+pub fn default_differential_drag_config() -> DifferentialDragConfig {
+    DifferentialDragConfig {
+        high_drag_euler_rad: [0.0, 0.0, 0.0],
+        low_drag_euler_rad: [0.0, 0.0, std::f64::consts::FRAC_PI_4],
+        target_along_track_m: 100.0,
+        time_constant: 1000.0,
+        deadband_m: 2.0,
+        horizon: 60,
+        dt: 60.0,
+        q_matrix: Array2::<f64>::zeros((6, 6)),
+        qf_matrix: Array2::from_diag(&arr1(&[1.0, 1.0, 1.0, 1e6, 1e6, 1e6])),
+        max_iterations: 20,
+    }
+}
@@ -0,0 +1,28 @@
+use ndarray::{Array2, arr1};
+
+/// **iLQR の設定値**
+#[derive(Debug, Clone)]
+pub struct IlqrConfig {
+    pub q_matrix: Array2<f64>,
+    pub r_matrix: Array2<f64>,
+    pub qf_matrix: Array2<f64>,
+    pub horizon: usize,  // 最適化するステップ数
+    pub dt: f64,         // 入力を保持する刻み (s)
+    pub max_iterations: usize,
+    pub tolerance: f64,
+    pub u_max: Option<f64>,  // 各軸の入力の上限 (None なら制約なし)
+}
+
+/// **デフォルトの `IlqrConfig` (モードスケジューラと同じ入力上限)**
+pub fn default_ilqr_config() -> IlqrConfig {
+    IlqrConfig {
+        q_matrix: Array2::<f64>::zeros((6, 6)),
+        r_matrix: Array2::<f64>::eye(3),
+        qf_matrix: Array2::from_diag(&arr1(&[1.0, 1.0, 1.0, 1e4, 1e4, 1e4])),
+        horizon: 200,
+        dt: 10.0,
+        max_iterations: 100,
+        tolerance: 1e-6,
+        u_max: Some(0.001),
+    }
+}
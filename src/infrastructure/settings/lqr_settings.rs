@@ -0,0 +1,18 @@
+use ndarray::Array2;
+
+/// **LQR の設定値**
+#[derive(Debug, Clone)]
+pub struct LqrConfig {
+    pub q_matrix: Array2<f64>,
+    pub r_matrix: Array2<f64>,
+    pub u_max: Option<f64>,  // 各軸の入力の上限 (None なら飽和させない)
+}
+
+/// **デフォルトの `LqrConfig` (モードスケジューラと同じ入力上限)**
+pub fn default_lqr_config() -> LqrConfig {
+    LqrConfig {
+        q_matrix: Array2::<f64>::eye(6) * 1e-6,
+        r_matrix: Array2::<f64>::eye(3),
+        u_max: Some(0.001),
+    }
+}
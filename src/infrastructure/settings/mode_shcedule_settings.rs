@@ -2,11 +2,10 @@ use std::collections::HashMap;
 use std::hash::Hash;
 
 use crate::domain::controller::mode_controller::mode_optimizer::{PassiveModeId, PassiveModeMap};
-use crate::domain::state::state_converter::StateConverter;
 use crate::domain::state::state_trait::StateVector;
 use crate::domain::force::force_trait::Force;
 use crate::domain::dynamics::dynamics_trait::ContinuousDynamics;
-use crate::domain::differentiable::differentiable_trait::{Differentiable2d, NumericalDefferential};
+use crate::domain::differentiable::differentiable_trait::{Differentiable2d, InputDifferentiable2d, NumericalDefferential};
 use crate::domain::controller::mode_controller::wrapper::InputDefinedDynamics;
 use crate::infrastructure::factory::simulator_factory::SimulationConfig;
 use ndarray::{Array1, Array2, arr2};
@@ -221,17 +220,6 @@ pub fn default_mode_scheduler_config(simulation_config: &SimulationConfig) -> Mo
 }
 
 
-//Fixme: 実装場所を変える
-impl StateConverter<PositionVelocityCovarianceStateLvlh> for PositionVelocityPairStateEci {
-    fn convert(&self) -> PositionVelocityCovarianceStateLvlh {
-        let pos_vec_lvlh: PositionVelocityStateLvlh = self.convert();
-        // 初期の不確かさは適当
-        let p = Array2::<f64>::eye(6) * 1e1 * 0.0;
-        PositionVelocityCovarianceStateLvlh::from_from_states(&pos_vec_lvlh, &pos_vec_lvlh, p)
-    }
-}
-
-
 // PositionVelocityCovarianceStateLvlh に対する制御入力定義ダイナミクス
 #[derive(Clone)]
 pub struct PositionVelocityCovarianceDynamics {
@@ -275,7 +263,7 @@ impl CreateInputDefinedDynamics<PositionVelocityCovarianceStateLvlh, Force3dLvlh
 }
 
 impl ContinuousDynamics<PositionVelocityCovarianceStateLvlh, Force3dLvlh> for PositionVelocityCovarianceDynamics {
-    fn compute_derivative(&self, state: &PositionVelocityCovarianceStateLvlh, _: &Force3dLvlh) -> PositionVelocityCovarianceStateLvlh {
+    fn compute_derivative(&self, state: &PositionVelocityCovarianceStateLvlh, _: &Force3dLvlh, _: f64) -> PositionVelocityCovarianceStateLvlh {
         // 状態量の取得
         let mu_x = state.get_mu_x();
         let est_x = state.get_est_x();
@@ -320,16 +308,16 @@ impl Differentiable2d<PositionVelocityCovarianceStateLvlh, Force3dLvlh> for Posi
     //     )
         
     // }
-    fn differentiate(&self, state: &PositionVelocityCovarianceStateLvlh, _: &Force3dLvlh, _: f64) -> Array2<f64> {
+    fn differentiate(&self, state: &PositionVelocityCovarianceStateLvlh, _: &Force3dLvlh, t: f64) -> Array2<f64> {
         let mut numerical_jacobian = Array2::<f64>::zeros((33, 33));
-        let f_original = self.compute_derivative(state, &Force3dLvlh::zeros()); // f(z) の値を取得
+        let f_original = self.compute_derivative(state, &Force3dLvlh::zeros(), t); // f(z) の値を取得
 
         for j in 0..33 {
             let mut perturbed_z = state.get_vector().clone();
             perturbed_z[j] += self.epsilon; // 状態を少しずらす
             let new_state = PositionVelocityCovarianceStateLvlh::form_from_array(perturbed_z); // z + ε に対応する状態量を生成
 
-            let f_perturbed = self.compute_derivative(&new_state, &Force3dLvlh::zeros()); // f(z + ε) を計算
+            let f_perturbed = self.compute_derivative(&new_state, &Force3dLvlh::zeros(), t); // f(z + ε) を計算
             let df = (&f_perturbed - &f_original) / self.epsilon; // 数値微分
 
             numerical_jacobian.column_mut(j).assign(&df.get_vector()); // 列に格納
@@ -461,6 +449,34 @@ impl Differentiable2d<PositionVelocityCovarianceStateLvlh, Force3dLvlh> for Posi
 }
 
 
+impl InputDifferentiable2d<PositionVelocityCovarianceStateLvlh, Force3dLvlh> for PositionVelocityCovarianceDynamics
+{
+    /// 固定された制御入力 u に関するヤコビアン
+    /// μ_x, est_x は B, P は外部ノイズ w w^T (w = F1 est_x + F2 u) を通して依存する
+    fn differentiate_input(&self, state: &PositionVelocityCovarianceStateLvlh, _: &Force3dLvlh, _: f64) -> Array2<f64> {
+        let w = self.f1_mat.dot(&state.get_est_x().get_vector().clone()) + self.f2_mat.dot(self.control_input.get_vector());
+        let mut jacobian = Array2::<f64>::zeros((33, 3));
+
+        for j in 0..3 {
+            let f2_j = self.f2_mat.column(j);
+            for i in 0..6 {
+                jacobian[[i, j]] = self.b_mat[[i, j]];
+                jacobian[[i + 6, j]] = self.b_mat[[i, j]];
+            }
+            // P は上三角を行優先で並べている
+            let mut index = 12;
+            for k in 0..6 {
+                for l in k..6 {
+                    jacobian[[index, j]] = f2_j[k] * w[l] + w[k] * f2_j[l];
+                    index += 1;
+                }
+            }
+        }
+
+        jacobian
+    }
+}
+
 impl InputDefinedDynamics<PositionVelocityCovarianceStateLvlh, Force3dLvlh> for PositionVelocityCovarianceDynamics
 {
     fn get_input(&self, _: &PositionVelocityCovarianceStateLvlh, _: f64) -> Force3dLvlh {
@@ -491,7 +507,7 @@ impl CreateInputDefinedDynamics<PositionVelocityStateLvlh, Force3dLvlh> for Line
 
 impl ContinuousDynamics<PositionVelocityStateLvlh, Force3dLvlh> for LinearControlledDynamics
 {
-    fn compute_derivative(&self, state: &PositionVelocityStateLvlh, _: &Force3dLvlh) -> PositionVelocityStateLvlh {
+    fn compute_derivative(&self, state: &PositionVelocityStateLvlh, _: &Force3dLvlh, _: f64) -> PositionVelocityStateLvlh {
         let dx = self.a_matrix.clone().dot(state.get_vector()) + (self.b_matrix.clone().dot(self.control_input.get_vector()));
         PositionVelocityStateLvlh::form_from_array(dx)
     }
@@ -504,6 +520,13 @@ impl Differentiable2d<PositionVelocityStateLvlh, Force3dLvlh> for LinearControll
     }
 }
 
+impl InputDifferentiable2d<PositionVelocityStateLvlh, Force3dLvlh> for LinearControlledDynamics
+{
+    fn differentiate_input(&self, _: &PositionVelocityStateLvlh, _: &Force3dLvlh, _: f64) -> Array2<f64> {
+        self.b_matrix.clone()
+    }
+}
+
 impl InputDefinedDynamics<PositionVelocityStateLvlh, Force3dLvlh> for LinearControlledDynamics
 {
     fn get_input(&self, _: &PositionVelocityStateLvlh, _: f64) -> Force3dLvlh {
@@ -525,14 +548,14 @@ fn numerical_jacobian(
     state: &PositionVelocityCovarianceStateLvlh,
 ) -> Array2<f64> {
     let mut numerical_jacobian = Array2::<f64>::zeros((33, 33));
-    let f_original = dynamics.compute_derivative(state, &Force3dLvlh::zeros()); // f(z) の値を取得
+    let f_original = dynamics.compute_derivative(state, &Force3dLvlh::zeros(), 0.0); // f(z) の値を取得
 
     for j in 0..33 {
         let mut perturbed_z = state.get_vector().clone();
         perturbed_z[j] += EPSILON; // 状態を少しずらす
         let new_state = PositionVelocityCovarianceStateLvlh::form_from_array(perturbed_z); // z + ε に対応する状態量を生成
 
-        let f_perturbed = dynamics.compute_derivative(&new_state, &Force3dLvlh::zeros()); // f(z + ε) を計算
+        let f_perturbed = dynamics.compute_derivative(&new_state, &Force3dLvlh::zeros(), 0.0); // f(z + ε) を計算
         let df = (&f_perturbed - &f_original) / EPSILON; // 数値微分
 
         numerical_jacobian.column_mut(j).assign(&df.get_vector()); // 列に格納
@@ -585,3 +608,26 @@ fn test_jacobian_accuracy() {
     println!("Max error: {:.6e}", max_error);
     assert!(max_error < 1e-3, "Jacobian computation may be incorrect!");
 }
+
+#[test]
+fn test_input_jacobian_accuracy() {
+    let simulation_config = default_pair_simulation_config();
+    let config = default_mode_scheduler_config(&simulation_config);
+    let state = PositionVelocityCovarianceStateLvlh::from_from_states(
+        &PositionVelocityStateLvlh::form_from_array(arr1(&[1.0, -2.0, 0.5, 0.01, 0.0, -0.02])),
+        &PositionVelocityStateLvlh::form_from_array(arr1(&[1.2, -1.8, 0.4, 0.0, 0.01, 0.0])),
+        Array2::<f64>::eye(6),
+    );
+    let input = Force3dLvlh::form_from_array(arr1(&[1e-3, -5e-4, 2e-4]));
+    let dynamics = PositionVelocityCovarianceDynamics::new(input.clone(), &config, &simulation_config);
+
+    // 制御入力は構造体に固定されているので, 入力を差し替えたダイナミクスで中心差分をとる
+    let f = |_: &PositionVelocityCovarianceStateLvlh, u: &Force3dLvlh| {
+        PositionVelocityCovarianceDynamics::new(u.clone(), &config, &simulation_config).compute_derivative(&state, u, 0.0)
+    };
+    let numerical = NumericalDefferential::differentiate_input_numeric(&f, &state, &input);
+    let analytical = dynamics.differentiate_input(&state, &input, 0.0);
+
+    let max_error = (&analytical - &numerical).iter().map(|x| x.abs()).fold(0.0, f64::max);
+    assert!(max_error < 1e-6, "Input jacobian computation may be incorrect!");
+}
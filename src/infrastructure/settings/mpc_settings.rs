@@ -0,0 +1,40 @@
+use ndarray::{Array2, arr1};
+
+use crate::domain::controller::mpc_controller::{ApproachCone, MpcConstraints};
+
+/// **MPC の設定値**
+#[derive(Debug, Clone)]
+pub struct MpcConfig {
+    pub q_matrix: Array2<f64>,
+    pub r_matrix: Array2<f64>,
+    pub qf_matrix: Array2<f64>,
+    pub horizon: usize,  // 予測ステップ数
+    pub dt: f64,         // 予測・入力更新の刻み (s)
+    pub constraints: MpcConstraints,
+    pub rho: f64,
+    pub max_iterations: usize,
+    pub tolerance: f64,
+}
+
+/// **デフォルトの `MpcConfig` (-y 方向からの接近コーン 30 度)**
+pub fn default_mpc_config() -> MpcConfig {
+    MpcConfig {
+        q_matrix: Array2::from_diag(&arr1(&[1e-4, 1e-4, 1e-4, 1.0, 1.0, 1.0])),
+        r_matrix: Array2::<f64>::eye(3) * 1e3,
+        qf_matrix: Array2::from_diag(&arr1(&[1e-3, 1e-3, 1e-3, 10.0, 10.0, 10.0])),
+        horizon: 20,
+        dt: 20.0,
+        constraints: MpcConstraints {
+            u_max: Some(0.001),
+            thrust_norm_max: None,
+            keep_out: None,
+            approach_cone: Some(ApproachCone {
+                axis: arr1(&[0.0, -1.0, 0.0]),
+                half_angle_rad: 30.0_f64.to_radians(),
+            }),
+        },
+        rho: 1.0,
+        max_iterations: 2000,
+        tolerance: 1e-4,  // 相対残差 (OSQP の既定値 1e-3 程度で十分)
+    }
+}
@@ -0,0 +1,22 @@
+use ndarray::{Array1, arr1};
+
+/// **相対軌道要素のリアプノフ制御器の設定値**
+#[derive(Debug, Clone)]
+pub struct RoeLyapunovConfig {
+    pub target_roe_m: [f64; 6],  // 目標の相対軌道要素に基準軌道の長半径を掛けたもの (m)
+    pub weights: Array1<f64>,    // [δa (δλ 込み), δex, δey, δix, δiy] の重み
+    pub convergence_rate: f64,   // 1/s
+    pub drift_time_constant: f64,  // δλ を縮める時定数 (s)
+    pub u_max: Option<f64>,  // 各軸の入力の上限 (None なら飽和させない)
+}
+
+/// **デフォルトの `RoeLyapunovConfig` (chief の 100 m 後方, 200 m の相対 e/i ベクトル)**
+pub fn default_roe_lyapunov_config() -> RoeLyapunovConfig {
+    RoeLyapunovConfig {
+        target_roe_m: [0.0, -100.0, 0.0, 200.0, 0.0, 200.0],
+        weights: arr1(&[1.0, 1.0, 1.0, 1.0, 1.0]),
+        convergence_rate: 1e-3,
+        drift_time_constant: 3000.0,
+        u_max: Some(0.001),
+    }
+}
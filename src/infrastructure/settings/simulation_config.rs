@@ -1,5 +1,5 @@
 #[allow(unused_imports)]
-use crate::infrastructure::factory::simulator_factory::{SimulationConfig, InitializationTypeEnum, SimulationConstants, DisturbanceEnum};
+use crate::infrastructure::factory::simulator_factory::{SimulationConfig, InitializationTypeEnum, SimulationConstants, DisturbanceEnum, AtmosphereModelEnum, WindModelEnum, AttitudeEnum};
 #[allow(unused_imports)]
 use crate::domain::dynamics::propagator::RungeKutta4Propagator;
 #[allow(unused_imports)]
@@ -7,6 +7,8 @@ use crate::domain::dynamics::propagator::EulerPropagator;
 #[allow(unused_imports)]
 use crate::domain::dynamics::dynamics_2sat_2body::PairTwoBodyDynamics;
 #[allow(unused_imports)]
+use crate::domain::dynamics::dynamics_2sat_2body_variable_mass::VariableMassPairTwoBodyDynamics;
+#[allow(unused_imports)]
 use crate::domain::dynamics::dynamics_2body::TwoBodyDynamics;
 #[allow(unused_imports)]
 use crate::domain::dynamics::dynamics_hcw::HcwDynamics;
@@ -19,6 +21,8 @@ use crate::domain::state::position_velocity_covariance_state_lvlh::PositionVeloc
 #[allow(unused_imports)]
 use crate::domain::state::position_velocity_pair_state_eci::PositionVelocityPairStateEci;
 #[allow(unused_imports)]
+use crate::domain::state::position_velocity_mass_pair_state_eci::PositionVelocityMassPairStateEci;
+#[allow(unused_imports)]
 use crate::domain::state::position_velocity_state_eci::PositionVelocityStateEci;
 #[allow(unused_imports)]
 use crate::domain::state::relative_position_velocity_state_lvlh::PositionVelocityStateLvlh;
@@ -31,6 +35,8 @@ use crate::domain::force::force_6d_eci::Force6dEci;
 #[allow(unused_imports)]
 use crate::domain::force::force_3d_lvlh::Force3dLvlh;
 #[allow(unused_imports)]
+use crate::domain::force::thrust_acceleration_pair_eci::ThrustAccelerationPairEci;
+#[allow(unused_imports)]
 use crate::domain::disturbance::air_drag_disturbance::Surface;
 #[allow(unused_imports)]
 use ndarray::arr1;
@@ -38,18 +44,23 @@ use ndarray::arr1;
 
 // pair,simgle : eci
 // hcw : lvlh
+// 推進剤の消費を考慮する場合は PositionVelocityMassPairStateEci, ThrustAccelerationPairEci, VariableMassPairTwoBodyDynamics
+// (制御器のパイプラインは convert_force の代わりに convert_to_thrust で推力にする)
 pub type StateType = PositionVelocityPairStateEci;
+// pub type StateType = PositionVelocityMassPairStateEci;
 // pub type StateType = PositionVelocityStateLvlh;
 // pub type StateType = PositionVelocityStateEci;
 
 // pub type ForceType = Force3dLvlh;
 // pub type ForceType = Force3dEci;
 pub type ForceType = Force6dEci;
+// pub type ForceType = ThrustAccelerationPairEci;
 
 // pub type PropagatorType = EulerPropagator;
 pub type PropagatorType = RungeKutta4Propagator;
 
 pub type DynamicsType = PairTwoBodyDynamics;
+// pub type DynamicsType = VariableMassPairTwoBodyDynamics;
 // pub type DynamicsType = HcwDynamics;
 // pub type DynamicsType = TwoBodyDynamics;
 
@@ -61,24 +72,29 @@ pub fn default_simulation_config() -> SimulationConfig {
 
 pub fn default_pair_simulation_config() -> SimulationConfig {
     let specularity = 0.4;
+    let specular_reflectivity = 0.1;
+    let diffuse_reflectivity = 0.3;
     let a0 = 2.0;
     let a1 = 0.2;
+    let mass_chief = 50.0;
+    let mass_deputy = 50.0;
+    let drag_coefficient = 2.2;
 
     let surface_list_chief = vec![
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
     ];
     let surface_list_deputy = vec![
-                Surface { air_specularity: specularity, area_m2: a1, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a1, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a1, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
-                Surface { air_specularity: specularity, area_m2: a1, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a1, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a1, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a1, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a1, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a1, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a1, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a1, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a1, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
     ];
     SimulationConfig {
         initialization: InitializationTypeEnum::OrbitalElements,
@@ -100,6 +116,14 @@ pub fn default_pair_simulation_config() -> SimulationConfig {
             DisturbanceEnum::AirDrag,
             // DisturbanceEnum::J2,
         ],
+        atmosphere: AtmosphereModelEnum::Exponential,
+        wind: WindModelEnum::NoWind,
+        attitude_chief: AttitudeEnum::Inertial,
+        attitude_deputy: AttitudeEnum::Inertial,
+        seed: 0,
+        control_period: 1.0,
+        control_delay: 0.0,
+        control_jitter: 0.0,
         constants: SimulationConstants {
             dt: 0.02,        // Time step (s)
             step: 5000,     // Time step num
@@ -108,27 +132,47 @@ pub fn default_pair_simulation_config() -> SimulationConfig {
             molecular_weight_chief: 18.0,
             wall_temperature_chief: 30.0,
             molecular_temperature: 3.0,
-            mass_chief: 50.0,
+            mass_chief,
             surfaces_chief:surface_list_chief,
             molecular_weight_deputy: 18.0,
             wall_temperature_deputy: 30.0,
-            mass_deputy: 50.0,
+            mass_deputy,
             surfaces_deputy:surface_list_deputy,
+            epoch_jd: 2460676.5,  // 2025-01-01 00:00:00 UTC
+            f107: 150.0,
+            f107a: 150.0,
+            ap: 15.0,
+            space_weather_file: None,  // 例: Some("data/space_weather/SW-All.csv".to_string())
+            harris_priester_exponent: 6.0,
+            reference_inclination: std::f64::consts::FRAC_PI_2,
+            reference_raan: 0.0,
+            reference_argument_of_latitude: 0.0,
+            ballistic_coefficient_chief: mass_chief / (drag_coefficient * a0),  // m / (C_D A)
+            ballistic_coefficient_deputy: mass_deputy / (drag_coefficient * a1),
+            propellant_mass_chief: 5.0,
+            propellant_mass_deputy: 5.0,
+            isp_chief: 60.0,  // コールドガス
+            isp_deputy: 60.0,
         },
     }
 }
 
 pub fn default_single_simulation_config() -> SimulationConfig {
     let specularity = 0.4;
+    let specular_reflectivity = 0.1;
+    let diffuse_reflectivity = 0.3;
     let a0 = 2.0;
+    let mass_chief = 50.0;
+    let mass_deputy = 50.0;
+    let drag_coefficient = 2.2;
 
     let surface_list = vec![
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
     ];
     SimulationConfig {
         initialization: InitializationTypeEnum::OrbitalElements,
@@ -144,6 +188,14 @@ pub fn default_single_simulation_config() -> SimulationConfig {
             DisturbanceEnum::AirDrag,
             // DisturbanceEnum::J2,
         ],
+        atmosphere: AtmosphereModelEnum::Exponential,
+        wind: WindModelEnum::NoWind,
+        attitude_chief: AttitudeEnum::Inertial,
+        attitude_deputy: AttitudeEnum::Inertial,
+        seed: 0,
+        control_period: 1.0,
+        control_delay: 0.0,
+        control_jitter: 0.0,
         constants: SimulationConstants {
             dt: 1.0,        // Time step (s)
             step: 30000,     // Time step num
@@ -152,27 +204,47 @@ pub fn default_single_simulation_config() -> SimulationConfig {
             molecular_weight_chief: 18.0,
             wall_temperature_chief: 30.0,
             molecular_temperature: 3.0,
-            mass_chief: 50.0,
+            mass_chief,
             surfaces_chief:surface_list.clone(),
             molecular_weight_deputy: 18.0,
             wall_temperature_deputy: 30.0,
-            mass_deputy: 50.0,
+            mass_deputy,
             surfaces_deputy:surface_list,
+            epoch_jd: 2460676.5,  // 2025-01-01 00:00:00 UTC
+            f107: 150.0,
+            f107a: 150.0,
+            ap: 15.0,
+            space_weather_file: None,  // 例: Some("data/space_weather/SW-All.csv".to_string())
+            harris_priester_exponent: 6.0,
+            reference_inclination: std::f64::consts::FRAC_PI_2,
+            reference_raan: 0.0,
+            reference_argument_of_latitude: 0.0,
+            ballistic_coefficient_chief: mass_chief / (drag_coefficient * a0),  // m / (C_D A)
+            ballistic_coefficient_deputy: mass_deputy / (drag_coefficient * a0),
+            propellant_mass_chief: 5.0,
+            propellant_mass_deputy: 5.0,
+            isp_chief: 60.0,  // コールドガス
+            isp_deputy: 60.0,
         },
     }
 }
 
 pub fn default_hcw_simulation_config() -> SimulationConfig {
     let specularity = 0.4;
+    let specular_reflectivity = 0.1;
+    let diffuse_reflectivity = 0.3;
     let a0 = 2.0;
+    let mass_chief = 50.0;
+    let mass_deputy = 50.0;
+    let drag_coefficient = 2.2;
 
     let surface_list = vec![
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
     ];
     SimulationConfig {
         initialization: InitializationTypeEnum::RelativePositionVelocity,
@@ -188,6 +260,14 @@ pub fn default_hcw_simulation_config() -> SimulationConfig {
             // DisturbanceEnum::AirDrag,
             // DisturbanceEnum::J2,
         ],
+        atmosphere: AtmosphereModelEnum::Exponential,
+        wind: WindModelEnum::NoWind,
+        attitude_chief: AttitudeEnum::Inertial,
+        attitude_deputy: AttitudeEnum::Inertial,
+        seed: 0,
+        control_period: 1.0,
+        control_delay: 0.0,
+        control_jitter: 0.0,
         constants: SimulationConstants {
             dt: 1.0,        // Time step (s)
             step: 30000,     // Time step num
@@ -196,12 +276,27 @@ pub fn default_hcw_simulation_config() -> SimulationConfig {
             molecular_weight_chief: 18.0,
             wall_temperature_chief: 30.0,
             molecular_temperature: 3.0,
-            mass_chief: 50.0,
+            mass_chief,
             surfaces_chief:surface_list.clone(),
             molecular_weight_deputy: 18.0,
             wall_temperature_deputy: 30.0,
-            mass_deputy: 50.0,
+            mass_deputy,
             surfaces_deputy:surface_list,
+            epoch_jd: 2460676.5,  // 2025-01-01 00:00:00 UTC
+            f107: 150.0,
+            f107a: 150.0,
+            ap: 15.0,
+            space_weather_file: None,  // 例: Some("data/space_weather/SW-All.csv".to_string())
+            harris_priester_exponent: 6.0,
+            reference_inclination: std::f64::consts::FRAC_PI_2,
+            reference_raan: 0.0,
+            reference_argument_of_latitude: 0.0,
+            ballistic_coefficient_chief: mass_chief / (drag_coefficient * a0),  // m / (C_D A)
+            ballistic_coefficient_deputy: mass_deputy / (drag_coefficient * a0),
+            propellant_mass_chief: 5.0,
+            propellant_mass_deputy: 5.0,
+            isp_chief: 60.0,  // コールドガス
+            isp_deputy: 60.0,
         },
     }
 }
@@ -1,5 +1,5 @@
 #[allow(unused_imports)]
-use crate::infrastructure::factory::simulator_factory::{SimulationConfig, InitializationTypeEnum, SimulationConstants, DisturbanceEnum};
+use crate::infrastructure::factory::simulator_factory::{SimulationConfig, InitializationTypeEnum, SimulationConstants, DisturbanceEnum, AtmosphereModelEnum, WindModelEnum, AttitudeEnum};
 #[allow(unused_imports)]
 use crate::domain::dynamics::propagator::RungeKutta4Propagator;
 #[allow(unused_imports)]
@@ -61,15 +61,20 @@ pub fn default_simulation_config() -> SimulationConfig {
 
 pub fn default_hcw_simulation_config() -> SimulationConfig {
     let specularity = 0.4;
+    let specular_reflectivity = 0.1;
+    let diffuse_reflectivity = 0.3;
     let a0 = 2.0;
+    let mass_chief = 50.0;
+    let mass_deputy = 50.0;
+    let drag_coefficient = 2.2;
 
     let surface_list = vec![
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
-                Surface { air_specularity: specularity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[1.0, 0.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 1.0, 0.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, 1.0] )},
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[-1.0, 0.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, -1.0, 0.0]) },
+                Surface { air_specularity: specularity, specular_reflectivity, diffuse_reflectivity, area_m2: a0, normal_direction: arr1(&[0.0, 0.0, -1.0]) },
     ];
     SimulationConfig {
         initialization: InitializationTypeEnum::RelativePositionVelocity,
@@ -85,6 +90,14 @@ pub fn default_hcw_simulation_config() -> SimulationConfig {
             // DisturbanceEnum::AirDrag,
             // DisturbanceEnum::J2,
         ],
+        atmosphere: AtmosphereModelEnum::Exponential,
+        wind: WindModelEnum::NoWind,
+        attitude_chief: AttitudeEnum::Inertial,
+        attitude_deputy: AttitudeEnum::Inertial,
+        seed: 0,
+        control_period: 1.0,
+        control_delay: 0.0,
+        control_jitter: 0.0,
         constants: SimulationConstants {
             dt: 0.1,        // Time step (s)
             step: 1000,     // Time step num
@@ -93,12 +106,27 @@ pub fn default_hcw_simulation_config() -> SimulationConfig {
             molecular_weight_chief: 18.0,
             wall_temperature_chief: 30.0,
             molecular_temperature: 3.0,
-            mass_chief: 50.0,
+            mass_chief,
             surfaces_chief:surface_list.clone(),
             molecular_weight_deputy: 18.0,
             wall_temperature_deputy: 30.0,
-            mass_deputy: 50.0,
+            mass_deputy,
             surfaces_deputy:surface_list,
+            epoch_jd: 2460676.5,  // 2025-01-01 00:00:00 UTC
+            f107: 150.0,
+            f107a: 150.0,
+            ap: 15.0,
+            space_weather_file: None,  // 例: Some("data/space_weather/SW-All.csv".to_string())
+            harris_priester_exponent: 6.0,
+            reference_inclination: std::f64::consts::FRAC_PI_2,
+            reference_raan: 0.0,
+            reference_argument_of_latitude: 0.0,
+            ballistic_coefficient_chief: mass_chief / (drag_coefficient * a0),  // m / (C_D A)
+            ballistic_coefficient_deputy: mass_deputy / (drag_coefficient * a0),
+            propellant_mass_chief: 5.0,
+            propellant_mass_deputy: 5.0,
+            isp_chief: 60.0,  // コールドガス
+            isp_deputy: 60.0,
         },
     }
 }
@@ -7,7 +7,11 @@ use crate::infrastructure::logger::logger::Logger;
 #[cfg(test)]
 use crate::application::simulator::simulator::Simulator;
 #[cfg(test)]
+use crate::domain::force::force_trait::Force;
+#[cfg(test)]
 use crate::infrastructure::settings::simulation_config::{StateType, ForceType, PropagatorType, DynamicsType};
+#[cfg(test)]
+use crate::infrastructure::factory::simulator_factory::{AtmosphereModelEnum, DisturbanceEnum};
 
 #[test]
 fn pair_state_simulation_test() {
@@ -16,9 +20,9 @@ fn pair_state_simulation_test() {
     let mut logger = Logger::new(log_filename).expect("Failed to initialize logger");
 
     let config = default_simulation_config();
-    let external_force = ForceType::form_from_list([0.0, 0.0, 0.0, 0.0, 0.0 ,0.0]);
+    let external_force = ForceType::zeros();
 
-    let mut simulator_box = SimulatorFactory::create_simulator::<StateType, ForceType, PropagatorType, DynamicsType>(&config);
+    let mut simulator_box = SimulatorFactory::create_simulator::<StateType, ForceType, PropagatorType, DynamicsType>(&config).unwrap();
     let simulator = simulator_box
         .downcast_mut::<Simulator<StateType, ForceType, PropagatorType, DynamicsType>>()
         .expect("Failed to cast Box<dyn Any> to Simulator");
@@ -35,3 +39,14 @@ fn pair_state_simulation_test() {
     logger.flush();
     logger.execute_python_script()
 }
+
+#[test]
+fn missing_space_weather_file_is_reported() {
+    let mut config = default_simulation_config();
+    config.atmosphere = AtmosphereModelEnum::Jacchia71;
+    config.disturbances = vec![DisturbanceEnum::AirDrag];
+    config.constants.space_weather_file = Some("missing_space_weather_file.csv".to_string());
+
+    let result = SimulatorFactory::create_simulator::<StateType, ForceType, PropagatorType, DynamicsType>(&config);
+    assert!(result.is_err());
+}